@@ -0,0 +1,129 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::AdminProposalV1,
+    utils::{
+        AccountCheck, AdminProposalAccount, Pda, ProcessInstruction, SignerAccount, SystemProgram,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct AcceptAdminV1Accounts<'a, 'info> {
+    /// The proposed admin — must sign to take over.
+    /// Must match `admin_proposal.pending_admin`.
+    pub new_admin: &'a AccountInfo<'info>,
+
+    /// The `ProjectV1` or `TraitItemV1` PDA being handed over.
+    /// Must be writable, owned by program.
+    pub target_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["admin_proposal_v1", target_pda, program_id]` — stores the pending admin.
+    /// Must be initialized and writable. Closed on accept.
+    pub admin_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created the proposal — receives the proposal rent.
+    /// Must match `admin_proposal.proposer`.
+    pub proposer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for AcceptAdminV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [new_admin, target_pda, admin_proposal_pda, proposer] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(new_admin)?;
+
+        WritableAccount::check(target_pda)?;
+        WritableAccount::check(admin_proposal_pda)?;
+        WritableAccount::check(proposer)?;
+
+        AdminProposalAccount::check(admin_proposal_pda)?;
+
+        Ok(Self {
+            new_admin,
+            target_pda,
+            admin_proposal_pda,
+            proposer,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct AcceptAdminV1<'a, 'info> {
+    pub accounts: AcceptAdminV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)> for AcceptAdminV1<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = AcceptAdminV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.admin_proposal_pda,
+            &[AdminProposalV1::SEED, accounts.target_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> AcceptAdminV1<'a, 'info> {
+    fn transfer_admin(&self) -> ProgramResult {
+        let proposal_data = self.accounts.admin_proposal_pda.try_borrow_data()?;
+        let proposal = AdminProposalV1::load(&proposal_data)?;
+
+        if proposal.pending_admin != *self.accounts.new_admin.key {
+            msg!("Unauthorized: signer is not the pending admin");
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        if proposal.proposer != *self.accounts.proposer.key {
+            msg!("Proposer account does not match the admin proposal");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        proposal.target_kind.check(self.accounts.target_pda)?;
+
+        let mut target_data = self.accounts.target_pda.try_borrow_mut_data()?;
+
+        if proposal.target_kind.admin(&target_data)? != proposal.proposer {
+            msg!("Admin proposal is stale: target admin changed since it was proposed");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        proposal
+            .target_kind
+            .set_admin(&mut target_data, proposal.pending_admin)?;
+
+        msg!(
+            "Admin of {} handed over from {} to {}",
+            self.accounts.target_pda.key,
+            proposal.proposer,
+            proposal.pending_admin
+        );
+
+        Ok(())
+    }
+
+    fn close_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.admin_proposal_pda, self.accounts.proposer)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for AcceptAdminV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.transfer_admin()?;
+        self.close_proposal()
+    }
+}
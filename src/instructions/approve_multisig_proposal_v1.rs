@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{MultisigProposalV1, MultisigV1},
+    utils::{
+        AccountCheck, MultisigAccount, MultisigProposalAccount, Pda, ProcessInstruction,
+        SignerAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct ApproveMultisigProposalV1Accounts<'a, 'info> {
+    /// Multisig signer approving the proposal — must sign.
+    pub signer: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_v1", project_pda, program_id]` — stores the signer set.
+    /// Must be owned by program.
+    pub multisig_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — proposal to approve.
+    /// Must be initialized and writable.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for ApproveMultisigProposalV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [signer, multisig_pda, multisig_proposal_pda] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(signer)?;
+
+        WritableAccount::check(multisig_proposal_pda)?;
+
+        MultisigAccount::check(multisig_pda)?;
+        MultisigProposalAccount::check(multisig_proposal_pda)?;
+
+        Ok(Self {
+            signer,
+            multisig_pda,
+            multisig_proposal_pda,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ApproveMultisigProposalV1<'a, 'info> {
+    pub accounts: ApproveMultisigProposalV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)>
+    for ApproveMultisigProposalV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ApproveMultisigProposalV1Accounts::try_from(accounts)?;
+
+        let proposal_data = accounts.multisig_proposal_pda.try_borrow_data()?;
+        let proposal = MultisigProposalV1::load(&proposal_data)?;
+
+        Pda::validate(
+            accounts.multisig_proposal_pda,
+            &[
+                MultisigProposalV1::SEED,
+                accounts.multisig_pda.key.as_ref(),
+                &proposal.index.to_le_bytes(),
+            ],
+            program_id,
+        )?;
+
+        drop(proposal_data);
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> ApproveMultisigProposalV1<'a, 'info> {
+    fn approve(&self) -> ProgramResult {
+        let multisig_data = self.accounts.multisig_pda.try_borrow_data()?;
+        let multisig = MultisigV1::load(&multisig_data)?;
+
+        let Some(signer_index) = multisig.signer_index(self.accounts.signer.key) else {
+            msg!(
+                "Unauthorized: {} is not a multisig signer",
+                self.accounts.signer.key
+            );
+            return Err(ProgramError::IllegalOwner);
+        };
+
+        let mut proposal_data = self.accounts.multisig_proposal_pda.try_borrow_mut_data()?;
+        let proposal = MultisigProposalV1::load_mut(&mut proposal_data)?;
+
+        if !proposal.approve(signer_index) {
+            msg!(
+                "{} already approved this proposal",
+                self.accounts.signer.key
+            );
+            return Ok(());
+        }
+
+        msg!(
+            "Multisig proposal #{} approved: {} of {}",
+            proposal.index,
+            proposal.approval_count(),
+            proposal.threshold
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for ApproveMultisigProposalV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.approve()
+    }
+}
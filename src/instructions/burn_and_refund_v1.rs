@@ -4,7 +4,7 @@ use solana_program::{
 };
 
 use crate::{
-    states::{NftAuthorityV1, ProjectV1, VaultV1, VestingMode},
+    states::{NftAuthorityV1, PauseFlag, ProjectV1, VaultV1, VestingMode},
     utils::{
         AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck,
         BurnMplCoreAssetAccounts, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
@@ -19,7 +19,7 @@ pub struct BurnAndRefundV1Accounts<'a, 'info> {
     /// Must be owner of `nft_token_account`.
     pub payer: &'a AccountInfo<'info>,
 
-    /// User's ATA — receives refund.
+    /// User's ATA for 'escrow_mint' — receives refund.
     /// Must be writable, owned by `token_program`.
     pub payer_ata: &'a AccountInfo<'info>,
 
@@ -28,10 +28,10 @@ pub struct BurnAndRefundV1Accounts<'a, 'info> {
     pub project_pda: &'a AccountInfo<'info>,
 
     /// PDA: `["vault_v1", nft_asset, nft_collection, token_mint, program_id]` — escrow state.
-    /// Must be readable.
+    /// Must be readable. `VaultV1::LEGACY_LEN` vaults are refunded in `token_mint`.
     pub vault_pda: &'a AccountInfo<'info>,
 
-    /// Vault's ATA — source of refund token_mint.
+    /// Vault's ATA for 'escrow_mint' — source of refund.
     /// Must be writable, owned by `token_program`.
     pub vault_ata: &'a AccountInfo<'info>,
 
@@ -62,13 +62,17 @@ pub struct BurnAndRefundV1Accounts<'a, 'info> {
     /// Metaplex Core program — for NFT minting.
     /// Must be the official MPL Core program.
     pub mpl_core: &'a AccountInfo<'info>,
+
+    /// Token mint escrowed in the vault — `token_mint`, or the payment mint used at mint time.
+    /// Must match `vault.mint`. `NATIVE_MINT` refunds lamports held by `vault_pda`.
+    pub escrow_mint: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for BurnAndRefundV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, payer_ata, project_pda, vault_pda, vault_ata, nft_authority, nft_collection, nft_asset, token_mint, token_program, system_program, mpl_core] =
+        let [payer, payer_ata, project_pda, vault_pda, vault_ata, nft_authority, nft_collection, nft_asset, token_mint, token_program, system_program, mpl_core, escrow_mint] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -85,11 +89,24 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for BurnAndRefundV1Accounts<'a
         VaultAccount::check(vault_pda)?;
         ProjectAccount::check(project_pda)?;
         MintAccount::check(token_mint)?;
+        MintAccount::check(escrow_mint)?;
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
-        AssociatedTokenAccount::check(payer_ata, payer.key, token_mint.key, token_program.key)?;
-        AssociatedTokenAccount::check(vault_ata, vault_pda.key, token_mint.key, token_program.key)?;
+        if !TokenProgram::is_native(escrow_mint.key) {
+            AssociatedTokenAccount::check(
+                payer_ata,
+                payer.key,
+                escrow_mint.key,
+                token_program.key,
+            )?;
+            AssociatedTokenAccount::check(
+                vault_ata,
+                vault_pda.key,
+                escrow_mint.key,
+                token_program.key,
+            )?;
+        }
 
         Ok(Self {
             payer,
@@ -104,6 +121,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for BurnAndRefundV1Accounts<'a
             token_program,
             system_program,
             mpl_core,
+            escrow_mint,
         })
     }
 }
@@ -174,6 +192,15 @@ impl<'a, 'info> BurnAndRefundV1<'a, 'info> {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        if vault.mint != *self.accounts.escrow_mint.key {
+            msg!(
+                "Escrow mint mismatch. Expected {}, got {}",
+                vault.mint,
+                self.accounts.escrow_mint.key,
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         match project.vesting_mode {
             VestingMode::None => Ok(()),
             VestingMode::Permanent => {
@@ -209,6 +236,20 @@ impl<'a, 'info> BurnAndRefundV1<'a, 'info> {
     }
 
     fn refund_token(&self, project: &ProjectV1, balance: u64) -> ProgramResult {
+        if TokenProgram::is_native(self.accounts.escrow_mint.key) {
+            return SystemProgram::withdraw_from_pda(
+                self.accounts.vault_pda,
+                self.accounts.payer,
+                balance,
+            );
+        }
+
+        let decimals = if self.accounts.escrow_mint.key == self.accounts.token_mint.key {
+            project.mint_decimals
+        } else {
+            TokenProgram::get_decimal(self.accounts.escrow_mint)?
+        };
+
         let signers_seeds: &[&[&[u8]]] = &[&[
             VaultV1::SEED,
             self.accounts.nft_asset.key.as_ref(),
@@ -222,12 +263,12 @@ impl<'a, 'info> BurnAndRefundV1<'a, 'info> {
                 source: self.accounts.vault_ata,
                 destination: self.accounts.payer_ata,
                 authority: self.accounts.vault_pda,
-                mint: self.accounts.token_mint,
+                mint: self.accounts.escrow_mint,
                 token_program: self.accounts.token_program,
             },
             TokenTransferArgs {
                 amount: balance,
-                decimals: project.mint_decimals,
+                decimals,
             },
             signers_seeds,
         )
@@ -242,13 +283,15 @@ impl<'a, 'info> BurnAndRefundV1<'a, 'info> {
             &[self.vault_bump],
         ];
 
-        SystemProgram::close_ata(
-            self.accounts.vault_ata,
-            self.accounts.payer,
-            self.accounts.vault_pda,
-            self.accounts.token_program,
-            vault_seeds,
-        )?;
+        if !TokenProgram::is_native(self.accounts.escrow_mint.key) {
+            SystemProgram::close_ata(
+                self.accounts.vault_ata,
+                self.accounts.payer,
+                self.accounts.vault_pda,
+                self.accounts.token_program,
+                vault_seeds,
+            )?;
+        }
 
         SystemProgram::close_account_pda(self.accounts.vault_pda, self.accounts.payer)
     }
@@ -259,10 +302,12 @@ impl<'a, 'info> ProcessInstruction for BurnAndRefundV1<'a, 'info> {
         let project_data = self.accounts.project_pda.try_borrow_data()?;
         let project = ProjectV1::load(project_data.as_ref())?;
 
+        PauseFlag::BurnAndRefund.check(project.paused)?;
+
         let amount = {
             let vault_data = self.accounts.vault_pda.try_borrow_data()?;
-            let vault = VaultV1::load(vault_data.as_ref())?;
-            self.check_vesting(project, vault)?;
+            let vault = VaultV1::read(vault_data.as_ref(), project)?;
+            self.check_vesting(project, &vault)?;
             vault.amount
         };
 
@@ -0,0 +1,110 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::AdminProposalV1,
+    utils::{
+        AccountCheck, AdminProposalAccount, Pda, ProcessInstruction, SignerAccount, SystemProgram,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct CancelAdminProposalV1Accounts<'a, 'info> {
+    /// Current admin of `target_pda` — must sign. Receives the proposal rent.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// The `ProjectV1` or `TraitItemV1` PDA the proposal refers to.
+    /// Must be owned by program.
+    pub target_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["admin_proposal_v1", target_pda, program_id]` — stores the pending admin.
+    /// Must be initialized and writable. Closed on cancel.
+    pub admin_proposal_pda: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for CancelAdminProposalV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, target_pda, admin_proposal_pda] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(admin_proposal_pda)?;
+
+        AdminProposalAccount::check(admin_proposal_pda)?;
+
+        Ok(Self {
+            admin,
+            target_pda,
+            admin_proposal_pda,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CancelAdminProposalV1<'a, 'info> {
+    pub accounts: CancelAdminProposalV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)>
+    for CancelAdminProposalV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CancelAdminProposalV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.admin_proposal_pda,
+            &[AdminProposalV1::SEED, accounts.target_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> CancelAdminProposalV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let proposal_data = self.accounts.admin_proposal_pda.try_borrow_data()?;
+        let proposal = AdminProposalV1::load(&proposal_data)?;
+
+        proposal.target_kind.check(self.accounts.target_pda)?;
+
+        let target_data = self.accounts.target_pda.try_borrow_data()?;
+
+        if proposal.target_kind.admin(&target_data)? != *self.accounts.admin.key {
+            msg!("Unauthorized: only the current admin may cancel an admin proposal");
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        Ok(())
+    }
+
+    fn close_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.admin_proposal_pda, self.accounts.admin)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for CancelAdminProposalV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.close_proposal()?;
+
+        msg!(
+            "Cancelled admin proposal for {}",
+            self.accounts.target_pda.key
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{MultisigAction, ProjectRole, ProjectUpdateV1, ProjectV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, ProjectMultisig, ProjectMultisigCheck, ProjectUpdateAccount,
+        SignerAccount, SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct CancelProjectUpdateV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    /// Under multisig mode any signer may execute an approved proposal.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_update_v1", project_pda, program_id]` — the pending update.
+    /// Must be initialized and writable. Closed on cancel.
+    pub project_update_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that queued the update — receives its rent.
+    /// Must match `project_update.queued_by`.
+    pub queued_by: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for CancelProjectUpdateV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, project_update_pda, queued_by, nft_collection, token_mint, multisig_proposal_pda, proposer] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(project_update_pda)?;
+        WritableAccount::check(queued_by)?;
+
+        ProjectAccount::check(project_pda)?;
+        ProjectUpdateAccount::check(project_update_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            project_update_pda,
+            queued_by,
+            nft_collection,
+            token_mint,
+            multisig_proposal_pda,
+            proposer,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CancelProjectUpdateV1<'a, 'info> {
+    pub accounts: CancelProjectUpdateV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)>
+    for CancelProjectUpdateV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CancelProjectUpdateV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.project_update_pda,
+            &[ProjectUpdateV1::SEED, accounts.project_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> CancelProjectUpdateV1<'a, 'info> {
+    fn check_authority(&self) -> Result<bool, ProgramError> {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        if project.has_multisig() {
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::CancelProjectUpdate,
+                &[],
+            )?;
+
+            return Ok(true);
+        }
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        Ok(false)
+    }
+
+    fn check_queued_by(&self) -> ProgramResult {
+        let update_data = self.accounts.project_update_pda.try_borrow_data()?;
+        let project_update = ProjectUpdateV1::load(&update_data)?;
+
+        if project_update.queued_by != *self.accounts.queued_by.key {
+            msg!("Rent receiver does not match the wallet that queued the update");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    fn close_project_update(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.project_update_pda, self.accounts.queued_by)
+    }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for CancelProjectUpdateV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let via_multisig = self.check_authority()?;
+        self.check_queued_by()?;
+        self.close_project_update()?;
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        msg!(
+            "Cancelled pending project update for {}",
+            self.accounts.project_pda.key
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{CouponV1, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, CouponAccount, MintAccount, Pda, ProcessInstruction, ProjectAccount,
+        ProjectAuthority, ProjectAuthorityCheck, SignerAccount, SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct CloseCouponV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::Treasurer`.
+    /// Receives the rent of the closed coupon account.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["coupon_v1", project_pda, code, program_id]` — the coupon.
+    /// Must be initialized and writable. Closed by this instruction.
+    pub coupon_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for CloseCouponV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, coupon_pda, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(coupon_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        CouponAccount::check(coupon_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            coupon_pda,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CloseCouponV1<'a, 'info> {
+    pub accounts: CloseCouponV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)> for CloseCouponV1<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CloseCouponV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> CloseCouponV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Treasurer,
+        )
+    }
+
+    fn check_coupon(&self) -> ProgramResult {
+        let coupon_data = self.accounts.coupon_pda.try_borrow_data()?;
+        let coupon = CouponV1::load(&coupon_data)?;
+
+        if coupon.project != *self.accounts.project_pda.key {
+            msg!(
+                "Coupon belongs to project {}, not {}",
+                coupon.project,
+                self.accounts.project_pda.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    fn close_coupon(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.coupon_pda, self.accounts.admin)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for CloseCouponV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.check_coupon()?;
+        self.close_coupon()?;
+
+        msg!("Closed coupon {}", self.accounts.coupon_pda.key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{CouponKind, CouponV1, InitCouponAccounts, InitCouponArgs, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, SignerAccount, SystemProgram,
+        UninitializedAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct CreateCouponV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the coupon account.
+    /// Must match `project.admin` or hold `ProjectRole::Treasurer`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["coupon_v1", project_pda, code, program_id]` — stores the coupon.
+    /// Must be writable and uninitialized.
+    pub coupon_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for CreateCouponV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, coupon_pda, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(coupon_pda)?;
+
+        UninitializedAccount::check(coupon_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            coupon_pda,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CreateCouponV1InstructionData {
+    /// Coupon code, part of the coupon PDA seeds.
+    pub code: [u8; 32],
+    pub kind: CouponKind,
+    /// Basis points of the price for `CouponKind::Percent`, raw `token_mint` units for `CouponKind::Fixed`.
+    pub amount: u64,
+    /// Maximum number of redemptions; `0` is unlimited.
+    pub max_redemptions: u64,
+    /// UNIX timestamp after which the coupon is unusable; `0` never expires.
+    pub expires_at: i64,
+    /// Only wallet allowed to redeem; `Pubkey::default()` allows any payer.
+    pub wallet: Pubkey,
+}
+
+#[derive(Debug)]
+pub struct CreateCouponV1<'a, 'info> {
+    pub accounts: CreateCouponV1Accounts<'a, 'info>,
+    pub instruction_data: CreateCouponV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        CreateCouponV1InstructionData,
+        &'a Pubkey,
+    )> for CreateCouponV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            CreateCouponV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CreateCouponV1Accounts::try_from(accounts)?;
+
+        CouponV1::check_args(instruction_data.kind, instruction_data.amount)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.coupon_pda,
+            &[
+                CouponV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                instruction_data.code.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> CreateCouponV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Treasurer,
+        )
+    }
+
+    fn init_coupon(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            CouponV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.instruction_data.code.as_ref(),
+        ];
+
+        CouponV1::init(
+            InitCouponAccounts {
+                pda: self.accounts.coupon_pda,
+            },
+            InitCouponArgs {
+                project: *self.accounts.project_pda.key,
+                wallet: self.instruction_data.wallet,
+                code: self.instruction_data.code,
+                kind: self.instruction_data.kind,
+                amount: self.instruction_data.amount,
+                max_redemptions: self.instruction_data.max_redemptions,
+                expires_at: self.instruction_data.expires_at,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.coupon_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: CouponV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for CreateCouponV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.init_coupon()?;
+
+        msg!(
+            "Created coupon {} ({:?} {}, max redemptions {}, expires at {})",
+            self.accounts.coupon_pda.key,
+            self.instruction_data.kind,
+            self.instruction_data.amount,
+            self.instruction_data.max_redemptions,
+            self.instruction_data.expires_at
+        );
+
+        Ok(())
+    }
+}
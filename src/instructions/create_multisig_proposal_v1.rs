@@ -0,0 +1,170 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{
+        InitMultisigProposalAccounts, InitMultisigProposalArgs, MultisigAction, MultisigProposalV1,
+        MultisigV1,
+    },
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MultisigAccount, Pda, ProcessInstruction,
+        SignerAccount, SystemProgram, UninitializedAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct CreateMultisigProposalV1Accounts<'a, 'info> {
+    /// Multisig signer creating the proposal — must sign and pay for the proposal account.
+    /// Counts as the first approval.
+    pub proposer: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_v1", project_pda, program_id]` — stores the signer set.
+    /// Must be writable, owned by program.
+    pub multisig_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, multisig.proposal_count, program_id]`.
+    /// Must be uninitialized and writable.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for CreateMultisigProposalV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [proposer, multisig_pda, multisig_proposal_pda, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(proposer)?;
+
+        WritableAccount::check(proposer)?;
+        WritableAccount::check(multisig_pda)?;
+        WritableAccount::check(multisig_proposal_pda)?;
+
+        UninitializedAccount::check(multisig_proposal_pda)?;
+
+        MultisigAccount::check(multisig_pda)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            proposer,
+            multisig_pda,
+            multisig_proposal_pda,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CreateMultisigProposalV1InstructionData {
+    pub action: MultisigAction,
+    /// `sha256` of the target instruction data, without its discriminator byte.
+    pub payload_hash: [u8; 32],
+}
+
+#[derive(Debug)]
+pub struct CreateMultisigProposalV1<'a, 'info> {
+    pub accounts: CreateMultisigProposalV1Accounts<'a, 'info>,
+    pub instruction_data: CreateMultisigProposalV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        CreateMultisigProposalV1InstructionData,
+        &'a Pubkey,
+    )> for CreateMultisigProposalV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            CreateMultisigProposalV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CreateMultisigProposalV1Accounts::try_from(accounts)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> CreateMultisigProposalV1<'a, 'info> {
+    fn init_proposal(&self, multisig: &mut MultisigV1) -> ProgramResult {
+        let Some(signer_index) = multisig.signer_index(self.accounts.proposer.key) else {
+            msg!(
+                "Unauthorized: {} is not a multisig signer",
+                self.accounts.proposer.key
+            );
+            return Err(ProgramError::IllegalOwner);
+        };
+
+        let index = multisig.next_proposal_index()?;
+        let index_bytes = index.to_le_bytes();
+
+        let seeds: &[&[u8]] = &[
+            MultisigProposalV1::SEED,
+            self.accounts.multisig_pda.key.as_ref(),
+            &index_bytes,
+        ];
+
+        Pda::validate(self.accounts.multisig_proposal_pda, seeds, self.program_id)?;
+
+        MultisigProposalV1::init(
+            InitMultisigProposalAccounts {
+                pda: self.accounts.multisig_proposal_pda,
+            },
+            InitMultisigProposalArgs {
+                multisig: *self.accounts.multisig_pda.key,
+                proposer: *self.accounts.proposer.key,
+                payload_hash: self.instruction_data.payload_hash,
+                index,
+                threshold: multisig.threshold,
+                action: self.instruction_data.action,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.proposer,
+                pda: self.accounts.multisig_proposal_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MultisigProposalV1::LEN,
+                program_id: self.program_id,
+            },
+        )?;
+
+        let mut proposal_data = self.accounts.multisig_proposal_pda.try_borrow_mut_data()?;
+        let proposal = MultisigProposalV1::load_mut(&mut proposal_data)?;
+        proposal.approve(signer_index);
+
+        msg!(
+            "Multisig proposal #{} created for {:?}",
+            index,
+            self.instruction_data.action
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for CreateMultisigProposalV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut multisig_data = self.accounts.multisig_pda.try_borrow_mut_data()?;
+        let multisig = MultisigV1::load_mut(&mut multisig_data)?;
+
+        self.init_proposal(multisig)
+    }
+}
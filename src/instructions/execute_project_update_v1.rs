@@ -0,0 +1,146 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{ProjectUpdateV1, ProjectV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectUpdateAccount,
+        SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct ExecuteProjectUpdateV1Accounts<'a, 'info> {
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must be writable.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_update_v1", project_pda, program_id]` — the pending update.
+    /// Must be initialized and writable. Closed after execution.
+    pub project_update_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that queued the update — receives its rent.
+    /// Must match `project_update.queued_by`.
+    pub queued_by: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for ExecuteProjectUpdateV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [project_pda, project_update_pda, queued_by, nft_collection, token_mint] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        WritableAccount::check(project_pda)?;
+        WritableAccount::check(project_update_pda)?;
+        WritableAccount::check(queued_by)?;
+
+        ProjectAccount::check(project_pda)?;
+        ProjectUpdateAccount::check(project_update_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            project_pda,
+            project_update_pda,
+            queued_by,
+            nft_collection,
+            token_mint,
+        })
+    }
+}
+
+/// Applies a queued project update once its `eta` has passed.
+///
+/// Permissionless: anyone may crank an update that has cleared the timelock.
+#[derive(Debug)]
+pub struct ExecuteProjectUpdateV1<'a, 'info> {
+    pub accounts: ExecuteProjectUpdateV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)>
+    for ExecuteProjectUpdateV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ExecuteProjectUpdateV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.project_update_pda,
+            &[ProjectUpdateV1::SEED, accounts.project_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> ExecuteProjectUpdateV1<'a, 'info> {
+    fn apply_update(&self) -> ProgramResult {
+        let update_data = self.accounts.project_update_pda.try_borrow_data()?;
+        let project_update = ProjectUpdateV1::load(&update_data)?;
+
+        if project_update.queued_by != *self.accounts.queued_by.key {
+            msg!("Rent receiver does not match the wallet that queued the update");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if !project_update.is_ready(now) {
+            msg!(
+                "Project update is timelocked until {} (now {})",
+                project_update.eta,
+                now
+            );
+            return Err(ProgramError::Custom(6));
+        }
+
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+        project.update(project_update.args);
+
+        Ok(())
+    }
+
+    fn close_project_update(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.project_update_pda, self.accounts.queued_by)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for ExecuteProjectUpdateV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.apply_update()?;
+        self.close_project_update()?;
+
+        msg!(
+            "Executed project update for {}",
+            self.accounts.project_pda.key
+        );
+
+        Ok(())
+    }
+}
@@ -4,9 +4,10 @@ use solana_program::{
 };
 
 use crate::{
-    states::{ProjectV1, VestingMode},
+    states::{MultisigAction, ProjectRole, ProjectV1, VestingMode},
     utils::{
-        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, SignerAccount,
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, ProjectMultisig, ProjectMultisigCheck, SignerAccount, SystemProgram,
         WritableAccount,
     },
 };
@@ -14,13 +15,18 @@ use crate::{
 #[derive(Debug)]
 pub struct ForceUnlockVestingV1Accounts<'a, 'info> {
     /// The project authority — must sign.
-    /// Must match `project.admin`.
+    /// Must match `project.admin` or hold `ProjectRole::VestingManager`.
+    /// Under multisig mode any signer may execute an approved proposal.
     pub admin: &'a AccountInfo<'info>,
 
     /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
     /// Must be writable.
     pub project_pda: &'a AccountInfo<'info>,
 
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
     /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
     /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
     pub token_mint: &'a AccountInfo<'info>,
@@ -28,13 +34,23 @@ pub struct ForceUnlockVestingV1Accounts<'a, 'info> {
     /// MPL Core Collection account that groups NFTs under this project.
     /// Determines the project scope for mint rules, royalties, and limits.
     pub nft_collection: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for ForceUnlockVestingV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [admin, project_pda, token_mint, nft_collection] = accounts else {
+        let [admin, project_pda, project_role_pda, token_mint, nft_collection, multisig_proposal_pda, proposer] =
+            accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -49,8 +65,11 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for ForceUnlockVestingV1Accoun
         Ok(Self {
             admin,
             project_pda,
+            project_role_pda,
             token_mint,
             nft_collection,
+            multisig_proposal_pda,
+            proposer,
         })
     }
 }
@@ -89,18 +108,44 @@ impl<'a, 'info> ProcessInstruction for ForceUnlockVestingV1<'a, 'info> {
         let mut project_data = self.accounts.project_pda.data.borrow_mut();
         let project = ProjectV1::load_mut(&mut project_data)?;
 
+        let via_multisig = self.check_authority(project)?;
         self.check_vesting(project)?;
-        self.unlock_vesting(project)
+        self.unlock_vesting(project)?;
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        Ok(())
     }
 }
 
 impl<'a, 'info> ForceUnlockVestingV1<'a, 'info> {
-    fn check_vesting(&self, project: &ProjectV1) -> ProgramResult {
-        if project.admin != *self.accounts.admin.key {
-            msg!("Unauthorized: only the project authority may trigger vesting unlocks.");
-            return Err(ProgramError::IllegalOwner);
+    fn check_authority(&self, project: &ProjectV1) -> Result<bool, ProgramError> {
+        if project.has_multisig() {
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::ForceUnlockVesting,
+                &[],
+            )?;
+
+            return Ok(true);
         }
 
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::VestingManager,
+        )?;
+
+        Ok(false)
+    }
+
+    fn check_vesting(&self, project: &ProjectV1) -> ProgramResult {
         match project.vesting_mode {
             VestingMode::None => {
                 msg!("Vesting unlock denied: vesting mode is disabled (None).");
@@ -133,4 +178,11 @@ impl<'a, 'info> ForceUnlockVestingV1<'a, 'info> {
 
         Ok(())
     }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
 }
@@ -0,0 +1,202 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{InitProjectRoleAccounts, InitProjectRoleArgs, ProjectRole, ProjectRoleV1, ProjectV1},
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectRoleAccount, SignerAccount, SystemProgram,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct GrantProjectRoleV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the role account.
+    /// Must match `project.admin`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, wallet, program_id]` — stores granted roles.
+    /// Must be writable. Created on first grant.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// Wallet receiving the roles.
+    pub wallet: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for GrantProjectRoleV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, wallet, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(project_role_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            wallet,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct GrantProjectRoleV1InstructionData {
+    /// Bitmask of `ProjectRole` values to add.
+    pub roles: u8,
+}
+
+#[derive(Debug)]
+pub struct GrantProjectRoleV1<'a, 'info> {
+    pub accounts: GrantProjectRoleV1Accounts<'a, 'info>,
+    pub instruction_data: GrantProjectRoleV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        GrantProjectRoleV1InstructionData,
+        &'a Pubkey,
+    )> for GrantProjectRoleV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            GrantProjectRoleV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = GrantProjectRoleV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.project_role_pda,
+            &[
+                ProjectRoleV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.wallet.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> GrantProjectRoleV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check_admin(self.accounts.admin, &project.admin)?;
+
+        if !ProjectRole::is_valid_mask(self.instruction_data.roles) {
+            msg!("Invalid role mask {:#010b}", self.instruction_data.roles);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    fn init_project_role_if_needed(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            ProjectRoleV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.accounts.wallet.key.as_ref(),
+        ];
+
+        ProjectRoleV1::init_if_needed(
+            InitProjectRoleAccounts {
+                pda: self.accounts.project_role_pda,
+            },
+            InitProjectRoleArgs {
+                project: *self.accounts.project_pda.key,
+                wallet: *self.accounts.wallet.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.project_role_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: ProjectRoleV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn grant_roles(&self) -> ProgramResult {
+        ProjectRoleAccount::check(self.accounts.project_role_pda)?;
+
+        let mut role_data = self.accounts.project_role_pda.try_borrow_mut_data()?;
+        let project_role = ProjectRoleV1::load_mut(&mut role_data)?;
+        project_role.grant(self.instruction_data.roles);
+
+        msg!(
+            "Granted roles {:#010b} to {} (now {:#010b})",
+            self.instruction_data.roles,
+            self.accounts.wallet.key,
+            project_role.roles
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for GrantProjectRoleV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.init_project_role_if_needed()?;
+        self.grant_roles()
+    }
+}
@@ -0,0 +1,209 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{InitVipPassAccounts, InitVipPassArgs, ProjectRole, ProjectV1, VipPassV1},
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, SignerAccount, SystemProgram,
+        VipPassAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct GrantVipPassV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the pass account.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["vip_pass_v1", project_pda, wallet, program_id]` — stores the VIP pass.
+    /// Must be writable. Created on first grant, updated afterwards.
+    pub vip_pass_pda: &'a AccountInfo<'info>,
+
+    /// Wallet receiving the VIP pass.
+    pub wallet: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for GrantVipPassV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, vip_pass_pda, wallet, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(vip_pass_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            vip_pass_pda,
+            wallet,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct GrantVipPassV1InstructionData {
+    /// Allocation override; `0` uses `project.max_mint_per_vip_user`.
+    pub max_mint: u64,
+    /// UNIX timestamp after which the pass is unusable; `0` never expires.
+    pub expires_at: i64,
+}
+
+#[derive(Debug)]
+pub struct GrantVipPassV1<'a, 'info> {
+    pub accounts: GrantVipPassV1Accounts<'a, 'info>,
+    pub instruction_data: GrantVipPassV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        GrantVipPassV1InstructionData,
+        &'a Pubkey,
+    )> for GrantVipPassV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            GrantVipPassV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = GrantVipPassV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.vip_pass_pda,
+            &[
+                VipPassV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.wallet.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> GrantVipPassV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )
+    }
+
+    fn init_vip_pass_if_needed(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            VipPassV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.accounts.wallet.key.as_ref(),
+        ];
+
+        VipPassV1::init_if_needed(
+            InitVipPassAccounts {
+                pda: self.accounts.vip_pass_pda,
+            },
+            InitVipPassArgs {
+                project: *self.accounts.project_pda.key,
+                wallet: *self.accounts.wallet.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.vip_pass_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: VipPassV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn set_vip_pass(&self) -> ProgramResult {
+        VipPassAccount::check(self.accounts.vip_pass_pda)?;
+
+        let mut vip_pass_data = self.accounts.vip_pass_pda.try_borrow_mut_data()?;
+        let vip_pass = VipPassV1::load_mut(&mut vip_pass_data)?;
+        vip_pass.max_mint = self.instruction_data.max_mint;
+        vip_pass.expires_at = self.instruction_data.expires_at;
+
+        msg!(
+            "Granted VIP pass to {} (max mint {}, expires at {})",
+            self.accounts.wallet.key,
+            vip_pass.max_mint,
+            vip_pass.expires_at
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for GrantVipPassV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.init_vip_pass_if_needed()?;
+        self.set_vip_pass()
+    }
+}
@@ -0,0 +1,208 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{InitMultisigAccounts, InitMultisigArgs, MultisigV1, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, SignerAccount, SystemProgram,
+        UninitializedAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct InitMultisigV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the multisig account.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be writable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_v1", project_pda, program_id]` — stores the signer set.
+    /// Must be uninitialized and writable.
+    pub multisig_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitMultisigV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, multisig_pda, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(project_pda)?;
+        WritableAccount::check(multisig_pda)?;
+
+        UninitializedAccount::check(multisig_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            multisig_pda,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitMultisigV1InstructionData {
+    pub threshold: u8,
+    pub num_signers: u8,
+    pub signers: [Pubkey; 10],
+}
+
+#[derive(Debug)]
+pub struct InitMultisigV1<'a, 'info> {
+    pub accounts: InitMultisigV1Accounts<'a, 'info>,
+    pub instruction_data: InitMultisigV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        InitMultisigV1InstructionData,
+        &'a Pubkey,
+    )> for InitMultisigV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            InitMultisigV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitMultisigV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.multisig_pda,
+            &[MultisigV1::SEED, accounts.project_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> InitMultisigV1<'a, 'info> {
+    fn check_authority(&self, project: &ProjectV1) -> ProgramResult {
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        if project.has_multisig() {
+            msg!("Project already guarded by multisig {}", project.multisig);
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        Ok(())
+    }
+
+    fn check_signers(&self) -> ProgramResult {
+        MultisigV1::check_signers(
+            self.instruction_data.threshold,
+            self.instruction_data.num_signers,
+            self.instruction_data.signers,
+        )
+    }
+
+    fn init_multisig(&self, project: &mut ProjectV1) -> ProgramResult {
+        let seeds: &[&[u8]] = &[MultisigV1::SEED, self.accounts.project_pda.key.as_ref()];
+
+        MultisigV1::init(
+            InitMultisigAccounts {
+                pda: self.accounts.multisig_pda,
+            },
+            InitMultisigArgs {
+                project: *self.accounts.project_pda.key,
+                signers: self.instruction_data.signers,
+                threshold: self.instruction_data.threshold,
+                num_signers: self.instruction_data.num_signers,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.multisig_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MultisigV1::LEN,
+                program_id: self.program_id,
+            },
+        )?;
+
+        project.multisig = *self.accounts.multisig_pda.key;
+
+        msg!(
+            "Multisig enabled: {} of {} signers",
+            self.instruction_data.threshold,
+            self.instruction_data.num_signers
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for InitMultisigV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+
+        self.check_authority(project)?;
+        self.check_signers()?;
+        self.init_multisig(project)
+    }
+}
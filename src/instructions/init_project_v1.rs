@@ -5,11 +5,14 @@ use solana_program::{
 };
 
 use crate::{
-    states::{InitProjectAccounts, InitProjectArgs, NftAuthorityV1, ProjectV1, VestingMode},
+    states::{
+        InitProjectAccounts, InitProjectArgs, NftAuthorityV1, Pricing, ProjectV1, ProtocolConfigV1,
+        VestingMode, VipGateArgs,
+    },
     utils::{
         AccountCheck, InitMplCoreCollectionAccounts, InitMplCoreCollectionArgs, InitPdaAccounts,
-        InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction, SignerAccount,
-        SystemProgram, TokenProgram, UninitializedAccount, WritableAccount,
+        InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction, ProtocolConfigAccount,
+        SignerAccount, SystemProgram, TokenProgram, UninitializedAccount, WritableAccount,
     },
 };
 
@@ -37,6 +40,10 @@ pub struct InitProjectV1Accounts<'a, 'info> {
     /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
     pub token_mint: &'a AccountInfo<'info>,
 
+    /// PDA: `["protocol_config_v1", program_id]` — supplies default protocol fees.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// System program — required for PDA creation and rent.
     pub system_program: &'a AccountInfo<'info>,
 
@@ -49,7 +56,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitProjectV1Accounts<'a,
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [admin, project_pda, nft_authority, nft_collection, token_mint, system_program, mpl_core] =
+        let [admin, project_pda, nft_authority, nft_collection, token_mint, protocol_config_pda, system_program, mpl_core] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -63,6 +70,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitProjectV1Accounts<'a,
         UninitializedAccount::check(nft_collection)?;
 
         MintAccount::check(token_mint)?;
+        ProtocolConfigAccount::check(protocol_config_pda)?;
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
@@ -72,6 +80,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitProjectV1Accounts<'a,
             nft_authority,
             nft_collection,
             token_mint,
+            protocol_config_pda,
             system_program,
             mpl_core,
         })
@@ -93,9 +102,15 @@ pub struct InitProjectV1InstructionData {
     pub num_revenue_wallets: u8,
     pub revenue_wallets: [Pubkey; 5],
     pub revenue_shares: [u64; 5],
+    pub pricing: Pricing,
     pub num_royalty_recipients: u8,
     pub royalty_recipients: [Pubkey; 5],
     pub royalty_shares_bps: [u16; 5],
+    pub vip_merkle_root: [u8; 32],
+    pub vip_gate: VipGateArgs,
+    pub reveal_root: [u8; 32],
+    pub vip_supply: u64,
+    pub vip_rollover_ts: i64,
     pub collection_name: String,
     pub collection_uri: String,
 }
@@ -126,6 +141,11 @@ impl<'a, 'info>
         let accounts = InitProjectV1Accounts::try_from(accounts)?;
 
         Pda::validate(accounts.nft_authority, &[NftAuthorityV1::SEED], program_id)?;
+        Pda::validate(
+            accounts.protocol_config_pda,
+            &[ProtocolConfigV1::SEED],
+            program_id,
+        )?;
 
         Ok(Self {
             accounts,
@@ -137,18 +157,30 @@ impl<'a, 'info>
 
 impl<'a, 'info> InitProjectV1<'a, 'info> {
     fn check_project_data(&self) -> ProgramResult {
+        let protocol_config_data = self.accounts.protocol_config_pda.try_borrow_data()?;
+        let protocol_config = ProtocolConfigV1::load(&protocol_config_data)?;
+
         ProjectV1::check_revenue_wallets(
             self.instruction_data.mint_price_total,
             self.instruction_data.escrow_amount,
             self.instruction_data.num_revenue_wallets,
             self.instruction_data.revenue_wallets,
             self.instruction_data.revenue_shares,
+            protocol_config.protocol_fee_bps,
         )?;
+        self.instruction_data
+            .pricing
+            .check(self.instruction_data.mint_price_total)?;
         ProjectV1::check_nft_royalties(
             self.instruction_data.num_royalty_recipients,
             self.instruction_data.royalty_recipients,
             self.instruction_data.royalty_shares_bps,
-        )
+        )?;
+        ProjectV1::check_vip_supply(
+            self.instruction_data.released,
+            self.instruction_data.vip_supply,
+        )?;
+        ProjectV1::check_vip_gate(&self.instruction_data.vip_gate)
     }
 
     fn init_project(&self) -> ProgramResult {
@@ -159,6 +191,9 @@ impl<'a, 'info> InitProjectV1<'a, 'info> {
         ];
         let decimals = TokenProgram::get_decimal(self.accounts.token_mint)?;
 
+        let protocol_config_data = self.accounts.protocol_config_pda.try_borrow_data()?;
+        let protocol_config = ProtocolConfigV1::load(&protocol_config_data)?;
+
         ProjectV1::init_if_needed(
             InitProjectAccounts {
                 pda: self.accounts.project_pda,
@@ -173,15 +208,29 @@ impl<'a, 'info> InitProjectV1<'a, 'info> {
                 mint_price_total: self.instruction_data.mint_price_total,
                 admin_minted: 0,
                 user_minted: 0,
+                vip_minted: 0,
                 vesting_mode: self.instruction_data.vesting_mode,
                 vesting_unlock_ts: self.instruction_data.vesting_unlock_ts,
-                mint_nft_fee_lamports: self.instruction_data.mint_nft_fee_lamports,
-                update_nft_fee_lamports: self.instruction_data.update_nft_fee_lamports,
+                mint_nft_fee_lamports: ProtocolConfigV1::fee_or_default(
+                    self.instruction_data.mint_nft_fee_lamports,
+                    protocol_config.default_mint_nft_fee_lamports,
+                ),
+                update_nft_fee_lamports: ProtocolConfigV1::fee_or_default(
+                    self.instruction_data.update_nft_fee_lamports,
+                    protocol_config.default_update_nft_fee_lamports,
+                ),
                 escrow_amount: self.instruction_data.escrow_amount,
                 mint_decimals: decimals,
                 num_revenue_wallets: self.instruction_data.num_revenue_wallets,
                 revenue_wallets: self.instruction_data.revenue_wallets,
                 revenue_shares: self.instruction_data.revenue_shares,
+                pricing: self.instruction_data.pricing,
+                protocol_fee_bps: protocol_config.protocol_fee_bps,
+                vip_merkle_root: self.instruction_data.vip_merkle_root,
+                vip_gate: self.instruction_data.vip_gate,
+                reveal_root: self.instruction_data.reveal_root,
+                vip_supply: self.instruction_data.vip_supply,
+                vip_rollover_ts: self.instruction_data.vip_rollover_ts,
             },
             InitPdaAccounts {
                 payer: self.accounts.admin,
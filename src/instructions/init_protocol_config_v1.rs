@@ -0,0 +1,159 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{InitProtocolConfigAccounts, InitProtocolConfigArgs, ProtocolConfigV1},
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, ProcessInstruction, SignerAccount,
+        SystemProgram, UninitializedAccount, UpgradeAuthority, UpgradeAuthorityCheck,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct InitProtocolConfigV1Accounts<'a, 'info> {
+    /// Program upgrade authority — must sign and pay. Becomes `protocol_config.authority`.
+    pub authority: &'a AccountInfo<'info>,
+
+    /// PDA: `["protocol_config_v1", program_id]` — stores the protocol config.
+    /// Must be uninitialized and writable.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that will receive SOL protocol fees.
+    /// Must be owned by the system program.
+    pub fee_wallet: &'a AccountInfo<'info>,
+
+    /// PDA: `[program_id]` of the BPF upgradeable loader — this program's `ProgramData`.
+    /// Used to verify `authority`.
+    pub program_data: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitProtocolConfigV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [authority, protocol_config_pda, fee_wallet, program_data, system_program] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+
+        WritableAccount::check(authority)?;
+        WritableAccount::check(protocol_config_pda)?;
+
+        UninitializedAccount::check(protocol_config_pda)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            authority,
+            protocol_config_pda,
+            fee_wallet,
+            program_data,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitProtocolConfigV1InstructionData {
+    pub default_mint_nft_fee_lamports: u64,
+    pub default_update_nft_fee_lamports: u64,
+    pub default_trait_mint_fee_lamports: u64,
+    pub protocol_fee_bps: u16,
+}
+
+#[derive(Debug)]
+pub struct InitProtocolConfigV1<'a, 'info> {
+    pub accounts: InitProtocolConfigV1Accounts<'a, 'info>,
+    pub instruction_data: InitProtocolConfigV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        InitProtocolConfigV1InstructionData,
+        &'a Pubkey,
+    )> for InitProtocolConfigV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            InitProtocolConfigV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitProtocolConfigV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.protocol_config_pda,
+            &[ProtocolConfigV1::SEED],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> InitProtocolConfigV1<'a, 'info> {
+    fn init_protocol_config(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[ProtocolConfigV1::SEED];
+
+        ProtocolConfigV1::init(
+            InitProtocolConfigAccounts {
+                pda: self.accounts.protocol_config_pda,
+            },
+            InitProtocolConfigArgs {
+                authority: *self.accounts.authority.key,
+                fee_wallet: *self.accounts.fee_wallet.key,
+                default_mint_nft_fee_lamports: self.instruction_data.default_mint_nft_fee_lamports,
+                default_update_nft_fee_lamports: self
+                    .instruction_data
+                    .default_update_nft_fee_lamports,
+                default_trait_mint_fee_lamports: self
+                    .instruction_data
+                    .default_trait_mint_fee_lamports,
+                protocol_fee_bps: self.instruction_data.protocol_fee_bps,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.authority,
+                pda: self.accounts.protocol_config_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: ProtocolConfigV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for InitProtocolConfigV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        UpgradeAuthority::check(self.accounts.authority, self.accounts.program_data)?;
+        ProtocolConfigV1::check_fee_wallet(self.accounts.fee_wallet)?;
+        ProtocolConfigV1::check_protocol_fee_bps(self.instruction_data.protocol_fee_bps)?;
+        self.init_protocol_config()?;
+
+        msg!(
+            "Protocol config initialized with fee wallet {}",
+            self.accounts.fee_wallet.key
+        );
+
+        Ok(())
+    }
+}
@@ -5,11 +5,13 @@ use solana_program::{
 };
 
 use crate::{
-    states::{InitTraitItemAccounts, InitTraitItemArgs, TraitAuthorityV1, TraitItemV1},
+    states::{
+        InitTraitItemAccounts, InitTraitItemArgs, ProtocolConfigV1, TraitAuthorityV1, TraitItemV1,
+    },
     utils::{
         AccountCheck, InitMplCoreCollectionAccounts, InitMplCoreCollectionArgs, InitPdaAccounts,
-        InitPdaArgs, MplCoreProgram, Pda, ProcessInstruction, SignerAccount, SystemProgram,
-        UninitializedAccount, WritableAccount,
+        InitPdaArgs, MplCoreProgram, Pda, ProcessInstruction, ProtocolConfigAccount, SignerAccount,
+        SystemProgram, UninitializedAccount, WritableAccount,
     },
 };
 
@@ -33,6 +35,10 @@ pub struct InitTraitV1Accounts<'a, 'info> {
     /// Determines the project scope for mint rules, royalties, and limits.
     pub trait_collection: &'a AccountInfo<'info>,
 
+    /// PDA: `["protocol_config_v1", program_id]` — supplies default protocol fees.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// System program — required for PDA creation and rent.
     pub system_program: &'a AccountInfo<'info>,
 
@@ -45,7 +51,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitTraitV1Accounts<'a, 'i
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [authority, trait_pda, trait_authority, trait_collection, system_program, mpl_core] =
+        let [authority, trait_pda, trait_authority, trait_collection, protocol_config_pda, system_program, mpl_core] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -59,6 +65,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitTraitV1Accounts<'a, 'i
 
         UninitializedAccount::check(trait_collection)?;
 
+        ProtocolConfigAccount::check(protocol_config_pda)?;
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
@@ -67,6 +74,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for InitTraitV1Accounts<'a, 'i
             trait_pda,
             trait_authority,
             trait_collection,
+            protocol_config_pda,
             system_program,
             mpl_core,
         })
@@ -114,6 +122,11 @@ impl<'a, 'info>
             &[TraitAuthorityV1::SEED],
             program_id,
         )?;
+        Pda::validate(
+            accounts.protocol_config_pda,
+            &[ProtocolConfigV1::SEED],
+            program_id,
+        )?;
 
         Ok(Self {
             accounts,
@@ -138,6 +151,9 @@ impl<'a, 'info> InitTraitV1<'a, 'info> {
             self.accounts.trait_collection.key.as_ref(),
         ];
 
+        let protocol_config_data = self.accounts.protocol_config_pda.try_borrow_data()?;
+        let protocol_config = ProtocolConfigV1::load(&protocol_config_data)?;
+
         TraitItemV1::init_if_needed(
             InitTraitItemAccounts {
                 pda: self.accounts.trait_pda,
@@ -146,7 +162,10 @@ impl<'a, 'info> InitTraitV1<'a, 'info> {
                 authority: *self.accounts.authority.key,
                 max_supply: self.instruction_data.max_supply,
                 user_minted: 0,
-                mint_fee_lamports: self.instruction_data.mint_fee_lamports,
+                mint_fee_lamports: ProtocolConfigV1::fee_or_default(
+                    self.instruction_data.mint_fee_lamports,
+                    protocol_config.default_trait_mint_fee_lamports,
+                ),
             },
             InitPdaAccounts {
                 payer: self.accounts.authority,
@@ -0,0 +1,123 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    states::ProjectV1,
+    utils::{
+        AccountCheck, LegacyProjectAccount, MintAccount, Pda, ProcessInstruction, SignerAccount,
+        SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct MigrateProjectV1Accounts<'a, 'info> {
+    /// Any wallet — must sign and pays the rent of the grown account.
+    pub payer: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — still `ProjectV1::LEGACY_LEN` long.
+    /// Must be writable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — funds the rent top-up.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MigrateProjectV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [payer, project_pda, nft_collection, token_mint, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(payer)?;
+
+        WritableAccount::check(payer)?;
+        WritableAccount::check(project_pda)?;
+
+        LegacyProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            payer,
+            project_pda,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct MigrateProjectV1<'a, 'info> {
+    pub accounts: MigrateProjectV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)> for MigrateProjectV1<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = MigrateProjectV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> MigrateProjectV1<'a, 'info> {
+    fn top_up_rent(&self) -> ProgramResult {
+        let rent = Rent::get()?.minimum_balance(ProjectV1::LEN);
+        let missing = rent.saturating_sub(self.accounts.project_pda.lamports());
+
+        if missing == 0 {
+            return Ok(());
+        }
+
+        SystemProgram::transfer(
+            self.accounts.payer,
+            self.accounts.project_pda,
+            self.accounts.system_program,
+            missing,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for MigrateProjectV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.top_up_rent()?;
+
+        // Every field past the legacy prefix starts zeroed, i.e. at its default:
+        // no multisig, timelock, pause, protocol cut, VIP supply or reveal.
+        self.accounts.project_pda.realloc(ProjectV1::LEN, true)?;
+
+        msg!(
+            "Project {} migrated from {} to {} bytes",
+            self.accounts.project_pda.key,
+            ProjectV1::LEGACY_LEN,
+            ProjectV1::LEN
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    states::TraitItemV1,
+    utils::{
+        AccountCheck, LegacyTraitItemAccount, Pda, ProcessInstruction, SignerAccount,
+        SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct MigrateTraitV1Accounts<'a, 'info> {
+    /// Any wallet — must sign and pays the rent of the grown account.
+    pub payer: &'a AccountInfo<'info>,
+
+    /// PDA: `["trait_item_v1", trait_collection, program_id]` — still `TraitItemV1::LEGACY_LEN` long.
+    /// Must be writable, owned by program.
+    pub trait_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this trait.
+    pub trait_collection: &'a AccountInfo<'info>,
+
+    /// System program — funds the rent top-up.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MigrateTraitV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [payer, trait_pda, trait_collection, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(payer)?;
+
+        WritableAccount::check(payer)?;
+        WritableAccount::check(trait_pda)?;
+
+        LegacyTraitItemAccount::check(trait_pda)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            payer,
+            trait_pda,
+            trait_collection,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct MigrateTraitV1<'a, 'info> {
+    pub accounts: MigrateTraitV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)> for MigrateTraitV1<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = MigrateTraitV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.trait_pda,
+            &[TraitItemV1::SEED, accounts.trait_collection.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> MigrateTraitV1<'a, 'info> {
+    fn top_up_rent(&self) -> ProgramResult {
+        let rent = Rent::get()?.minimum_balance(TraitItemV1::LEN);
+        let missing = rent.saturating_sub(self.accounts.trait_pda.lamports());
+
+        if missing == 0 {
+            return Ok(());
+        }
+
+        SystemProgram::transfer(
+            self.accounts.payer,
+            self.accounts.trait_pda,
+            self.accounts.system_program,
+            missing,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for MigrateTraitV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.top_up_rent()?;
+
+        // `paused` starts zeroed: no operation is halted.
+        self.accounts.trait_pda.realloc(TraitItemV1::LEN, true)?;
+
+        msg!(
+            "Trait item {} migrated from {} to {} bytes",
+            self.accounts.trait_pda.key,
+            TraitItemV1::LEGACY_LEN,
+            TraitItemV1::LEN
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,485 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{
+        InitMintRecordAccounts, InitMintRecordArgs, InitVaultAccounts, InitVaultArgs, MintRecordV1,
+        MintTier, NftAuthorityV1, PauseFlag, ProjectRole, ProjectV1, VaultV1, MAX_MINT_BATCH_SIZE,
+    },
+    utils::{
+        AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
+        CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, InitAssociatedTokenProgramAccounts,
+        InitPdaAccounts, InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, ProtocolFeeWallet,
+        ProtocolFeeWalletCheck, SignerAccount, SystemProgram, TokenProgram, TokenTransferAccounts,
+        TokenTransferArgs, UninitializedAccount, WritableAccount,
+    },
+};
+
+/// Accounts of a single airdropped NFT, passed as remaining accounts.
+#[derive(Debug)]
+pub struct MintAdminAirdropV1NftAccounts<'a, 'info> {
+    /// Wallet that receives the NFT. Does not need to sign.
+    pub recipient: &'a AccountInfo<'info>,
+
+    /// NFT asset (MPL Core) — the NFT being minted.
+    /// Must be signer, writable and uninitialized.
+    pub nft_asset: &'a AccountInfo<'info>,
+
+    /// PDA: `["vault_v1", nft_asset, nft_collection, token_mint, program_id]` — stores `Vault` state.
+    /// Must be writable.
+    pub vault_pda: &'a AccountInfo<'info>,
+
+    /// Associated Token Account (ATA) of the vault PDA.
+    /// Must be writable.
+    pub vault_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — records the NFT's mint index.
+    /// Must be writable and uninitialized.
+    pub mint_record_pda: &'a AccountInfo<'info>,
+}
+
+#[derive(Debug)]
+pub struct MintAdminAirdropV1Accounts<'a, 'info> {
+    /// Authority as payer — the project admin or a wallet holding `ProjectRole::Minter`.
+    /// Must be a signer.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// Admin's ATA for 'token_mint' — funds the escrow of every airdropped NFT.
+    /// Must be writable, owned by `token_program`.
+    pub admin_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be writable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["nft_authority_v1", program_id]`
+    /// Controls: update/burn all NFTs.
+    /// Only program can sign
+    pub nft_authority: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint — the token being escrowed (e.g. ZDLT).
+    /// Must match `project_pda.data.mint`, owned by `token_program`.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// SPL Token Program (legacy or Token-2022).
+    /// Must match `token_mint.owner`.
+    pub token_program: &'a AccountInfo<'info>,
+
+    /// Associated Token Program (ATA).
+    pub associated_token_program: &'a AccountInfo<'info>,
+
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
+    /// Protocol wallet — receives the configurable SOL protocol fee.
+    /// Must match `protocol_config.fee_wallet`.
+    pub protocol_wallet: &'a AccountInfo<'info>,
+
+    /// System program — for account allocation.
+    pub system_program: &'a AccountInfo<'info>,
+
+    /// Metaplex Core program — for NFT minting.
+    /// Must be the official MPL Core program.
+    pub mpl_core: &'a AccountInfo<'info>,
+
+    /// Remaining accounts: one `[recipient, nft_asset, vault_pda, vault_ata, mint_record_pda]` group per NFT.
+    pub nfts: Vec<MintAdminAirdropV1NftAccounts<'a, 'info>>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintAdminAirdropV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, admin_ata, project_pda, project_role_pda, nft_authority, nft_collection, token_mint, token_program, associated_token_program, protocol_config_pda, protocol_wallet, system_program, mpl_core, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if remaining_accounts.is_empty() || remaining_accounts.len() % 5 != 0 {
+            msg!(
+                "Airdrop expects [recipient, nft_asset, vault_pda, vault_ata, mint_record_pda] per NFT"
+            );
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin_ata)?;
+        WritableAccount::check(project_pda)?;
+        WritableAccount::check(nft_collection)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+        MplCoreProgram::check(mpl_core)?;
+
+        if !TokenProgram::is_native(token_mint.key) {
+            AssociatedTokenAccount::check(admin_ata, admin.key, token_mint.key, token_program.key)?;
+        }
+
+        let mut nfts = Vec::with_capacity(remaining_accounts.len() / 5);
+        for chunk in remaining_accounts.chunks_exact(5) {
+            let [recipient, nft_asset, vault_pda, vault_ata, mint_record_pda] = chunk else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            SignerAccount::check(nft_asset)?;
+            WritableAccount::check(nft_asset)?;
+            WritableAccount::check(vault_pda)?;
+            WritableAccount::check(vault_ata)?;
+            WritableAccount::check(mint_record_pda)?;
+            UninitializedAccount::check(nft_asset)?;
+
+            nfts.push(MintAdminAirdropV1NftAccounts {
+                recipient,
+                nft_asset,
+                vault_pda,
+                vault_ata,
+                mint_record_pda,
+            });
+        }
+
+        Ok(Self {
+            admin,
+            admin_ata,
+            project_pda,
+            project_role_pda,
+            nft_authority,
+            nft_collection,
+            token_mint,
+            token_program,
+            associated_token_program,
+            protocol_config_pda,
+            protocol_wallet,
+            system_program,
+            mpl_core,
+            nfts,
+        })
+    }
+}
+
+/// One airdropped NFT.
+///
+/// - `recipient` must be set and match the `recipient` account of its group.
+/// - Empty `nft_name` / `nft_uri` use the project NFT naming; non-empty ones override it.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct MintAdminAirdropItem {
+    pub recipient: Pubkey,
+    pub nft_name: String,
+    pub nft_uri: String,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MintAdminAirdropV1InstructionData {
+    /// One entry per `[recipient, nft_asset, vault_pda, vault_ata, mint_record_pda]` group, in the same order.
+    pub nfts: Vec<MintAdminAirdropItem>,
+}
+
+#[derive(Debug)]
+pub struct MintAdminAirdropV1<'a, 'info> {
+    pub accounts: MintAdminAirdropV1Accounts<'a, 'info>,
+    pub instruction_data: MintAdminAirdropV1InstructionData,
+    pub program_id: &'a Pubkey,
+    pub nft_authority_bump: u8,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        MintAdminAirdropV1InstructionData,
+        &'a Pubkey,
+    )> for MintAdminAirdropV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            MintAdminAirdropV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = MintAdminAirdropV1Accounts::try_from(accounts)?;
+
+        if instruction_data.nfts.len() > MAX_MINT_BATCH_SIZE {
+            msg!("Too many NFTs in airdrop. Max: {}", MAX_MINT_BATCH_SIZE);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if instruction_data.nfts.len() != accounts.nfts.len() {
+            msg!(
+                "Airdrop has {} NFTs but {} account groups",
+                instruction_data.nfts.len(),
+                accounts.nfts.len(),
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        for (nft, item) in accounts.nfts.iter().zip(&instruction_data.nfts) {
+            if item.recipient == Pubkey::default() {
+                msg!("Airdrop recipient must be set");
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            if item.recipient != *nft.recipient.key {
+                msg!(
+                    "Airdrop recipient mismatch. Expected {}, got {}",
+                    item.recipient,
+                    nft.recipient.key,
+                );
+                return Err(ProgramError::InvalidInstructionData);
+            }
+        }
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        let (_, nft_authority_bump) =
+            Pda::validate(accounts.nft_authority, &[NftAuthorityV1::SEED], program_id)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+            nft_authority_bump,
+        })
+    }
+}
+
+impl<'a, 'info> MintAdminAirdropV1<'a, 'info> {
+    fn check_authority(&self, project: &ProjectV1) -> ProgramResult {
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Minter,
+        )
+    }
+
+    fn check_mint_eligibility(&self, project: &ProjectV1) -> ProgramResult {
+        let max_supply = project.max_supply;
+        let released = project.released;
+        let admin_supply = max_supply - released;
+        let admin_minted = project.admin_minted;
+        let minted = project.total_minted();
+
+        if !project.nft_stock_available() {
+            msg!(
+                "All NFTs are minted. Allowed supply: {}. Minted: {}",
+                max_supply,
+                minted,
+            );
+            return Err(ProgramError::Custom(0));
+        }
+
+        if !project.admin_mint_available() {
+            msg!(
+                "All admin NFTs already minted. Allowed supply: {}. Minted: {}",
+                admin_supply,
+                admin_minted
+            );
+            return Err(ProgramError::Custom(1));
+        }
+
+        Ok(())
+    }
+
+    fn store_to_vault(
+        &self,
+        project: &ProjectV1,
+        nft: &MintAdminAirdropV1NftAccounts<'a, 'info>,
+    ) -> ProgramResult {
+        if !project.need_vault() {
+            return Ok(());
+        }
+
+        let seeds: &[&[u8]] = &[
+            VaultV1::SEED,
+            nft.nft_asset.key.as_ref(),
+            self.accounts.nft_collection.key.as_ref(),
+            self.accounts.token_mint.key.as_ref(),
+        ];
+
+        VaultV1::init_if_needed(
+            InitVaultAccounts { pda: nft.vault_pda },
+            InitVaultArgs {
+                nft: *nft.nft_asset.key,
+                amount: project.escrow_amount,
+                is_unlocked: false,
+                price_paid: 0,
+                mint: *self.accounts.token_mint.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: nft.vault_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: VaultV1::LEN,
+                program_id: self.program_id,
+            },
+        )?;
+
+        if TokenProgram::is_native(self.accounts.token_mint.key) {
+            return SystemProgram::transfer(
+                self.accounts.admin,
+                nft.vault_pda,
+                self.accounts.system_program,
+                project.escrow_amount,
+            );
+        }
+
+        AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
+            payer: self.accounts.admin,
+            wallet: nft.vault_pda,
+            mint: self.accounts.token_mint,
+            token_program: self.accounts.token_program,
+            associated_token_program: self.accounts.associated_token_program,
+            system_program: self.accounts.system_program,
+            ata: nft.vault_ata,
+        })?;
+
+        TokenProgram::transfer(
+            TokenTransferAccounts {
+                source: self.accounts.admin_ata,
+                destination: nft.vault_ata,
+                authority: self.accounts.admin,
+                mint: self.accounts.token_mint,
+                token_program: self.accounts.token_program,
+            },
+            TokenTransferArgs {
+                amount: project.escrow_amount,
+                decimals: project.mint_decimals,
+            },
+        )
+    }
+
+    fn pay_protocol_fee(&self, project: &ProjectV1) -> ProgramResult {
+        if project.is_free_mint_nft_fee() {
+            return Ok(());
+        }
+
+        let lamports = project
+            .mint_nft_fee_lamports
+            .checked_mul(self.accounts.nfts.len() as u64)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Airdrop protocol fee overflow"))?;
+
+        SystemProgram::transfer(
+            self.accounts.admin,
+            self.accounts.protocol_wallet,
+            self.accounts.system_program,
+            lamports,
+        )
+    }
+
+    fn init_mint_record(
+        &self,
+        project: &ProjectV1,
+        nft: &MintAdminAirdropV1NftAccounts<'a, 'info>,
+        index: u64,
+    ) -> ProgramResult {
+        let clock = Clock::get()?;
+        let seeds: &[&[u8]] = &[MintRecordV1::SEED, nft.nft_asset.key.as_ref()];
+
+        MintRecordV1::init(
+            InitMintRecordAccounts {
+                pda: nft.mint_record_pda,
+            },
+            InitMintRecordArgs {
+                project: *self.accounts.project_pda.key,
+                nft: *nft.nft_asset.key,
+                minter: *self.accounts.admin.key,
+                mint: *self.accounts.token_mint.key,
+                index,
+                price_paid: 0,
+                escrow_amount: project.escrow_amount,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+                tier: MintTier::Admin,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: nft.mint_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MintRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn mint_nft(
+        &self,
+        project: &mut ProjectV1,
+        nft: &MintAdminAirdropV1NftAccounts<'a, 'info>,
+        item: MintAdminAirdropItem,
+    ) -> ProgramResult {
+        let index = project.next_nft_index()?;
+        let (name, uri) = project.nft_metadata(index, &item.nft_name, &item.nft_uri, true)?;
+
+        self.init_mint_record(project, nft, index)?;
+
+        MplCoreProgram::create(
+            CreateMplCoreAssetAccounts {
+                payer: self.accounts.admin,
+                owner: nft.recipient,
+                asset: nft.nft_asset,
+                collection: self.accounts.nft_collection,
+                authority: Some(self.accounts.nft_authority),
+                mpl_core: self.accounts.mpl_core,
+                system_program: self.accounts.system_program,
+            },
+            CreateMplCoreAssetArgs { name, uri },
+            &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
+        )?;
+
+        project.increment_admin_minted()
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for MintAdminAirdropV1<'a, 'info> {
+    fn process(mut self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(project_data.as_mut())?;
+
+        PauseFlag::MintAdmin.check(project.paused)?;
+
+        self.check_authority(project)?;
+
+        let items = core::mem::take(&mut self.instruction_data.nfts);
+        for (nft, item) in self.accounts.nfts.iter().zip(items) {
+            self.check_mint_eligibility(project)?;
+            self.store_to_vault(project, nft)?;
+            self.mint_nft(project, nft, item)?;
+        }
+
+        self.pay_protocol_fee(project)
+    }
+}
@@ -1,23 +1,27 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 use crate::{
-    states::{InitVaultAccounts, InitVaultArgs, NftAuthorityV1, ProjectV1, VaultV1},
+    states::{
+        InitMintRecordAccounts, InitMintRecordArgs, InitVaultAccounts, InitVaultArgs, MintRecordV1,
+        MintTier, NftAuthorityV1, PauseFlag, ProjectRole, ProjectV1, VaultV1,
+    },
     utils::{
         AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
         CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, InitAssociatedTokenProgramAccounts,
         InitPdaAccounts, InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
-        ProjectAccount, SignerAccount, SystemProgram, TokenProgram, TokenTransferAccounts,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, ProtocolFeeWallet,
+        ProtocolFeeWalletCheck, SignerAccount, SystemProgram, TokenProgram, TokenTransferAccounts,
         TokenTransferArgs, UninitializedAccount, WritableAccount,
     },
 };
 
 #[derive(Debug)]
 pub struct MintAdminV1Accounts<'a, 'info> {
-    /// Authority as payer (e.g. admin wallet).
+    /// Authority as payer — the project admin or a wallet holding `ProjectRole::Minter`.
     /// Must be a signer.
     pub admin: &'a AccountInfo<'info>,
 
@@ -29,6 +33,10 @@ pub struct MintAdminV1Accounts<'a, 'info> {
     /// Must be readable, owned by program.
     pub project_pda: &'a AccountInfo<'info>,
 
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
     /// PDA: `["vault_v1", nft_asset, nft_collection, token_mint, program_id]` — stores `Vault` state.
     /// Must be writable if updating vault balance.
     pub vault_pda: &'a AccountInfo<'info>,
@@ -65,8 +73,13 @@ pub struct MintAdminV1Accounts<'a, 'info> {
     /// Must be the official SPL Associated Token Account program.
     pub associated_token_program: &'a AccountInfo<'info>,
 
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// Protocol wallet — receives the configurable SOL protocol fee.
     /// Must writable, not zero address, owned by system_program.
+    /// Must match `protocol_config.fee_wallet`.
     pub protocol_wallet: &'a AccountInfo<'info>,
 
     /// System program — for account allocation.
@@ -75,13 +88,17 @@ pub struct MintAdminV1Accounts<'a, 'info> {
     /// Metaplex Core program — for NFT minting.
     /// Must be the official MPL Core program.
     pub mpl_core: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — records the NFT's mint index.
+    /// Must be writable and uninitialized.
+    pub mint_record_pda: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintAdminV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [admin, admin_ata, project_pda, vault_pda, vault_ata, nft_authority, nft_collection, nft_asset, token_mint, token_program, associated_token_program, protocol_wallet, system_program, mpl_core] =
+        let [admin, admin_ata, project_pda, project_role_pda, vault_pda, vault_ata, nft_authority, nft_collection, nft_asset, token_mint, token_program, associated_token_program, protocol_config_pda, protocol_wallet, system_program, mpl_core, mint_record_pda] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -96,7 +113,8 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintAdminV1Accounts<'a, 'i
         WritableAccount::check(vault_ata)?;
         WritableAccount::check(nft_collection)?;
         WritableAccount::check(nft_asset)?;
-        WritableAccount::check(protocol_wallet)?;
+        WritableAccount::check(mint_record_pda)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
 
         UninitializedAccount::check(nft_asset)?;
 
@@ -105,12 +123,15 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintAdminV1Accounts<'a, 'i
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
-        AssociatedTokenAccount::check(admin_ata, admin.key, token_mint.key, token_program.key)?;
+        if !TokenProgram::is_native(token_mint.key) {
+            AssociatedTokenAccount::check(admin_ata, admin.key, token_mint.key, token_program.key)?;
+        }
 
         Ok(Self {
             admin,
             admin_ata,
             project_pda,
+            project_role_pda,
             vault_pda,
             vault_ata,
             nft_authority,
@@ -119,16 +140,20 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintAdminV1Accounts<'a, 'i
             token_mint,
             token_program,
             associated_token_program,
+            protocol_config_pda,
             protocol_wallet,
             system_program,
             mpl_core,
+            mint_record_pda,
         })
     }
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct MintAdminV1InstructionData {
+    /// NFT name. Empty uses the generated name when `project.naming` is set.
     pub nft_name: String,
+    /// NFT metadata URI. Empty uses the generated URI when `project.naming` is set.
     pub nft_uri: String,
 }
 
@@ -181,13 +206,22 @@ impl<'a, 'info>
 }
 
 impl<'a, 'info> MintAdminV1<'a, 'info> {
+    fn check_authority(&self, project: &ProjectV1) -> ProgramResult {
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Minter,
+        )
+    }
+
     fn check_mint_eligibility(&self, project: &ProjectV1) -> ProgramResult {
         let max_supply = project.max_supply;
         let released = project.released;
         let admin_supply = max_supply - released;
         let admin_minted = project.admin_minted;
-        let user_minted = project.user_minted;
-        let minted = admin_minted + user_minted;
+        let minted = project.total_minted();
 
         if !project.nft_stock_available() {
             msg!(
@@ -230,6 +264,8 @@ impl<'a, 'info> MintAdminV1<'a, 'info> {
                 nft: *self.accounts.nft_asset.key,
                 amount: project.escrow_amount,
                 is_unlocked: false,
+                price_paid: 0,
+                mint: *self.accounts.token_mint.key,
             },
             InitPdaAccounts {
                 payer: self.accounts.admin,
@@ -243,6 +279,15 @@ impl<'a, 'info> MintAdminV1<'a, 'info> {
             },
         )?;
 
+        if TokenProgram::is_native(self.accounts.token_mint.key) {
+            return SystemProgram::transfer(
+                self.accounts.admin,
+                self.accounts.vault_pda,
+                self.accounts.system_program,
+                project.escrow_amount,
+            );
+        }
+
         AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
             payer: self.accounts.admin,
             wallet: self.accounts.vault_pda,
@@ -281,20 +326,61 @@ impl<'a, 'info> MintAdminV1<'a, 'info> {
         )
     }
 
+    fn init_mint_record(&self, project: &ProjectV1, index: u64) -> ProgramResult {
+        let clock = Clock::get()?;
+        let seeds: &[&[u8]] = &[MintRecordV1::SEED, self.accounts.nft_asset.key.as_ref()];
+
+        MintRecordV1::init(
+            InitMintRecordAccounts {
+                pda: self.accounts.mint_record_pda,
+            },
+            InitMintRecordArgs {
+                project: *self.accounts.project_pda.key,
+                nft: *self.accounts.nft_asset.key,
+                minter: *self.accounts.admin.key,
+                mint: *self.accounts.token_mint.key,
+                index,
+                price_paid: 0,
+                escrow_amount: project.escrow_amount,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+                tier: MintTier::Admin,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.mint_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MintRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
     fn mint_nft(self, project: &mut ProjectV1) -> ProgramResult {
+        let index = project.next_nft_index()?;
+        let (name, uri) = project.nft_metadata(
+            index,
+            &self.instruction_data.nft_name,
+            &self.instruction_data.nft_uri,
+            true,
+        )?;
+
+        self.init_mint_record(project, index)?;
+
         MplCoreProgram::create(
             CreateMplCoreAssetAccounts {
                 payer: self.accounts.admin,
+                owner: self.accounts.admin,
                 asset: self.accounts.nft_asset,
                 collection: self.accounts.nft_collection,
                 authority: Some(self.accounts.nft_authority),
                 mpl_core: self.accounts.mpl_core,
                 system_program: self.accounts.system_program,
             },
-            CreateMplCoreAssetArgs {
-                name: self.instruction_data.nft_name,
-                uri: self.instruction_data.nft_uri,
-            },
+            CreateMplCoreAssetArgs { name, uri },
             &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
         )?;
 
@@ -309,6 +395,9 @@ impl<'a, 'info> ProcessInstruction for MintAdminV1<'a, 'info> {
         let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
         let project = ProjectV1::load_mut(project_data.as_mut())?;
 
+        PauseFlag::MintAdmin.check(project.paused)?;
+
+        self.check_authority(project)?;
         self.check_mint_eligibility(project)?;
         self.store_to_vault(project)?;
         self.pay_protocol_fee(project)?;
@@ -5,10 +5,11 @@ use solana_program::{
 };
 
 use crate::{
-    states::{TraitAuthorityV1, TraitItemV1},
+    states::{PauseFlag, TraitAuthorityV1, TraitItemV1},
     utils::{
         AccountCheck, CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, MplCoreProgram, Pda,
-        ProcessInstruction, SignerAccount, SystemProgram, UninitializedAccount, WritableAccount,
+        ProcessInstruction, ProtocolFeeWallet, ProtocolFeeWalletCheck, SignerAccount,
+        SystemProgram, UninitializedAccount, WritableAccount,
     },
 };
 
@@ -36,8 +37,13 @@ pub struct MintTraitV1Accounts<'a, 'info> {
     /// Must be uninitialized, owned by `mpl_core`.
     pub trait_asset: &'a AccountInfo<'info>,
 
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// Protocol wallet — receives the configurable SOL protocol fee.
     /// Must writable, not zero address, owned by system_program.
+    /// Must match `protocol_config.fee_wallet`.
     pub protocol_wallet: &'a AccountInfo<'info>,
 
     /// System program — for account allocation.
@@ -52,7 +58,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintTraitV1Accounts<'a, 'i
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, trait_pda, trait_authority, trait_collection, trait_asset, protocol_wallet, system_program, mpl_core] =
+        let [payer, trait_pda, trait_authority, trait_collection, trait_asset, protocol_config_pda, protocol_wallet, system_program, mpl_core] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -64,7 +70,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintTraitV1Accounts<'a, 'i
         WritableAccount::check(trait_pda)?;
         WritableAccount::check(trait_collection)?;
         WritableAccount::check(trait_asset)?;
-        WritableAccount::check(protocol_wallet)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
 
         UninitializedAccount::check(trait_asset)?;
 
@@ -77,6 +83,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintTraitV1Accounts<'a, 'i
             trait_authority,
             trait_collection,
             trait_asset,
+            protocol_config_pda,
             protocol_wallet,
             system_program,
             mpl_core,
@@ -166,6 +173,7 @@ impl<'a, 'info> MintTraitV1<'a, 'info> {
         MplCoreProgram::create(
             CreateMplCoreAssetAccounts {
                 payer: self.accounts.payer,
+                owner: self.accounts.payer,
                 asset: self.accounts.trait_asset,
                 collection: self.accounts.trait_collection,
                 authority: Some(self.accounts.trait_authority),
@@ -190,6 +198,8 @@ impl<'a, 'info> ProcessInstruction for MintTraitV1<'a, 'info> {
         let mut trait_data = self.accounts.trait_pda.try_borrow_mut_data()?;
         let trait_item = TraitItemV1::load_mut(trait_data.as_mut())?;
 
+        PauseFlag::MintUser.check(trait_item.paused)?;
+
         self.check_mint_eligibility(trait_item)?;
         self.pay_protocol_fee(trait_item)?;
         self.mint_nft(trait_item)
@@ -0,0 +1,723 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{
+        InitMintRecordAccounts, InitMintRecordArgs, InitUserMintedAccounts, InitUserMintedArgs,
+        InitVaultAccounts, InitVaultArgs, MintPhaseKind, MintRecordV1, MintTier, NftAuthorityV1,
+        PauseFlag, PriceSplit, ProjectV1, UserMintedV1, VaultV1, MAX_MINT_BATCH_SIZE,
+    },
+    utils::{
+        AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
+        CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, InitAssociatedTokenProgramAccounts,
+        InitPdaAccounts, InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
+        ProjectAccount, ProtocolFeeWallet, ProtocolFeeWalletCheck, RevenueWallet,
+        RevenueWalletAccounts, RevenueWalletArgs, SignerAccount, SystemProgram, TokenProgram,
+        TokenTransferAccounts, TokenTransferArgs, UninitializedAccount, WritableAccount,
+    },
+};
+
+/// Accounts of a single NFT in the batch, passed as remaining accounts.
+#[derive(Debug)]
+pub struct MintUserBatchV1NftAccounts<'a, 'info> {
+    /// NFT asset (MPL Core) — the NFT being minted.
+    /// Must be signer, writable and uninitialized.
+    pub nft_asset: &'a AccountInfo<'info>,
+
+    /// PDA: `["vault_v1", nft_asset, nft_collection, token_mint, program_id]` — stores `Vault` state.
+    /// Must be writable.
+    pub vault_pda: &'a AccountInfo<'info>,
+
+    /// Associated Token Account (ATA) of the vault PDA.
+    /// Must be writable.
+    pub vault_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — records the NFT's mint index.
+    /// Must be writable and uninitialized.
+    pub mint_record_pda: &'a AccountInfo<'info>,
+}
+
+#[derive(Debug)]
+pub struct MintUserBatchV1Accounts<'a, 'info> {
+    /// User paying the mint price in 'token_mint' and solana.
+    /// Must be signer and owner of `payer_ata`.
+    pub payer: &'a AccountInfo<'info>,
+
+    /// Payer's ATA for 'token_mint' — source of payment.
+    /// Must be writable, owned by `token_program`.
+    pub payer_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be writable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["user_minted_v1", nft_collection, token_mint, payer, program_id]` — per-user mint counter.
+    /// Must be uninitialized, or owned by program and belong to `payer`.
+    pub user_minted_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["nft_authority_v1", program_id]`
+    /// Controls: update/burn all NFTs.
+    /// Only program can sign
+    pub nft_authority: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint — the token being escrowed (e.g. ZDLT).
+    /// Must match `project_pda.data.mint`, owned by `token_program`.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    // ---------------- Revenue Wallets ----------------
+    /// Revenue wallet #0 — corresponds to `project.revenue_wallet(0)`.
+    /// Must be writable if receiving transfer.
+    pub revenue_wallet_0: &'a AccountInfo<'info>,
+    pub revenue_wallet_ata_0: &'a AccountInfo<'info>,
+
+    /// Revenue wallet #1 — corresponds to `project.revenue_wallet(1)`.
+    /// Must be writable if receiving transfer.
+    pub revenue_wallet_1: &'a AccountInfo<'info>,
+    pub revenue_wallet_ata_1: &'a AccountInfo<'info>,
+
+    /// Revenue wallet #2 — corresponds to `project.revenue_wallet(2)`.
+    /// Must be writable if receiving transfer.
+    pub revenue_wallet_2: &'a AccountInfo<'info>,
+    pub revenue_wallet_ata_2: &'a AccountInfo<'info>,
+
+    /// Revenue wallet #3 — corresponds to `project.revenue_wallet(3)`.
+    /// Must be writable if receiving transfer.
+    pub revenue_wallet_3: &'a AccountInfo<'info>,
+    pub revenue_wallet_ata_3: &'a AccountInfo<'info>,
+
+    /// Revenue wallet #4 — corresponds to `project.revenue_wallet(4)`.
+    /// Must be writable if receiving transfer.
+    pub revenue_wallet_4: &'a AccountInfo<'info>,
+    pub revenue_wallet_ata_4: &'a AccountInfo<'info>,
+
+    // --------------------------------------------------
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
+    /// Protocol wallet — receives the configurable SOL protocol fee.
+    /// Must match `protocol_config.fee_wallet`.
+    pub protocol_wallet: &'a AccountInfo<'info>,
+
+    /// Protocol wallet's ATA for 'token_mint' — receives `project.protocol_fee_bps` of the mint price.
+    /// Must be writable when the project has a protocol cut.
+    pub protocol_wallet_ata: &'a AccountInfo<'info>,
+
+    /// SPL Token Program (legacy or Token-2022).
+    /// Must match `token_mint.owner`.
+    pub token_program: &'a AccountInfo<'info>,
+
+    /// Associated Token Program (ATA).
+    pub associated_token_program: &'a AccountInfo<'info>,
+
+    /// System program — for account allocation.
+    pub system_program: &'a AccountInfo<'info>,
+
+    /// Metaplex Core program — for NFT minting.
+    /// Must be the official MPL Core program.
+    pub mpl_core: &'a AccountInfo<'info>,
+
+    /// Remaining accounts: one `[nft_asset, vault_pda, vault_ata, mint_record_pda]` group per NFT.
+    pub nfts: Vec<MintUserBatchV1NftAccounts<'a, 'info>>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintUserBatchV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [payer, payer_ata, project_pda, user_minted_pda, nft_authority, nft_collection, token_mint, revenue_wallet_0, revenue_wallet_ata_0, revenue_wallet_1, revenue_wallet_ata_1, revenue_wallet_2, revenue_wallet_ata_2, revenue_wallet_3, revenue_wallet_ata_3, revenue_wallet_4, revenue_wallet_ata_4, protocol_config_pda, protocol_wallet, protocol_wallet_ata, token_program, associated_token_program, system_program, mpl_core, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if remaining_accounts.is_empty() || remaining_accounts.len() % 4 != 0 {
+            msg!("Batch mint expects [nft_asset, vault_pda, vault_ata, mint_record_pda] per NFT");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        SignerAccount::check(payer)?;
+
+        WritableAccount::check(payer_ata)?;
+        WritableAccount::check(project_pda)?;
+        WritableAccount::check(user_minted_pda)?;
+        WritableAccount::check(nft_collection)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+        MplCoreProgram::check(mpl_core)?;
+
+        if !TokenProgram::is_native(token_mint.key) {
+            AssociatedTokenAccount::check(payer_ata, payer.key, token_mint.key, token_program.key)?;
+        }
+
+        let mut nfts = Vec::with_capacity(remaining_accounts.len() / 4);
+        for chunk in remaining_accounts.chunks_exact(4) {
+            let [nft_asset, vault_pda, vault_ata, mint_record_pda] = chunk else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            SignerAccount::check(nft_asset)?;
+            WritableAccount::check(nft_asset)?;
+            WritableAccount::check(vault_pda)?;
+            WritableAccount::check(vault_ata)?;
+            WritableAccount::check(mint_record_pda)?;
+            UninitializedAccount::check(nft_asset)?;
+
+            nfts.push(MintUserBatchV1NftAccounts {
+                nft_asset,
+                vault_pda,
+                vault_ata,
+                mint_record_pda,
+            });
+        }
+
+        Ok(Self {
+            payer,
+            payer_ata,
+            project_pda,
+            user_minted_pda,
+            nft_authority,
+            nft_collection,
+            token_mint,
+            revenue_wallet_0,
+            revenue_wallet_ata_0,
+            revenue_wallet_1,
+            revenue_wallet_ata_1,
+            revenue_wallet_2,
+            revenue_wallet_ata_2,
+            revenue_wallet_3,
+            revenue_wallet_ata_3,
+            revenue_wallet_4,
+            revenue_wallet_ata_4,
+            protocol_config_pda,
+            protocol_wallet,
+            protocol_wallet_ata,
+            token_program,
+            associated_token_program,
+            system_program,
+            mpl_core,
+            nfts,
+        })
+    }
+}
+
+/// Name and URI of one NFT in the batch.
+///
+/// - Must be empty when the project generates names (`project.naming`).
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct MintUserBatchItem {
+    pub nft_name: String,
+    pub nft_uri: String,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MintUserBatchV1InstructionData {
+    /// One entry per `[nft_asset, vault_pda, vault_ata, mint_record_pda]` group, in the same order.
+    pub nfts: Vec<MintUserBatchItem>,
+    /// Highest total price the payer accepts for the whole batch, in raw units of `token_mint`.
+    pub max_price: u64,
+}
+
+#[derive(Debug)]
+pub struct MintUserBatchV1<'a, 'info> {
+    pub accounts: MintUserBatchV1Accounts<'a, 'info>,
+    pub instruction_data: MintUserBatchV1InstructionData,
+    pub program_id: &'a Pubkey,
+    pub nft_authority_bump: u8,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        MintUserBatchV1InstructionData,
+        &'a Pubkey,
+    )> for MintUserBatchV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            MintUserBatchV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = MintUserBatchV1Accounts::try_from(accounts)?;
+
+        if instruction_data.nfts.is_empty() {
+            msg!("Batch must contain at least one NFT");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if instruction_data.nfts.len() > MAX_MINT_BATCH_SIZE {
+            msg!("Too many NFTs in batch. Max: {}", MAX_MINT_BATCH_SIZE);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if instruction_data.nfts.len() != accounts.nfts.len() {
+            msg!(
+                "Batch has {} NFTs but {} account groups of 4",
+                instruction_data.nfts.len(),
+                accounts.nfts.len(),
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.user_minted_pda,
+            &[
+                UserMintedV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+                accounts.payer.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        let (_, nft_authority_bump) =
+            Pda::validate(accounts.nft_authority, &[NftAuthorityV1::SEED], program_id)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+            nft_authority_bump,
+        })
+    }
+}
+
+impl<'a, 'info> MintUserBatchV1<'a, 'info> {
+    fn check_mint_eligibility(&self, project: &ProjectV1, now: i64) -> ProgramResult {
+        let max_supply = project.max_supply;
+        let public_supply = project.public_supply_at(now);
+        let user_minted = project.user_minted;
+        let minted = project.total_minted();
+
+        if !project.nft_stock_available() {
+            msg!(
+                "All nft are minted. Allowed supply: {}. Minted {}",
+                max_supply,
+                minted,
+            );
+            return Err(ProgramError::Custom(0));
+        }
+
+        if !project.user_mint_available(now) {
+            msg!(
+                "Sold out. Allowed supply: {}. Minted: {}",
+                public_supply,
+                user_minted,
+            );
+            return Err(ProgramError::Custom(1));
+        }
+
+        Ok(())
+    }
+
+    fn init_user_minted_if_needed(&self) -> ProgramResult {
+        let seeds = &[
+            UserMintedV1::SEED,
+            self.accounts.nft_collection.key.as_ref(),
+            self.accounts.token_mint.key.as_ref(),
+            self.accounts.payer.key.as_ref(),
+        ];
+
+        UserMintedV1::init_if_needed(
+            InitUserMintedAccounts {
+                pda: self.accounts.user_minted_pda,
+            },
+            InitUserMintedArgs {
+                owner: self.accounts.payer.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: self.accounts.user_minted_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: UserMintedV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    /// Reserves every NFT of the batch and returns the split of each, in batch order.
+    ///
+    /// Phase caps, supply and bonding curves advance per NFT, exactly as
+    /// the same number of `mint_user_v1` calls would.
+    fn reserve_batch(
+        &self,
+        project: &mut ProjectV1,
+        user_minted: &mut UserMintedV1,
+        now: i64,
+    ) -> Result<Vec<PriceSplit>, ProgramError> {
+        let mut splits = Vec::with_capacity(self.accounts.nfts.len());
+
+        for _ in 0..self.accounts.nfts.len() {
+            project.consume_mint_phase(MintPhaseKind::Public, now)?;
+            self.check_mint_eligibility(project, now)?;
+
+            splits.push(project.price_split(project.mint_price_at(now)?)?);
+
+            user_minted.increment();
+            project.increment_user_minted()?;
+        }
+
+        Ok(splits)
+    }
+
+    fn store_to_vault(
+        &self,
+        project: &ProjectV1,
+        nft: &MintUserBatchV1NftAccounts<'a, 'info>,
+        split: &PriceSplit,
+    ) -> ProgramResult {
+        if !project.need_vault() {
+            return Ok(());
+        }
+
+        let seeds: &[&[u8]] = &[
+            VaultV1::SEED,
+            nft.nft_asset.key.as_ref(),
+            self.accounts.nft_collection.key.as_ref(),
+            self.accounts.token_mint.key.as_ref(),
+        ];
+
+        VaultV1::init_if_needed(
+            InitVaultAccounts { pda: nft.vault_pda },
+            InitVaultArgs {
+                nft: *nft.nft_asset.key,
+                amount: split.escrow_amount,
+                is_unlocked: false,
+                price_paid: split.total()?,
+                mint: *self.accounts.token_mint.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: nft.vault_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: VaultV1::LEN,
+                program_id: self.program_id,
+            },
+        )?;
+
+        if TokenProgram::is_native(self.accounts.token_mint.key) {
+            return SystemProgram::transfer(
+                self.accounts.payer,
+                nft.vault_pda,
+                self.accounts.system_program,
+                split.escrow_amount,
+            );
+        }
+
+        AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
+            payer: self.accounts.payer,
+            wallet: nft.vault_pda,
+            mint: self.accounts.token_mint,
+            token_program: self.accounts.token_program,
+            associated_token_program: self.accounts.associated_token_program,
+            system_program: self.accounts.system_program,
+            ata: nft.vault_ata,
+        })?;
+
+        TokenProgram::transfer(
+            TokenTransferAccounts {
+                source: self.accounts.payer_ata,
+                destination: nft.vault_ata,
+                authority: self.accounts.payer,
+                mint: self.accounts.token_mint,
+                token_program: self.accounts.token_program,
+            },
+            TokenTransferArgs {
+                amount: split.escrow_amount,
+                decimals: project.mint_decimals,
+            },
+        )
+    }
+
+    fn pay_to_all_revenue_wallets(&self, project: &ProjectV1, total: &PriceSplit) -> ProgramResult {
+        let num_wallets = project.num_revenue_wallets as usize;
+
+        if num_wallets == 0 {
+            return Ok(());
+        }
+
+        let revenue_wallets = [
+            self.accounts.revenue_wallet_0,
+            self.accounts.revenue_wallet_1,
+            self.accounts.revenue_wallet_2,
+            self.accounts.revenue_wallet_3,
+            self.accounts.revenue_wallet_4,
+        ];
+
+        let revenue_wallet_atas = [
+            self.accounts.revenue_wallet_ata_0,
+            self.accounts.revenue_wallet_ata_1,
+            self.accounts.revenue_wallet_ata_2,
+            self.accounts.revenue_wallet_ata_3,
+            self.accounts.revenue_wallet_ata_4,
+        ];
+
+        if num_wallets > revenue_wallet_atas.len() {
+            msg!("Incorrect number of accounts for revenue's wallet ATAs");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        for index in 0..num_wallets {
+            let (Ok(expected_revenue_wallet), Ok(amount)) = (
+                project
+                    .revenue_wallet(index)
+                    .inspect_err(|_| msg!("Revenue wallet index {} not found!", index)),
+                total
+                    .revenue_share(index)
+                    .inspect_err(|_| msg!("Revenue share index {} not found!", index)),
+            ) else {
+                continue;
+            };
+
+            let revenue_wallet = revenue_wallets
+                .get(index)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+                .inspect_err(|_| msg!("Missing revenue wallet at index {}", index))?;
+            let revenue_ata = revenue_wallet_atas
+                .get(index)
+                .ok_or(ProgramError::InvalidAccountData)
+                .inspect_err(|_| msg!("Missing revenue wallet ATA at index {}", index))?;
+
+            if revenue_wallet.key != expected_revenue_wallet {
+                msg!(
+                    "Revenue wallet mismatch at index {}. Expected {}, got {}",
+                    index,
+                    expected_revenue_wallet,
+                    revenue_wallet.key,
+                );
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if !project.allow_tf_to_dao_wallet(index)
+                || *expected_revenue_wallet == Pubkey::default()
+            {
+                continue;
+            }
+
+            WritableAccount::check(revenue_wallet)?;
+            WritableAccount::check(revenue_ata)?;
+
+            RevenueWallet::transfer(
+                RevenueWalletAccounts {
+                    payer_ata: self.accounts.payer_ata,
+                    destination_ata: revenue_ata,
+                    wallet: revenue_wallet,
+                    payer: self.accounts.payer,
+                    mint: self.accounts.token_mint,
+                    token_program: self.accounts.token_program,
+                    associated_token_program: self.accounts.associated_token_program,
+                    system_program: self.accounts.system_program,
+                },
+                RevenueWalletArgs {
+                    amount,
+                    decimals: project.mint_decimals,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn pay_protocol_token_fee(&self, project: &ProjectV1, total: &PriceSplit) -> ProgramResult {
+        let amount = total.protocol_fee;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        WritableAccount::check(self.accounts.protocol_wallet_ata)?;
+
+        RevenueWallet::transfer(
+            RevenueWalletAccounts {
+                payer_ata: self.accounts.payer_ata,
+                destination_ata: self.accounts.protocol_wallet_ata,
+                wallet: self.accounts.protocol_wallet,
+                payer: self.accounts.payer,
+                mint: self.accounts.token_mint,
+                token_program: self.accounts.token_program,
+                associated_token_program: self.accounts.associated_token_program,
+                system_program: self.accounts.system_program,
+            },
+            RevenueWalletArgs {
+                amount,
+                decimals: project.mint_decimals,
+            },
+        )
+    }
+
+    fn pay_protocol_fee(&self, project: &ProjectV1) -> ProgramResult {
+        if project.is_free_mint_nft_fee() {
+            return Ok(());
+        }
+
+        let lamports = project
+            .mint_nft_fee_lamports
+            .checked_mul(self.accounts.nfts.len() as u64)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Batch protocol fee overflow"))?;
+
+        SystemProgram::transfer(
+            self.accounts.payer,
+            self.accounts.protocol_wallet,
+            self.accounts.system_program,
+            lamports,
+        )?;
+
+        Ok(())
+    }
+
+    fn init_mint_record(
+        &self,
+        nft: &MintUserBatchV1NftAccounts<'a, 'info>,
+        index: u64,
+        split: &PriceSplit,
+        clock: &Clock,
+    ) -> ProgramResult {
+        let seeds: &[&[u8]] = &[MintRecordV1::SEED, nft.nft_asset.key.as_ref()];
+
+        MintRecordV1::init(
+            InitMintRecordAccounts {
+                pda: nft.mint_record_pda,
+            },
+            InitMintRecordArgs {
+                project: *self.accounts.project_pda.key,
+                nft: *nft.nft_asset.key,
+                minter: *self.accounts.payer.key,
+                mint: *self.accounts.token_mint.key,
+                index,
+                price_paid: split.total()?,
+                escrow_amount: split.escrow_amount,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+                tier: MintTier::User,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: nft.mint_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MintRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn mint_nfts(
+        &self,
+        project: &ProjectV1,
+        first_index: u64,
+        splits: &[PriceSplit],
+    ) -> ProgramResult {
+        let clock = Clock::get()?;
+
+        for (((nft, item), index), split) in self
+            .accounts
+            .nfts
+            .iter()
+            .zip(&self.instruction_data.nfts)
+            .zip(first_index..)
+            .zip(splits)
+        {
+            let (name, uri) = project.nft_metadata(index, &item.nft_name, &item.nft_uri, false)?;
+
+            self.init_mint_record(nft, index, split, &clock)?;
+
+            MplCoreProgram::create(
+                CreateMplCoreAssetAccounts {
+                    payer: self.accounts.payer,
+                    owner: self.accounts.payer,
+                    asset: nft.nft_asset,
+                    collection: self.accounts.nft_collection,
+                    authority: Some(self.accounts.nft_authority),
+                    mpl_core: self.accounts.mpl_core,
+                    system_program: self.accounts.system_program,
+                },
+                CreateMplCoreAssetArgs { name, uri },
+                &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for MintUserBatchV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(project_data.as_mut())?;
+
+        PauseFlag::MintUser.check(project.paused)?;
+        let now = Clock::get()?.unix_timestamp;
+
+        self.init_user_minted_if_needed()?;
+
+        if self.accounts.user_minted_pda.owner != self.program_id {
+            msg!("User minted account is not owned by the program");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut user_minted_data = self.accounts.user_minted_pda.try_borrow_mut_data()?;
+        let user_minted = UserMintedV1::load_mut(user_minted_data.as_mut())?;
+        if user_minted.owner != *self.accounts.payer.key {
+            msg!("User minted account does not belong to the payer");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !user_minted.can_mint(project, self.accounts.nfts.len() as u64) {
+            msg!(
+                "Batch of {} exceeds the user's allowed supply",
+                self.accounts.nfts.len()
+            );
+            return Err(ProgramError::Custom(2));
+        }
+
+        let first_index = project.next_nft_index()?;
+        let splits = self.reserve_batch(project, user_minted, now)?;
+        let total = splits
+            .iter()
+            .try_fold(PriceSplit::default(), |total, split| {
+                total.checked_add(split)
+            })?;
+        total.check_max_price(self.instruction_data.max_price)?;
+
+        for (nft, split) in self.accounts.nfts.iter().zip(&splits) {
+            self.store_to_vault(project, nft, split)?;
+        }
+
+        self.pay_to_all_revenue_wallets(project, &total)?;
+        self.pay_protocol_token_fee(project, &total)?;
+        self.pay_protocol_fee(project)?;
+        self.mint_nfts(project, first_index, &splits)
+    }
+}
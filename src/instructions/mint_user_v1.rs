@@ -1,31 +1,36 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 use crate::{
     states::{
-        InitUserMintedAccounts, InitUserMintedArgs, InitVaultAccounts, InitVaultArgs,
-        NftAuthorityV1, ProjectV1, UserMintedV1, VaultV1,
+        CouponKind, CouponV1, InitMintRecordAccounts, InitMintRecordArgs,
+        InitReferrerEarningsAccounts, InitReferrerEarningsArgs, InitUserMintedAccounts,
+        InitUserMintedArgs, InitVaultAccounts, InitVaultArgs, MintPhaseKind, MintRecordV1,
+        MintTier, NftAuthorityV1, PauseFlag, PaymentMintV1, PriceSplit, ProjectV1,
+        ReferrerEarningsV1, ReferrerV1, UserMintedV1, VaultV1,
     },
     utils::{
         AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
-        CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, InitAssociatedTokenProgramAccounts,
-        InitPdaAccounts, InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
-        ProjectAccount, RevenueWallet, RevenueWalletAccounts, RevenueWalletArgs, SignerAccount,
-        SystemProgram, TokenProgram, TokenTransferAccounts, TokenTransferArgs,
-        UninitializedAccount, WritableAccount,
+        CouponAccount, CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs,
+        InitAssociatedTokenProgramAccounts, InitPdaAccounts, InitPdaArgs, MintAccount,
+        MplCoreProgram, PaymentMintAccount, Pda, ProcessInstruction, ProjectAccount,
+        ProtocolFeeWallet, ProtocolFeeWalletCheck, ReferrerAccount, ReferrerEarningsAccount,
+        RevenueWallet, RevenueWalletAccounts, RevenueWalletArgs, SignerAccount, SystemProgram,
+        TokenProgram, TokenTransferAccounts, TokenTransferArgs, UninitializedAccount,
+        WritableAccount,
     },
 };
 
 #[derive(Debug)]
 pub struct MintUserV1Accounts<'a, 'info> {
-    /// User paying the mint price in 'token_mint' and solana.
+    /// User paying the mint price in 'payment_mint' and solana.
     /// Must be signer and owner of `payer_ata`.
     pub payer: &'a AccountInfo<'info>,
 
-    /// Payer's ATA for 'token_mint' — source of payment.
+    /// Payer's ATA for 'payment_mint' — source of payment.
     /// Must be writable, owned by `token_program`.
     pub payer_ata: &'a AccountInfo<'info>,
 
@@ -38,12 +43,12 @@ pub struct MintUserV1Accounts<'a, 'info> {
     pub vault_pda: &'a AccountInfo<'info>,
 
     /// Associated Token Account (ATA) of the vault PDA.
-    /// Holds 'token_mint' received from users.
+    /// Holds 'payment_mint' received from users.
     /// Must be writable, owned by `token_program`.
     pub vault_ata: &'a AccountInfo<'info>,
 
-    /// PDA: `["user_minted_v1", nft_collection, token_mint, payer, program_id]` — per-user mint flag.
-    /// Prevents double-minting.
+    /// PDA: `["user_minted_v1", nft_collection, token_mint, recipient, program_id]` — per-user mint flag.
+    /// Prevents double-minting. Charged to the recipient, not the payer.
     /// Must be uninitialized or checked for prior mint.
     pub user_minted_pda: &'a AccountInfo<'info>,
 
@@ -92,10 +97,19 @@ pub struct MintUserV1Accounts<'a, 'info> {
     pub revenue_wallet_ata_4: &'a AccountInfo<'info>,
 
     // --------------------------------------------------
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// Protocol wallet — receives the configurable SOL protocol fee.
     /// Must writable, not zero address, owned by system_program.
+    /// Must match `protocol_config.fee_wallet`.
     pub protocol_wallet: &'a AccountInfo<'info>,
 
+    /// Protocol wallet's ATA for 'payment_mint' — receives `project.protocol_fee_bps` of the mint price.
+    /// Must be writable when the project has a protocol cut.
+    pub protocol_wallet_ata: &'a AccountInfo<'info>,
+
     /// SPL Token Program (legacy or Token-2022).
     /// Must match `token_mint.owner`.
     pub token_program: &'a AccountInfo<'info>,
@@ -110,13 +124,49 @@ pub struct MintUserV1Accounts<'a, 'info> {
     /// Metaplex Core program — for NFT minting.
     /// Must be the official MPL Core program.
     pub mpl_core: &'a AccountInfo<'info>,
+
+    /// Wallet that will own the NFT — `payer` itself, or another wallet for a gift mint.
+    /// Does not need to sign. Must not be the zero address.
+    pub recipient: &'a AccountInfo<'info>,
+
+    /// Token mint the price is paid (and escrowed) in.
+    /// Either `token_mint` itself, or a mint registered with `set_payment_mint_v1`.
+    pub payment_mint: &'a AccountInfo<'info>,
+
+    /// PDA: `["payment_mint_v1", project_pda, payment_mint, program_id]` — price table entry.
+    /// Only read when `payment_mint` is not `token_mint`.
+    pub payment_mint_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["referrer_v1", project_pda, referrer, program_id]` — registered referrer.
+    /// Pass the program id when minting without a referrer.
+    pub referrer_pda: &'a AccountInfo<'info>,
+
+    /// Referrer wallet — must match `referrer.wallet`, and differ from `payer` and `recipient`.
+    /// Must be writable when receiving a kickback.
+    pub referrer: &'a AccountInfo<'info>,
+
+    /// Referrer's ATA for 'payment_mint' — receives `referrer.referral_bps` of the revenue shares.
+    /// Must be writable when receiving a kickback.
+    pub referrer_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["coupon_v1", project_pda, code, program_id]` — discount coupon, redeemed once.
+    /// Pass the program id when minting without a coupon.
+    pub coupon_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — records the NFT's mint index.
+    /// Must be writable and uninitialized.
+    pub mint_record_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["referrer_earnings_v1", referrer_pda, payment_mint, program_id]` — per-mint referral earnings.
+    /// Must be writable when minting with a referrer and a `payment_mint` other than `token_mint`; created on first use.
+    pub referrer_earnings_pda: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintUserV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, payer_ata, project_pda, vault_pda, vault_ata, user_minted_pda, nft_authority, nft_collection, nft_asset, token_mint, revenue_wallet_0, revenue_wallet_ata_0, revenue_wallet_1, revenue_wallet_ata_1, revenue_wallet_2, revenue_wallet_ata_2, revenue_wallet_3, revenue_wallet_ata_3, revenue_wallet_4, revenue_wallet_ata_4, protocol_wallet, token_program, associated_token_program, system_program, mpl_core] =
+        let [payer, payer_ata, project_pda, vault_pda, vault_ata, user_minted_pda, nft_authority, nft_collection, nft_asset, token_mint, revenue_wallet_0, revenue_wallet_ata_0, revenue_wallet_1, revenue_wallet_ata_1, revenue_wallet_2, revenue_wallet_ata_2, revenue_wallet_3, revenue_wallet_ata_3, revenue_wallet_4, revenue_wallet_ata_4, protocol_config_pda, protocol_wallet, protocol_wallet_ata, token_program, associated_token_program, system_program, mpl_core, recipient, payment_mint, payment_mint_pda, referrer_pda, referrer, referrer_ata, coupon_pda, mint_record_pda, referrer_earnings_pda] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -132,16 +182,30 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintUserV1Accounts<'a, 'in
         WritableAccount::check(user_minted_pda)?;
         WritableAccount::check(nft_collection)?;
         WritableAccount::check(nft_asset)?;
-        WritableAccount::check(protocol_wallet)?;
+        WritableAccount::check(mint_record_pda)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
 
         UninitializedAccount::check(nft_asset)?;
 
+        if *recipient.key == Pubkey::default() {
+            msg!("Recipient cannot be the zero address");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         ProjectAccount::check(project_pda)?;
         MintAccount::check(token_mint)?;
+        MintAccount::check(payment_mint)?;
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
-        AssociatedTokenAccount::check(payer_ata, payer.key, token_mint.key, token_program.key)?;
+        if !TokenProgram::is_native(payment_mint.key) {
+            AssociatedTokenAccount::check(
+                payer_ata,
+                payer.key,
+                payment_mint.key,
+                token_program.key,
+            )?;
+        }
 
         Ok(Self {
             payer,
@@ -164,19 +228,34 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintUserV1Accounts<'a, 'in
             revenue_wallet_ata_3,
             revenue_wallet_4,
             revenue_wallet_ata_4,
+            protocol_config_pda,
             protocol_wallet,
+            protocol_wallet_ata,
             token_program,
             associated_token_program,
             system_program,
             mpl_core,
+            recipient,
+            payment_mint,
+            payment_mint_pda,
+            referrer_pda,
+            referrer,
+            referrer_ata,
+            coupon_pda,
+            mint_record_pda,
+            referrer_earnings_pda,
         })
     }
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct MintUserV1InstructionData {
+    /// NFT name. Must be empty when the project generates names (`project.naming`).
     pub nft_name: String,
+    /// NFT metadata URI. Must be empty when the project generates URIs (`project.naming`).
     pub nft_uri: String,
+    /// Highest total price the payer accepts, in raw units of `payment_mint`.
+    pub max_price: u64,
 }
 
 #[derive(Debug)]
@@ -215,6 +294,19 @@ impl<'a, 'info>
             program_id,
         )?;
 
+        if accounts.payment_mint.key != accounts.token_mint.key {
+            Pda::validate(
+                accounts.payment_mint_pda,
+                &[
+                    PaymentMintV1::SEED,
+                    accounts.project_pda.key.as_ref(),
+                    accounts.payment_mint.key.as_ref(),
+                ],
+                program_id,
+            )?;
+            PaymentMintAccount::check(accounts.payment_mint_pda)?;
+        }
+
         let (_, nft_authority_bump) =
             Pda::validate(accounts.nft_authority, &[NftAuthorityV1::SEED], program_id)?;
 
@@ -228,12 +320,11 @@ impl<'a, 'info>
 }
 
 impl<'a, 'info> MintUserV1<'a, 'info> {
-    fn check_mint_eligibility(&self, project: &ProjectV1) -> ProgramResult {
+    fn check_mint_eligibility(&self, project: &ProjectV1, now: i64) -> ProgramResult {
         let max_supply = project.max_supply;
-        let released = project.released;
-        let admin_minted = project.admin_minted;
+        let public_supply = project.public_supply_at(now);
         let user_minted = project.user_minted;
-        let minted = admin_minted + user_minted;
+        let minted = project.total_minted();
 
         if !project.nft_stock_available() {
             msg!(
@@ -244,10 +335,10 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
             return Err(ProgramError::Custom(0));
         }
 
-        if !project.user_mint_available() {
+        if !project.user_mint_available(now) {
             msg!(
                 "Sold out. Allowed supply: {}. Minted: {}",
-                released,
+                public_supply,
                 user_minted,
             );
             return Err(ProgramError::Custom(1));
@@ -256,12 +347,179 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
         Ok(())
     }
 
+    /// Returns the price split, decimals and vault requirement in `payment_mint`.
+    fn payment_terms(
+        &self,
+        project: &ProjectV1,
+        now: i64,
+    ) -> Result<(PriceSplit, u8, bool), ProgramError> {
+        let price = project.mint_price_at(now)?;
+
+        if self.accounts.payment_mint.key == self.accounts.token_mint.key {
+            return Ok((
+                project.price_split(price)?,
+                project.mint_decimals,
+                project.need_vault(),
+            ));
+        }
+
+        let payment_mint_data = self.accounts.payment_mint_pda.try_borrow_data()?;
+        let payment_mint = PaymentMintV1::load(&payment_mint_data)?;
+
+        if !payment_mint.is_active(now) {
+            msg!(
+                "Payment mint {} not active until {}",
+                payment_mint.mint,
+                payment_mint.active_from_ts
+            );
+            return Err(ProgramError::Custom(20));
+        }
+
+        Ok((
+            payment_mint.price_split(project, price)?,
+            payment_mint.mint_decimals,
+            payment_mint.need_vault(),
+        ))
+    }
+
+    fn has_coupon(&self) -> bool {
+        self.accounts.coupon_pda.key != self.program_id
+    }
+
+    /// Redeems the coupon and takes its discount out of the revenue shares of `split`.
+    fn redeem_coupon(&self, split: &mut PriceSplit, now: i64) -> ProgramResult {
+        WritableAccount::check(self.accounts.coupon_pda)?;
+        CouponAccount::check(self.accounts.coupon_pda)?;
+
+        let mut coupon_data = self.accounts.coupon_pda.try_borrow_mut_data()?;
+        let coupon = CouponV1::load_mut(&mut coupon_data)?;
+
+        if coupon.project != *self.accounts.project_pda.key {
+            msg!(
+                "Coupon belongs to project {}, not {}",
+                coupon.project,
+                self.accounts.project_pda.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if coupon.kind == CouponKind::Fixed
+            && self.accounts.payment_mint.key != self.accounts.token_mint.key
+        {
+            msg!("Fixed coupons only apply to payments in the project mint");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        coupon.redeem(self.accounts.payer.key, now)?;
+
+        let discount = split.apply_discount(coupon.discount(split.total()?))?;
+        msg!("Coupon discount applied: {}", discount);
+
+        Ok(())
+    }
+
+    fn has_referrer(&self) -> bool {
+        self.accounts.referrer_pda.key != self.program_id
+    }
+
+    /// Diverts the referral cut out of `split` to the referrer and records the referral.
+    fn pay_referrer(&self, split: &mut PriceSplit, decimals: u8) -> ProgramResult {
+        Pda::validate(
+            self.accounts.referrer_pda,
+            &[
+                ReferrerV1::SEED,
+                self.accounts.project_pda.key.as_ref(),
+                self.accounts.referrer.key.as_ref(),
+            ],
+            self.program_id,
+        )?;
+
+        WritableAccount::check(self.accounts.referrer_pda)?;
+        ReferrerAccount::check(self.accounts.referrer_pda)?;
+
+        ReferrerV1::check_not_self_referral(
+            self.accounts.referrer.key,
+            &[self.accounts.payer.key, self.accounts.recipient.key],
+        )?;
+
+        let mut referrer_data = self.accounts.referrer_pda.try_borrow_mut_data()?;
+        let referrer = ReferrerV1::load_mut(&mut referrer_data)?;
+
+        let amount = split.take_referral(referrer.referral_bps)?;
+
+        if amount > 0 {
+            WritableAccount::check(self.accounts.referrer)?;
+            WritableAccount::check(self.accounts.referrer_ata)?;
+
+            RevenueWallet::transfer(
+                RevenueWalletAccounts {
+                    payer_ata: self.accounts.payer_ata,
+                    destination_ata: self.accounts.referrer_ata,
+                    wallet: self.accounts.referrer,
+                    payer: self.accounts.payer,
+                    mint: self.accounts.payment_mint,
+                    token_program: self.accounts.token_program,
+                    associated_token_program: self.accounts.associated_token_program,
+                    system_program: self.accounts.system_program,
+                },
+                RevenueWalletArgs { amount, decimals },
+            )?;
+        }
+
+        let earned = if self.accounts.payment_mint.key == self.accounts.token_mint.key {
+            amount
+        } else {
+            self.record_referrer_earnings(amount)?;
+            0
+        };
+
+        referrer.record(earned)
+    }
+
+    /// Adds a kickback paid in a `payment_mint` other than `token_mint` to its `ReferrerEarningsV1`.
+    fn record_referrer_earnings(&self, amount: u64) -> ProgramResult {
+        WritableAccount::check(self.accounts.referrer_earnings_pda)?;
+
+        let seeds: &[&[u8]] = &[
+            ReferrerEarningsV1::SEED,
+            self.accounts.referrer_pda.key.as_ref(),
+            self.accounts.payment_mint.key.as_ref(),
+        ];
+
+        Pda::validate(self.accounts.referrer_earnings_pda, seeds, self.program_id)?;
+
+        ReferrerEarningsV1::init_if_needed(
+            InitReferrerEarningsAccounts {
+                pda: self.accounts.referrer_earnings_pda,
+            },
+            InitReferrerEarningsArgs {
+                referrer: *self.accounts.referrer_pda.key,
+                mint: *self.accounts.payment_mint.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: self.accounts.referrer_earnings_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: ReferrerEarningsV1::LEN,
+                program_id: self.program_id,
+            },
+        )?;
+
+        ReferrerEarningsAccount::check(self.accounts.referrer_earnings_pda)?;
+
+        let mut earnings_data = self.accounts.referrer_earnings_pda.try_borrow_mut_data()?;
+        ReferrerEarningsV1::load_mut(&mut earnings_data)?.record(amount)
+    }
+
     fn init_user_minted_if_needed(&self) -> ProgramResult {
         let seeds = &[
             UserMintedV1::SEED,
             self.accounts.nft_collection.key.as_ref(),
             self.accounts.token_mint.key.as_ref(),
-            self.accounts.payer.key.as_ref(),
+            self.accounts.recipient.key.as_ref(),
         ];
 
         UserMintedV1::init_if_needed(
@@ -269,7 +527,7 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
                 pda: self.accounts.user_minted_pda,
             },
             InitUserMintedArgs {
-                owner: self.accounts.payer.key,
+                owner: self.accounts.recipient.key,
             },
             InitPdaAccounts {
                 payer: self.accounts.payer,
@@ -284,8 +542,8 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
         )
     }
 
-    fn store_to_vault(&self, project: &ProjectV1) -> ProgramResult {
-        if !project.need_vault() {
+    fn store_to_vault(&self, split: &PriceSplit, decimals: u8, need_vault: bool) -> ProgramResult {
+        if !need_vault {
             return Ok(());
         }
 
@@ -302,8 +560,10 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
             },
             InitVaultArgs {
                 nft: *self.accounts.nft_asset.key,
-                amount: project.escrow_amount,
+                amount: split.escrow_amount,
                 is_unlocked: false,
+                price_paid: split.total()?,
+                mint: *self.accounts.payment_mint.key,
             },
             InitPdaAccounts {
                 payer: self.accounts.payer,
@@ -317,10 +577,19 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
             },
         )?;
 
+        if TokenProgram::is_native(self.accounts.payment_mint.key) {
+            return SystemProgram::transfer(
+                self.accounts.payer,
+                self.accounts.vault_pda,
+                self.accounts.system_program,
+                split.escrow_amount,
+            );
+        }
+
         AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
             payer: self.accounts.payer,
             wallet: self.accounts.vault_pda,
-            mint: self.accounts.token_mint,
+            mint: self.accounts.payment_mint,
             token_program: self.accounts.token_program,
             associated_token_program: self.accounts.associated_token_program,
             system_program: self.accounts.system_program,
@@ -332,17 +601,22 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
                 source: self.accounts.payer_ata,
                 destination: self.accounts.vault_ata,
                 authority: self.accounts.payer,
-                mint: self.accounts.token_mint,
+                mint: self.accounts.payment_mint,
                 token_program: self.accounts.token_program,
             },
             TokenTransferArgs {
-                amount: project.escrow_amount,
-                decimals: project.mint_decimals,
+                amount: split.escrow_amount,
+                decimals,
             },
         )
     }
 
-    fn pay_to_all_revenue_wallets(&self, project: &ProjectV1) -> ProgramResult {
+    fn pay_to_all_revenue_wallets(
+        &self,
+        project: &ProjectV1,
+        split: &PriceSplit,
+        decimals: u8,
+    ) -> ProgramResult {
         let num_wallets = project.num_revenue_wallets as usize;
 
         if num_wallets == 0 {
@@ -375,7 +649,7 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
                 project
                     .revenue_wallet(index)
                     .inspect_err(|_| msg!("Revenue wallet index {} not found!", index)),
-                project
+                split
                     .revenue_share(index)
                     .inspect_err(|_| msg!("Revenue share index {} not found!", index)),
             ) else {
@@ -416,21 +690,42 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
                     destination_ata: revenue_ata,
                     wallet: revenue_wallet,
                     payer: self.accounts.payer,
-                    mint: self.accounts.token_mint,
+                    mint: self.accounts.payment_mint,
                     token_program: self.accounts.token_program,
                     associated_token_program: self.accounts.associated_token_program,
                     system_program: self.accounts.system_program,
                 },
-                RevenueWalletArgs {
-                    amount,
-                    decimals: project.mint_decimals,
-                },
+                RevenueWalletArgs { amount, decimals },
             )?;
         }
 
         Ok(())
     }
 
+    fn pay_protocol_token_fee(&self, split: &PriceSplit, decimals: u8) -> ProgramResult {
+        let amount = split.protocol_fee;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        WritableAccount::check(self.accounts.protocol_wallet_ata)?;
+
+        RevenueWallet::transfer(
+            RevenueWalletAccounts {
+                payer_ata: self.accounts.payer_ata,
+                destination_ata: self.accounts.protocol_wallet_ata,
+                wallet: self.accounts.protocol_wallet,
+                payer: self.accounts.payer,
+                mint: self.accounts.payment_mint,
+                token_program: self.accounts.token_program,
+                associated_token_program: self.accounts.associated_token_program,
+                system_program: self.accounts.system_program,
+            },
+            RevenueWalletArgs { amount, decimals },
+        )
+    }
+
     fn pay_protocol_fee(&self, project: &ProjectV1) -> ProgramResult {
         if project.is_free_mint_nft_fee() {
             return Ok(());
@@ -446,20 +741,66 @@ impl<'a, 'info> MintUserV1<'a, 'info> {
         Ok(())
     }
 
-    fn mint_nft(self, project: &mut ProjectV1, user_minted: &mut UserMintedV1) -> ProgramResult {
+    fn init_mint_record(&self, index: u64, split: &PriceSplit) -> ProgramResult {
+        let clock = Clock::get()?;
+        let seeds: &[&[u8]] = &[MintRecordV1::SEED, self.accounts.nft_asset.key.as_ref()];
+
+        MintRecordV1::init(
+            InitMintRecordAccounts {
+                pda: self.accounts.mint_record_pda,
+            },
+            InitMintRecordArgs {
+                project: *self.accounts.project_pda.key,
+                nft: *self.accounts.nft_asset.key,
+                minter: *self.accounts.payer.key,
+                mint: *self.accounts.payment_mint.key,
+                index,
+                price_paid: split.total()?,
+                escrow_amount: split.escrow_amount,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+                tier: MintTier::User,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: self.accounts.mint_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MintRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn mint_nft(
+        self,
+        project: &mut ProjectV1,
+        user_minted: &mut UserMintedV1,
+        split: &PriceSplit,
+    ) -> ProgramResult {
+        let index = project.next_nft_index()?;
+        let (name, uri) = project.nft_metadata(
+            index,
+            &self.instruction_data.nft_name,
+            &self.instruction_data.nft_uri,
+            false,
+        )?;
+
+        self.init_mint_record(index, split)?;
+
         MplCoreProgram::create(
             CreateMplCoreAssetAccounts {
                 payer: self.accounts.payer,
+                owner: self.accounts.recipient,
                 asset: self.accounts.nft_asset,
                 collection: self.accounts.nft_collection,
                 authority: Some(self.accounts.nft_authority),
                 mpl_core: self.accounts.mpl_core,
                 system_program: self.accounts.system_program,
             },
-            CreateMplCoreAssetArgs {
-                name: self.instruction_data.nft_name,
-                uri: self.instruction_data.nft_uri,
-            },
+            CreateMplCoreAssetArgs { name, uri },
             &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
         )?;
 
@@ -475,19 +816,42 @@ impl<'a, 'info> ProcessInstruction for MintUserV1<'a, 'info> {
         let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
         let project = ProjectV1::load_mut(project_data.as_mut())?;
 
+        PauseFlag::MintUser.check(project.paused)?;
+        let now = Clock::get()?.unix_timestamp;
+        project.consume_mint_phase(MintPhaseKind::Public, now)?;
+
         self.init_user_minted_if_needed()?;
 
         let mut user_minted_data = self.accounts.user_minted_pda.try_borrow_mut_data()?;
         let user_minted = UserMintedV1::load_mut(user_minted_data.as_mut())?;
+        if user_minted.owner != *self.accounts.recipient.key {
+            msg!("User minted account does not belong to the recipient");
+            return Err(ProgramError::InvalidAccountData);
+        }
         if user_minted.has_reached_limit(project) {
             msg!("User has minted their allowed supply");
             return Err(ProgramError::Custom(2));
         }
 
-        self.check_mint_eligibility(project)?;
-        self.store_to_vault(project)?;
-        self.pay_to_all_revenue_wallets(project)?;
+        self.check_mint_eligibility(project, now)?;
+
+        let (mut split, decimals, need_vault) = self.payment_terms(project, now)?;
+
+        if self.has_coupon() {
+            self.redeem_coupon(&mut split, now)?;
+        }
+
+        split.check_max_price(self.instruction_data.max_price)?;
+
+        self.store_to_vault(&split, decimals, need_vault)?;
+
+        if self.has_referrer() {
+            self.pay_referrer(&mut split, decimals)?;
+        }
+
+        self.pay_to_all_revenue_wallets(project, &split, decimals)?;
+        self.pay_protocol_token_fee(&split, decimals)?;
         self.pay_protocol_fee(project)?;
-        self.mint_nft(project, user_minted)
+        self.mint_nft(project, user_minted, &split)
     }
 }
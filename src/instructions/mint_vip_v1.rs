@@ -1,21 +1,26 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 use crate::{
     states::{
-        InitUserMintedAccounts, InitUserMintedArgs, InitVaultAccounts, InitVaultArgs,
-        NftAuthorityV1, ProjectV1, UserMintedV1, VaultV1,
+        CouponV1, InitMintRecordAccounts, InitMintRecordArgs, InitUserMintedAccounts,
+        InitUserMintedArgs, InitVaultAccounts, InitVaultArgs, InitVipGateRecordAccounts,
+        InitVipGateRecordArgs, MintPhaseKind, MintRecordV1, MintTier, NftAuthorityV1, PauseFlag,
+        PriceSplit, ProjectV1, ReferrerV1, UserMintedV1, VaultV1, VipGateMode, VipGateRecordV1,
+        VipPassV1,
     },
     utils::{
         AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
-        CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs, InitAssociatedTokenProgramAccounts,
-        InitPdaAccounts, InitPdaArgs, MintAccount, MplCoreProgram, Pda, ProcessInstruction,
-        ProjectAccount, RevenueWallet, RevenueWalletAccounts, RevenueWalletArgs, SignerAccount,
-        SystemProgram, TokenProgram, TokenTransferAccounts, TokenTransferArgs,
-        UninitializedAccount, WritableAccount,
+        CouponAccount, CreateMplCoreAssetAccounts, CreateMplCoreAssetArgs,
+        InitAssociatedTokenProgramAccounts, InitPdaAccounts, InitPdaArgs, MintAccount,
+        MplCoreProgram, Pda, ProcessInstruction, ProjectAccount, ProtocolFeeWallet,
+        ProtocolFeeWalletCheck, ReferrerAccount, RevenueWallet, RevenueWalletAccounts,
+        RevenueWalletArgs, SignerAccount, SystemProgram, TokenAccount, TokenProgram,
+        TokenTransferAccounts, TokenTransferArgs, UninitializedAccount, VipPassAccount,
+        WritableAccount,
     },
 };
 
@@ -92,10 +97,19 @@ pub struct MintVipV1Accounts<'a, 'info> {
     pub revenue_wallet_ata_4: &'a AccountInfo<'info>,
 
     // --------------------------------------------------
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// Protocol wallet — receives the configurable SOL protocol fee.
     /// Must writable, not zero address, owned by system_program.
+    /// Must match `protocol_config.fee_wallet`.
     pub protocol_wallet: &'a AccountInfo<'info>,
 
+    /// Protocol wallet's ATA for 'token_mint' — receives `project.protocol_fee_bps` of the mint price.
+    /// Must be writable when the project has a protocol cut.
+    pub protocol_wallet_ata: &'a AccountInfo<'info>,
+
     /// SPL Token Program (legacy or Token-2022).
     /// Must match `token_mint.owner`.
     pub token_program: &'a AccountInfo<'info>,
@@ -111,13 +125,46 @@ pub struct MintVipV1Accounts<'a, 'info> {
     /// Metaplex Core program — for NFT minting.
     /// Must be the official MPL Core program.
     pub mpl_core: &'a AccountInfo<'info>,
+
+    /// PDA: `["vip_pass_v1", project_pda, payer, program_id]` — payer's VIP pass.
+    /// Pass the program id to prove VIP status with `vip_proof` instead.
+    pub vip_pass_pda: &'a AccountInfo<'info>,
+
+    /// Holder-gate proof: payer's token account of `project.vip_gate` (`VipGateMode::Token`)
+    /// or payer's MPL Core asset from collection `project.vip_gate` (`VipGateMode::Collection`).
+    /// Pass the program id when not using the gate.
+    pub vip_gate_account: &'a AccountInfo<'info>,
+
+    /// PDA: `["vip_gate_record_v1", project_pda, vip_gate_account, program_id]` — single-use marker.
+    /// Only used when `project.vip_gate_single_use` is set; otherwise pass the program id.
+    pub vip_gate_record_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["referrer_v1", project_pda, referrer, program_id]` — registered referrer.
+    /// Pass the program id when minting without a referrer.
+    pub referrer_pda: &'a AccountInfo<'info>,
+
+    /// Referrer wallet — must match `referrer.wallet` and differ from `payer`.
+    /// Must be writable when receiving a kickback.
+    pub referrer: &'a AccountInfo<'info>,
+
+    /// Referrer's ATA for 'token_mint' — receives `referrer.referral_bps` of the revenue shares.
+    /// Must be writable when receiving a kickback.
+    pub referrer_ata: &'a AccountInfo<'info>,
+
+    /// PDA: `["coupon_v1", project_pda, code, program_id]` — discount coupon, redeemed once.
+    /// Pass the program id when minting without a coupon.
+    pub coupon_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — records the NFT's mint index.
+    /// Must be writable and uninitialized.
+    pub mint_record_pda: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintVipV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, payer_ata, project_pda, vault_pda, vault_ata, user_minted_pda, nft_authority, nft_collection, nft_asset, token_mint, revenue_wallet_0, revenue_wallet_ata_0, revenue_wallet_1, revenue_wallet_ata_1, revenue_wallet_2, revenue_wallet_ata_2, revenue_wallet_3, revenue_wallet_ata_3, revenue_wallet_4, revenue_wallet_ata_4, protocol_wallet, token_program, associated_token_program, system_program, mpl_core] =
+        let [payer, payer_ata, project_pda, vault_pda, vault_ata, user_minted_pda, nft_authority, nft_collection, nft_asset, token_mint, revenue_wallet_0, revenue_wallet_ata_0, revenue_wallet_1, revenue_wallet_ata_1, revenue_wallet_2, revenue_wallet_ata_2, revenue_wallet_3, revenue_wallet_ata_3, revenue_wallet_4, revenue_wallet_ata_4, protocol_config_pda, protocol_wallet, protocol_wallet_ata, token_program, associated_token_program, system_program, mpl_core, vip_pass_pda, vip_gate_account, vip_gate_record_pda, referrer_pda, referrer, referrer_ata, coupon_pda, mint_record_pda] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -133,7 +180,8 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintVipV1Accounts<'a, 'inf
         WritableAccount::check(user_minted_pda)?;
         WritableAccount::check(nft_collection)?;
         WritableAccount::check(nft_asset)?;
-        WritableAccount::check(protocol_wallet)?;
+        WritableAccount::check(mint_record_pda)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
 
         UninitializedAccount::check(nft_asset)?;
 
@@ -142,7 +190,9 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintVipV1Accounts<'a, 'inf
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
 
-        AssociatedTokenAccount::check(payer_ata, payer.key, token_mint.key, token_program.key)?;
+        if !TokenProgram::is_native(token_mint.key) {
+            AssociatedTokenAccount::check(payer_ata, payer.key, token_mint.key, token_program.key)?;
+        }
 
         Ok(Self {
             payer,
@@ -165,19 +215,36 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for MintVipV1Accounts<'a, 'inf
             revenue_wallet_ata_3,
             revenue_wallet_4,
             revenue_wallet_ata_4,
+            protocol_config_pda,
             protocol_wallet,
+            protocol_wallet_ata,
             token_program,
             associated_token_program,
             system_program,
             mpl_core,
+            vip_pass_pda,
+            vip_gate_account,
+            vip_gate_record_pda,
+            referrer_pda,
+            referrer,
+            referrer_ata,
+            coupon_pda,
+            mint_record_pda,
         })
     }
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct MintVipV1InstructionData {
+    /// NFT name. Must be empty when the project generates names (`project.naming`).
     pub nft_name: String,
+    /// NFT metadata URI. Must be empty when the project generates URIs (`project.naming`).
     pub nft_uri: String,
+    /// Sibling hashes proving `payer` is in `project.vip_merkle_root`.
+    /// Ignored when a VIP pass is supplied.
+    pub vip_proof: Vec<[u8; 32]>,
+    /// Highest total price the payer accepts, in raw units of `token_mint`.
+    pub max_price: u64,
 }
 
 #[derive(Debug)]
@@ -229,12 +296,209 @@ impl<'a, 'info>
 }
 
 impl<'a, 'info> MintVipV1<'a, 'info> {
-    fn check_mint_eligibility(&self, project: &ProjectV1) -> ProgramResult {
+    fn has_vip_pass(&self) -> bool {
+        self.accounts.vip_pass_pda.key != self.program_id
+    }
+
+    fn has_vip_gate_account(&self) -> bool {
+        self.accounts.vip_gate_account.key != self.program_id
+    }
+
+    fn has_coupon(&self) -> bool {
+        self.accounts.coupon_pda.key != self.program_id
+    }
+
+    /// Redeems the coupon and takes its discount out of the revenue shares of `split`.
+    fn redeem_coupon(&self, split: &mut PriceSplit, now: i64) -> ProgramResult {
+        WritableAccount::check(self.accounts.coupon_pda)?;
+        CouponAccount::check(self.accounts.coupon_pda)?;
+
+        let mut coupon_data = self.accounts.coupon_pda.try_borrow_mut_data()?;
+        let coupon = CouponV1::load_mut(&mut coupon_data)?;
+
+        if coupon.project != *self.accounts.project_pda.key {
+            msg!(
+                "Coupon belongs to project {}, not {}",
+                coupon.project,
+                self.accounts.project_pda.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        coupon.redeem(self.accounts.payer.key, now)?;
+
+        let discount = split.apply_discount(coupon.discount(split.total()?))?;
+        msg!("Coupon discount applied: {}", discount);
+
+        Ok(())
+    }
+
+    fn has_referrer(&self) -> bool {
+        self.accounts.referrer_pda.key != self.program_id
+    }
+
+    fn check_vip_proof(&self, project: &ProjectV1) -> ProgramResult {
+        if !project.is_vip(self.accounts.payer.key, &self.instruction_data.vip_proof) {
+            msg!(
+                "Payer {} is not on the VIP allowlist",
+                self.accounts.payer.key
+            );
+            return Err(ProgramError::Custom(9));
+        }
+
+        Ok(())
+    }
+
+    fn check_vip_gate(&self, project: &ProjectV1) -> ProgramResult {
+        match project.vip_gate_mode {
+            VipGateMode::None => {
+                msg!("Project has no VIP gate");
+                Err(ProgramError::Custom(9))
+            }
+            VipGateMode::Token => self.check_token_gate(project),
+            VipGateMode::Collection => self.check_collection_gate(project),
+        }
+    }
+
+    fn check_token_gate(&self, project: &ProjectV1) -> ProgramResult {
+        let gate_account = self.accounts.vip_gate_account;
+
+        // Owned by the gate mint's own token program, which may differ from the payment one.
+        TokenAccount::check(gate_account)?;
+
+        if project.vip_gate_single_use {
+            msg!("VIP token gates can't be single use");
+            return Err(ProgramError::Custom(9));
+        }
+
+        let (mint, owner) = TokenProgram::get_mint_and_owner(gate_account)?;
+        if mint != project.vip_gate || owner != *self.accounts.payer.key {
+            msg!(
+                "VIP gate token account {} is not a {} account of the payer",
+                gate_account.key,
+                project.vip_gate
+            );
+            return Err(ProgramError::Custom(9));
+        }
+
+        let balance = TokenProgram::get_balance_of(gate_account, gate_account.owner)?;
+        if balance < project.vip_gate_min_amount {
+            msg!(
+                "VIP gate balance too low. Required: {}. Held: {}",
+                project.vip_gate_min_amount,
+                balance
+            );
+            return Err(ProgramError::Custom(9));
+        }
+
+        Ok(())
+    }
+
+    fn check_collection_gate(&self, project: &ProjectV1) -> ProgramResult {
+        let gate_asset = self.accounts.vip_gate_account;
+
+        if gate_asset.owner != self.accounts.mpl_core.key {
+            msg!("VIP gate asset {} is not an MPL Core asset", gate_asset.key);
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let owner = MplCoreProgram::get_asset_owner(gate_asset)?;
+        let collection = MplCoreProgram::get_asset_collection(gate_asset)?;
+        if owner != *self.accounts.payer.key || collection != Some(project.vip_gate) {
+            msg!(
+                "VIP gate asset {} is not a {} asset owned by the payer",
+                gate_asset.key,
+                project.vip_gate
+            );
+            return Err(ProgramError::Custom(9));
+        }
+
+        if project.vip_gate_single_use {
+            self.record_vip_gate_use()?;
+        }
+
+        Ok(())
+    }
+
+    fn record_vip_gate_use(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            VipGateRecordV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.accounts.vip_gate_account.key.as_ref(),
+        ];
+
+        WritableAccount::check(self.accounts.vip_gate_record_pda)?;
+
+        if UninitializedAccount::check(self.accounts.vip_gate_record_pda).is_err() {
+            msg!(
+                "VIP gate asset {} was already used",
+                self.accounts.vip_gate_account.key
+            );
+            return Err(ProgramError::Custom(9));
+        }
+
+        VipGateRecordV1::init(
+            InitVipGateRecordAccounts {
+                pda: self.accounts.vip_gate_record_pda,
+            },
+            InitVipGateRecordArgs {
+                project: *self.accounts.project_pda.key,
+                asset: *self.accounts.vip_gate_account.key,
+                wallet: *self.accounts.payer.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: self.accounts.vip_gate_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: VipGateRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn consume_vip_pass(&self, project: &ProjectV1) -> ProgramResult {
+        Pda::validate(
+            self.accounts.vip_pass_pda,
+            &[
+                VipPassV1::SEED,
+                self.accounts.project_pda.key.as_ref(),
+                self.accounts.payer.key.as_ref(),
+            ],
+            self.program_id,
+        )?;
+
+        WritableAccount::check(self.accounts.vip_pass_pda)?;
+        VipPassAccount::check(self.accounts.vip_pass_pda)?;
+
+        let mut vip_pass_data = self.accounts.vip_pass_pda.try_borrow_mut_data()?;
+        let vip_pass = VipPassV1::load_mut(&mut vip_pass_data)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        if vip_pass.is_expired(now) {
+            msg!("VIP pass expired at {}", vip_pass.expires_at);
+            return Err(ProgramError::Custom(9));
+        }
+
+        if vip_pass.has_reached_limit(project) {
+            msg!(
+                "VIP pass allocation used up. Allowed: {}. Minted: {}",
+                vip_pass.allocation(project),
+                vip_pass.minted
+            );
+            return Err(ProgramError::Custom(2));
+        }
+
+        vip_pass.consume()
+    }
+
+    fn check_mint_eligibility(&self, project: &ProjectV1, now: i64) -> ProgramResult {
         let max_supply = project.max_supply;
-        let released = project.released;
-        let admin_minted = project.admin_minted;
-        let user_minted = project.user_minted;
-        let minted = admin_minted + user_minted;
+        let vip_supply = project.vip_supply_at(now);
+        let vip_minted = project.vip_minted;
+        let minted = project.total_minted();
 
         if !project.nft_stock_available() {
             msg!(
@@ -245,11 +509,11 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
             return Err(ProgramError::Custom(0));
         }
 
-        if !project.user_mint_available() {
+        if !project.vip_mint_available(now) {
             msg!(
-                "Sold out. Allowed supply: {}. Minted: {}",
-                released,
-                user_minted
+                "VIP sold out. Allowed supply: {}. Minted: {}",
+                vip_supply,
+                vip_minted
             );
             return Err(ProgramError::Custom(1));
         }
@@ -285,7 +549,57 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
         )
     }
 
-    fn store_to_vault(&self, project: &ProjectV1) -> ProgramResult {
+    /// Diverts the referral cut out of `split` to the referrer and records the referral.
+    fn pay_referrer(&self, project: &ProjectV1, split: &mut PriceSplit) -> ProgramResult {
+        Pda::validate(
+            self.accounts.referrer_pda,
+            &[
+                ReferrerV1::SEED,
+                self.accounts.project_pda.key.as_ref(),
+                self.accounts.referrer.key.as_ref(),
+            ],
+            self.program_id,
+        )?;
+
+        WritableAccount::check(self.accounts.referrer_pda)?;
+        ReferrerAccount::check(self.accounts.referrer_pda)?;
+
+        ReferrerV1::check_not_self_referral(
+            self.accounts.referrer.key,
+            &[self.accounts.payer.key],
+        )?;
+
+        let mut referrer_data = self.accounts.referrer_pda.try_borrow_mut_data()?;
+        let referrer = ReferrerV1::load_mut(&mut referrer_data)?;
+
+        let amount = split.take_referral(referrer.referral_bps)?;
+
+        if amount > 0 {
+            WritableAccount::check(self.accounts.referrer)?;
+            WritableAccount::check(self.accounts.referrer_ata)?;
+
+            RevenueWallet::transfer(
+                RevenueWalletAccounts {
+                    payer_ata: self.accounts.payer_ata,
+                    destination_ata: self.accounts.referrer_ata,
+                    wallet: self.accounts.referrer,
+                    payer: self.accounts.payer,
+                    mint: self.accounts.token_mint,
+                    token_program: self.accounts.token_program,
+                    associated_token_program: self.accounts.associated_token_program,
+                    system_program: self.accounts.system_program,
+                },
+                RevenueWalletArgs {
+                    amount,
+                    decimals: project.mint_decimals,
+                },
+            )?;
+        }
+
+        referrer.record(amount)
+    }
+
+    fn store_to_vault(&self, project: &ProjectV1, split: &PriceSplit) -> ProgramResult {
         if !project.need_vault() {
             return Ok(());
         }
@@ -303,8 +617,10 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
             },
             InitVaultArgs {
                 nft: *self.accounts.nft_asset.key,
-                amount: project.escrow_amount,
+                amount: split.escrow_amount,
                 is_unlocked: false,
+                price_paid: split.total()?,
+                mint: *self.accounts.token_mint.key,
             },
             InitPdaAccounts {
                 payer: self.accounts.payer,
@@ -318,6 +634,15 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
             },
         )?;
 
+        if TokenProgram::is_native(self.accounts.token_mint.key) {
+            return SystemProgram::transfer(
+                self.accounts.payer,
+                self.accounts.vault_pda,
+                self.accounts.system_program,
+                split.escrow_amount,
+            );
+        }
+
         AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
             payer: self.accounts.payer,
             wallet: self.accounts.vault_pda,
@@ -337,7 +662,7 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
                 token_program: self.accounts.token_program,
             },
             TokenTransferArgs {
-                amount: project.escrow_amount,
+                amount: split.escrow_amount,
                 decimals: project.mint_decimals,
             },
         )?;
@@ -345,7 +670,7 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
         Ok(())
     }
 
-    fn pay_to_all_revenue_wallets(&self, project: &ProjectV1) -> ProgramResult {
+    fn pay_to_all_revenue_wallets(&self, project: &ProjectV1, split: &PriceSplit) -> ProgramResult {
         let num_wallets = project.num_revenue_wallets as usize;
 
         if num_wallets == 0 {
@@ -378,7 +703,7 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
                 project
                     .revenue_wallet(index)
                     .inspect_err(|_| msg!("Revenue wallet index {} not found!", index)),
-                project
+                split
                     .revenue_share(index)
                     .inspect_err(|_| msg!("Revenue share index {} not found!", index)),
             ) else {
@@ -434,6 +759,33 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
         Ok(())
     }
 
+    fn pay_protocol_token_fee(&self, project: &ProjectV1, split: &PriceSplit) -> ProgramResult {
+        let amount = split.protocol_fee;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        WritableAccount::check(self.accounts.protocol_wallet_ata)?;
+
+        RevenueWallet::transfer(
+            RevenueWalletAccounts {
+                payer_ata: self.accounts.payer_ata,
+                destination_ata: self.accounts.protocol_wallet_ata,
+                wallet: self.accounts.protocol_wallet,
+                payer: self.accounts.payer,
+                mint: self.accounts.token_mint,
+                token_program: self.accounts.token_program,
+                associated_token_program: self.accounts.associated_token_program,
+                system_program: self.accounts.system_program,
+            },
+            RevenueWalletArgs {
+                amount,
+                decimals: project.mint_decimals,
+            },
+        )
+    }
+
     fn pay_protocol_fee(&self, project: &ProjectV1) -> ProgramResult {
         if project.is_free_mint_nft_fee() {
             return Ok(());
@@ -447,25 +799,71 @@ impl<'a, 'info> MintVipV1<'a, 'info> {
         )
     }
 
-    fn mint_nft(self, project: &mut ProjectV1, user_minted: &mut UserMintedV1) -> ProgramResult {
+    fn init_mint_record(&self, index: u64, split: &PriceSplit) -> ProgramResult {
+        let clock = Clock::get()?;
+        let seeds: &[&[u8]] = &[MintRecordV1::SEED, self.accounts.nft_asset.key.as_ref()];
+
+        MintRecordV1::init(
+            InitMintRecordAccounts {
+                pda: self.accounts.mint_record_pda,
+            },
+            InitMintRecordArgs {
+                project: *self.accounts.project_pda.key,
+                nft: *self.accounts.nft_asset.key,
+                minter: *self.accounts.payer.key,
+                mint: *self.accounts.token_mint.key,
+                index,
+                price_paid: split.total()?,
+                escrow_amount: split.escrow_amount,
+                slot: clock.slot,
+                timestamp: clock.unix_timestamp,
+                tier: MintTier::Vip,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.payer,
+                pda: self.accounts.mint_record_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: MintRecordV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn mint_nft(
+        self,
+        project: &mut ProjectV1,
+        user_minted: &mut UserMintedV1,
+        split: &PriceSplit,
+    ) -> ProgramResult {
+        let index = project.next_nft_index()?;
+        let (name, uri) = project.nft_metadata(
+            index,
+            &self.instruction_data.nft_name,
+            &self.instruction_data.nft_uri,
+            false,
+        )?;
+
+        self.init_mint_record(index, split)?;
+
         MplCoreProgram::create(
             CreateMplCoreAssetAccounts {
                 payer: self.accounts.payer,
+                owner: self.accounts.payer,
                 asset: self.accounts.nft_asset,
                 collection: self.accounts.nft_collection,
                 authority: Some(self.accounts.nft_authority),
                 mpl_core: self.accounts.mpl_core,
                 system_program: self.accounts.system_program,
             },
-            CreateMplCoreAssetArgs {
-                name: self.instruction_data.nft_name,
-                uri: self.instruction_data.nft_uri,
-            },
+            CreateMplCoreAssetArgs { name, uri },
             &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
         )?;
 
         user_minted.increment();
-        project.increment_user_minted()?;
+        project.increment_vip_minted()?;
 
         Ok(())
     }
@@ -476,19 +874,49 @@ impl<'a, 'info> ProcessInstruction for MintVipV1<'a, 'info> {
         let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
         let project = ProjectV1::load_mut(project_data.as_mut())?;
 
+        PauseFlag::MintVip.check(project.paused)?;
+        let now = Clock::get()?.unix_timestamp;
+        project.consume_mint_phase(MintPhaseKind::Vip, now)?;
+
         self.init_user_mint_if_needed()?;
 
         let mut user_minted_data = self.accounts.user_minted_pda.try_borrow_mut_data()?;
         let user_minted = UserMintedV1::load_mut(user_minted_data.as_mut())?;
-        if user_minted.has_reached_vip_limit(project) {
-            msg!("VIP user has minted their allowed supply");
-            return Err(ProgramError::Custom(2));
+
+        if self.has_vip_pass() {
+            self.consume_vip_pass(project)?;
+        } else {
+            if self.has_vip_gate_account() {
+                self.check_vip_gate(project)?;
+            } else {
+                self.check_vip_proof(project)?;
+            }
+
+            if user_minted.has_reached_vip_limit(project) {
+                msg!("VIP user has minted their allowed supply");
+                return Err(ProgramError::Custom(2));
+            }
+        }
+
+        self.check_mint_eligibility(project, now)?;
+
+        let mut split = project.price_split(project.vip_mint_price()?)?;
+
+        if self.has_coupon() {
+            self.redeem_coupon(&mut split, now)?;
+        }
+
+        split.check_max_price(self.instruction_data.max_price)?;
+
+        self.store_to_vault(project, &split)?;
+
+        if self.has_referrer() {
+            self.pay_referrer(project, &mut split)?;
         }
 
-        self.check_mint_eligibility(project)?;
-        self.store_to_vault(project)?;
-        self.pay_to_all_revenue_wallets(project)?;
+        self.pay_to_all_revenue_wallets(project, &split)?;
+        self.pay_protocol_token_fee(project, &split)?;
         self.pay_protocol_fee(project)?;
-        self.mint_nft(project, user_minted)
+        self.mint_nft(project, user_minted, &split)
     }
 }
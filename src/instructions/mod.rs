@@ -1,27 +1,89 @@
+mod accept_admin_v1;
+mod approve_multisig_proposal_v1;
 mod burn_and_refund_v1;
+mod cancel_admin_proposal_v1;
+mod cancel_project_update_v1;
+mod close_coupon_v1;
+mod create_coupon_v1;
+mod create_multisig_proposal_v1;
+mod execute_project_update_v1;
 mod force_unlock_vesting_v1;
+mod grant_project_role_v1;
+mod grant_vip_pass_v1;
+mod init_multisig_v1;
 mod init_project_v1;
+mod init_protocol_config_v1;
 mod init_trait_v1;
+mod migrate_project_v1;
+mod migrate_trait_v1;
+mod mint_admin_airdrop_v1;
 mod mint_admin_v1;
 mod mint_trait_v1;
+mod mint_user_batch_v1;
 mod mint_user_v1;
 mod mint_vip_v1;
+mod propose_admin_v1;
+mod queue_project_update_v1;
+mod register_referrer_v1;
+mod remove_payment_mint_v1;
+mod reveal_v1;
+mod revoke_project_role_v1;
+mod revoke_vip_pass_v1;
+mod set_metadata_policy_v1;
+mod set_mint_phases_v1;
+mod set_nft_naming_v1;
+mod set_payment_mint_v1;
+mod set_project_pause_v1;
+mod set_project_timelock_v1;
+mod set_trait_pause_v1;
 mod transfer_to_vault_v1;
 mod update_nft_v1;
 mod update_project_v1;
+mod update_protocol_config_v1;
 mod update_trait_v1;
 
+pub use accept_admin_v1::*;
+pub use approve_multisig_proposal_v1::*;
 pub use burn_and_refund_v1::*;
+pub use cancel_admin_proposal_v1::*;
+pub use cancel_project_update_v1::*;
+pub use close_coupon_v1::*;
+pub use create_coupon_v1::*;
+pub use create_multisig_proposal_v1::*;
+pub use execute_project_update_v1::*;
 pub use force_unlock_vesting_v1::*;
+pub use grant_project_role_v1::*;
+pub use grant_vip_pass_v1::*;
+pub use init_multisig_v1::*;
 pub use init_project_v1::*;
+pub use init_protocol_config_v1::*;
 pub use init_trait_v1::*;
+pub use migrate_project_v1::*;
+pub use migrate_trait_v1::*;
+pub use mint_admin_airdrop_v1::*;
 pub use mint_admin_v1::*;
 pub use mint_trait_v1::*;
+pub use mint_user_batch_v1::*;
 pub use mint_user_v1::*;
 pub use mint_vip_v1::*;
+pub use propose_admin_v1::*;
+pub use queue_project_update_v1::*;
+pub use register_referrer_v1::*;
+pub use remove_payment_mint_v1::*;
+pub use reveal_v1::*;
+pub use revoke_project_role_v1::*;
+pub use revoke_vip_pass_v1::*;
+pub use set_metadata_policy_v1::*;
+pub use set_mint_phases_v1::*;
+pub use set_nft_naming_v1::*;
+pub use set_payment_mint_v1::*;
+pub use set_project_pause_v1::*;
+pub use set_project_timelock_v1::*;
+pub use set_trait_pause_v1::*;
 pub use transfer_to_vault_v1::*;
 pub use update_nft_v1::*;
 pub use update_project_v1::*;
+pub use update_protocol_config_v1::*;
 pub use update_trait_v1::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -61,11 +123,16 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         5,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        6,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        6,
+        7,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
@@ -85,30 +152,49 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — only read when admin is not project.admin."
+    )]
+    #[account(
+        3,
         name = "nft_authority",
         desc = "PDA that have authority control of nft mint, updates, and burn."
     )]
     #[account(
-        3,
+        4,
         writable,
         name = "nft_collection",
         desc = "MPL Core Collection account that groups NFTs under this project."
     )]
     #[account(
-        4,
+        5,
         name = "token_mint",
         desc = "Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022."
     )]
     #[account(
-        5,
+        6,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        6,
+        7,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
+    #[account(
+        8,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        9,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
     UpdateProjectV1(UpdateProjectV1InstructionData),
 
     #[account(
@@ -131,61 +217,77 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         3,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — only read when admin is not project.admin."
+    )]
+    #[account(
+        4,
         writable,
         name = "vault_pda",
         desc = "Uninitialized vault pda with seeds [\"vault_v1\", nft_asset, nft_collection, token_mint, program_id]"
     )]
     #[account(
-        4,
+        5,
         writable,
         name = "vault_ata",
         desc = "Vault PDA's associated token account — holds escrowed 'token_mint' funds."
     )]
-    #[account(5, name = "nft_authority", desc = "Controls: update all NFTs.")]
+    #[account(6, name = "nft_authority", desc = "Controls: update all NFTs.")]
     #[account(
-        6,
+        7,
         writable,
         name = "nft_collection",
         desc = "MPL Core Collection account that groups NFTs under this project."
     )]
     #[account(
-        7,
+        8,
         signer,
         writable,
         name = "nft_asset",
         desc = "Uninitialize NFT asset (MPL Core) — the NFT being minted."
     )]
     #[account(
-        8,
+        9,
         name = "token_mint",
         desc = "Token mint — the token being escrowed (e.g. ZDLT)"
     )]
     #[account(
-        9,
+        10,
         name = "token_program",
         desc = "SPL Token Program (legacy) or Token-2022 Program."
     )]
     #[account(
-        10,
+        11,
         name = "associated_token_program",
         desc = "Associated Token Program — for ATA derivation and creation."
     )]
     #[account(
-        11,
+        12,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        13,
         writable,
         name = "protocol_wallet",
         desc = "Protocol wallet — receives the configurable SOL protocol fee."
     )]
     #[account(
-        12,
+        14,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        13,
+        15,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
+    #[account(
+        16,
+        writable,
+        name = "mint_record_pda",
+        desc = "Mint record pda with seeds [\"mint_record_v1\", nft_asset, program_id] — must be uninitialized"
+    )]
     MintAdminV1(MintAdminV1InstructionData),
 
     #[account(
@@ -222,7 +324,7 @@ pub enum TweetoniumInstruction {
         5,
         writable,
         name = "user_minted_pda",
-        desc = "Uninitialize user mint pda with seeds [\"user_minted_v1\", nft_collection, token_mint, payer, program_id]"
+        desc = "Uninitialize user mint pda with seeds [\"user_minted_v1\", nft_collection, token_mint, recipient, program_id]"
     )]
     #[account(6, name = "nft_authority", desc = "Controls: update all NFTs.")]
     #[account(
@@ -305,30 +407,92 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         20,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        21,
         writable,
         name = "protocol_wallet",
         desc = "Protocol wallet — receives the configurable SOL protocol fee."
     )]
     #[account(
-        21,
+        22,
+        writable,
+        name = "protocol_wallet_ata",
+        desc = "ATA of the protocol wallet — receives the protocol cut of the mint price."
+    )]
+    #[account(
+        23,
         name = "token_program",
         desc = "SPL Token Program (legacy) or Token-2022 Program."
     )]
     #[account(
-        22,
+        24,
         name = "associated_token_program",
         desc = "Associated Token Program"
     )]
     #[account(
-        23,
+        25,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        24,
+        26,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
+    #[account(
+        27,
+        name = "recipient",
+        desc = "Wallet that will own the NFT — the payer itself, or another wallet for a gift mint."
+    )]
+    #[account(
+        28,
+        name = "payment_mint",
+        desc = "Token mint the price is paid in — token_mint, or a mint set with SetPaymentMintV1."
+    )]
+    #[account(
+        29,
+        name = "payment_mint_pda",
+        desc = "Payment mint pda with seeds [\"payment_mint_v1\", project_pda, payment_mint, program_id] — read only when payment_mint is not token_mint"
+    )]
+    #[account(
+        30,
+        writable,
+        name = "referrer_pda",
+        desc = "Referrer pda with seeds [\"referrer_v1\", project_pda, referrer, program_id], or the program id to mint without a referrer"
+    )]
+    #[account(
+        31,
+        writable,
+        name = "referrer",
+        desc = "Referrer wallet — must match referrer.wallet and differ from the payer and the recipient."
+    )]
+    #[account(
+        32,
+        writable,
+        name = "referrer_ata",
+        desc = "Referrer's ATA for payment_mint — receives referral_bps of the revenue shares."
+    )]
+    #[account(
+        33,
+        writable,
+        name = "coupon_pda",
+        desc = "Coupon pda with seeds [\"coupon_v1\", project_pda, code, program_id], or the program id to mint without a coupon"
+    )]
+    #[account(
+        34,
+        writable,
+        name = "mint_record_pda",
+        desc = "Mint record pda with seeds [\"mint_record_v1\", nft_asset, program_id] — must be uninitialized"
+    )]
+    #[account(
+        35,
+        writable,
+        name = "referrer_earnings_pda",
+        desc = "Referrer earnings pda with seeds [\"referrer_earnings_v1\", referrer_pda, payment_mint, program_id] — used when a referred mint pays in a payment_mint other than token_mint"
+    )]
     MintUserV1(MintUserV1InstructionData),
 
     #[account(
@@ -448,30 +612,88 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         20,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        21,
         writable,
         name = "protocol_wallet",
         desc = "Protocol wallet — receives the configurable SOL protocol fee."
     )]
     #[account(
-        21,
+        22,
+        writable,
+        name = "protocol_wallet_ata",
+        desc = "ATA of the protocol wallet — receives the protocol cut of the mint price."
+    )]
+    #[account(
+        23,
         name = "token_program",
         desc = "SPL Token Program (legacy) or Token-2022 Program."
     )]
     #[account(
-        22,
+        24,
         name = "associated_token_program",
         desc = "Associated Token Program"
     )]
     #[account(
-        23,
+        25,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        24,
+        26,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
+    #[account(
+        27,
+        writable,
+        name = "vip_pass_pda",
+        desc = "VIP pass pda with seeds [\"vip_pass_v1\", project_pda, payer, program_id], or the program id to use vip_proof"
+    )]
+    #[account(
+        28,
+        name = "vip_gate_account",
+        desc = "Payer's gate token account or gate collection asset, or the program id to skip the VIP gate"
+    )]
+    #[account(
+        29,
+        writable,
+        name = "vip_gate_record_pda",
+        desc = "VIP gate record pda with seeds [\"vip_gate_record_v1\", project_pda, vip_gate_account, program_id] for single-use gates, or the program id"
+    )]
+    #[account(
+        30,
+        writable,
+        name = "referrer_pda",
+        desc = "Referrer pda with seeds [\"referrer_v1\", project_pda, referrer, program_id], or the program id to mint without a referrer"
+    )]
+    #[account(
+        31,
+        writable,
+        name = "referrer",
+        desc = "Referrer wallet — must match referrer.wallet and differ from the payer."
+    )]
+    #[account(
+        32,
+        writable,
+        name = "referrer_ata",
+        desc = "Referrer's ATA for token_mint — receives referral_bps of the revenue shares."
+    )]
+    #[account(
+        33,
+        writable,
+        name = "coupon_pda",
+        desc = "Coupon pda with seeds [\"coupon_v1\", project_pda, code, program_id], or the program id to mint without a coupon"
+    )]
+    #[account(
+        34,
+        writable,
+        name = "mint_record_pda",
+        desc = "Mint record pda with seeds [\"mint_record_v1\", nft_asset, program_id] — must be uninitialized"
+    )]
     MintVipV1(MintVipV1InstructionData),
 
     #[account(
@@ -500,11 +722,16 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         4,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        5,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        5,
+        6,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
@@ -577,17 +804,22 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         5,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        6,
         writable,
         name = "protocol_wallet",
         desc = "Protocol wallet — receives the configurable SOL protocol fee."
     )]
     #[account(
-        6,
+        7,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        7,
+        8,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
@@ -628,17 +860,22 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         6,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        7,
         writable,
         name = "protocol_wallet",
         desc = "Protocol wallet — receives the configurable SOL protocol fee."
     )]
     #[account(
-        7,
+        8,
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
     #[account(
-        8,
+        9,
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
@@ -706,6 +943,11 @@ pub enum TweetoniumInstruction {
         name = "mpl_core",
         desc = "Metaplex Core program — must be the official MPL Core program."
     )]
+    #[account(
+        12,
+        name = "escrow_mint",
+        desc = "Token mint escrowed in the vault — must match vault.mint."
+    )]
     BurnAndRefundV1,
 
     #[account(
@@ -722,15 +964,34 @@ pub enum TweetoniumInstruction {
     )]
     #[account(
         2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — only read when admin is not project.admin."
+    )]
+    #[account(
+        3,
         name = "token_mint",
         desc = "Token mint — the token being escrowed (e.g. ZDLT)"
     )]
     #[account(
-        3,
+        4,
         writable,
         name = "nft_collection",
         desc = "MPL Core Collection account that groups NFTs under this project."
     )]
+    #[account(
+        5,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        6,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
     ForceUnlockVestingV1,
 
     #[account(
@@ -792,5 +1053,1048 @@ pub enum TweetoniumInstruction {
         name = "system_program",
         desc = "System Program — required for PDA creation and rent."
     )]
+    #[account(
+        11,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, project_token_mint, program_id] — read for the pause mask."
+    )]
     TransferToVaultV1(TransferToVaultV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin — must match project.admin. Pays for the role account."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, wallet, program_id] — created on first grant."
+    )]
+    #[account(3, name = "wallet", desc = "Wallet receiving the roles.")]
+    #[account(
+        4,
+        name = "nft_collection",
+        desc = "MPL Core Collection account that groups NFTs under this project."
+    )]
+    #[account(
+        5,
+        name = "token_mint",
+        desc = "Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022."
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    GrantProjectRoleV1(GrantProjectRoleV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin — must match project.admin. Receives rent when the role account is closed."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "project_role_pda",
+        desc = "Initialized project role pda with seeds [\"project_role_v1\", project_pda, wallet, program_id] — closed once no roles remain."
+    )]
+    #[account(3, name = "wallet", desc = "Wallet losing the roles.")]
+    #[account(
+        4,
+        name = "nft_collection",
+        desc = "MPL Core Collection account that groups NFTs under this project."
+    )]
+    #[account(
+        5,
+        name = "token_mint",
+        desc = "Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022."
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    RevokeProjectRoleV1(RevokeProjectRoleV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Current admin of target_pda. Pays for the proposal account."
+    )]
+    #[account(
+        1,
+        name = "target_pda",
+        desc = "Initialized ProjectV1 or TraitItemV1 pda whose admin is being handed over."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "admin_proposal_pda",
+        desc = "Admin proposal pda with seeds [\"admin_proposal_v1\", target_pda, program_id]"
+    )]
+    #[account(3, name = "new_admin", desc = "Wallet proposed as the new admin.")]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    ProposeAdminV1(ProposeAdminV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "new_admin",
+        desc = "Pending admin from the proposal. Must sign to take over."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "target_pda",
+        desc = "Initialized ProjectV1 or TraitItemV1 pda whose admin is being handed over."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "admin_proposal_pda",
+        desc = "Initialized admin proposal pda with seeds [\"admin_proposal_v1\", target_pda, program_id]"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "proposer",
+        desc = "Wallet that created the proposal — receives the proposal rent."
+    )]
+    AcceptAdminV1,
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Current admin of target_pda. Receives the proposal rent."
+    )]
+    #[account(
+        1,
+        name = "target_pda",
+        desc = "Initialized ProjectV1 or TraitItemV1 pda the proposal refers to."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "admin_proposal_pda",
+        desc = "Initialized admin proposal pda with seeds [\"admin_proposal_v1\", target_pda, program_id]"
+    )]
+    CancelAdminProposalV1,
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder. Pays for the multisig account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "multisig_pda",
+        desc = "Uninitialized multisig pda with seeds [\"multisig_v1\", project_pda, program_id]"
+    )]
+    #[account(
+        4,
+        name = "nft_collection",
+        desc = "MPL Core Collection account that groups NFTs under this project."
+    )]
+    #[account(
+        5,
+        name = "token_mint",
+        desc = "Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022."
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    InitMultisigV1(InitMultisigV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "proposer",
+        desc = "Multisig signer creating the proposal. Pays for the proposal account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "multisig_pda",
+        desc = "Initialized multisig pda with seeds [\"multisig_v1\", project_pda, program_id]"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "multisig_proposal_pda",
+        desc = "Uninitialized proposal pda with seeds [\"multisig_proposal_v1\", multisig_pda, multisig.proposal_count (u64 LE), program_id]"
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    CreateMultisigProposalV1(CreateMultisigProposalV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "signer",
+        desc = "Multisig signer approving the proposal."
+    )]
+    #[account(
+        1,
+        name = "multisig_pda",
+        desc = "Initialized multisig pda with seeds [\"multisig_v1\", project_pda, program_id]"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "multisig_proposal_pda",
+        desc = "Initialized proposal pda with seeds [\"multisig_proposal_v1\", multisig_pda, index (u64 LE), program_id]"
+    )]
+    ApproveMultisigProposalV1,
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(
+        5,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        6,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
+    SetProjectTimelockV1(SetProjectTimelockV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder; pays for the pending update."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "project_update_pda",
+        desc = "Uninitialized pending update pda with seeds [\"project_update_v1\", project_pda, program_id]"
+    )]
+    #[account(4, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(5, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(6, name = "system_program", desc = "System program.")]
+    #[account(
+        7,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        8,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
+    QueueProjectUpdateV1(QueueProjectUpdateV1InstructionData),
+
+    #[account(
+        0,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_update_pda",
+        desc = "Pending update pda with seeds [\"project_update_v1\", project_pda, program_id] — closed on execution"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "queued_by",
+        desc = "Wallet that queued the update — receives its rent."
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    ExecuteProjectUpdateV1,
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "project_update_pda",
+        desc = "Pending update pda with seeds [\"project_update_v1\", project_pda, program_id] — closed on cancel"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "queued_by",
+        desc = "Wallet that queued the update — receives its rent."
+    )]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(
+        7,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        8,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
+    CancelProjectUpdateV1,
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    SetProjectPauseV1(SetProjectPauseV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Trait authority — must match trait_item.authority."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "trait_pda",
+        desc = "Initialized trait pda with seeds [\"trait_item_v1\", trait_collection, program_id]"
+    )]
+    #[account(
+        2,
+        name = "trait_collection",
+        desc = "MPL Core collection account of the trait."
+    )]
+    SetTraitPauseV1(SetTraitPauseV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "authority",
+        desc = "Program upgrade authority — becomes the protocol authority and pays rent."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "protocol_config_pda",
+        desc = "Uninitialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        2,
+        name = "fee_wallet",
+        desc = "System-owned wallet receiving SOL protocol fees."
+    )]
+    #[account(
+        3,
+        name = "program_data",
+        desc = "ProgramData account of this program (BPF upgradeable loader)."
+    )]
+    #[account(4, name = "system_program", desc = "System program.")]
+    InitProtocolConfigV1(InitProtocolConfigV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Protocol authority — must match protocol_config.authority."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        2,
+        name = "fee_wallet",
+        desc = "System-owned wallet receiving SOL protocol fees."
+    )]
+    UpdateProtocolConfigV1(UpdateProtocolConfigV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder — pays for the VIP pass account."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "vip_pass_pda",
+        desc = "VIP pass pda with seeds [\"vip_pass_v1\", project_pda, wallet, program_id] — created on first grant"
+    )]
+    #[account(4, name = "wallet", desc = "Wallet receiving the VIP pass.")]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(7, name = "system_program", desc = "System Program")]
+    GrantVipPassV1(GrantVipPassV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder — receives the rent of the closed pass."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "vip_pass_pda",
+        desc = "VIP pass pda with seeds [\"vip_pass_v1\", project_pda, wallet, program_id] — closed"
+    )]
+    #[account(4, name = "wallet", desc = "Wallet losing the VIP pass.")]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(7, name = "system_program", desc = "System Program")]
+    RevokeVipPassV1,
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    SetMintPhasesV1(SetMintPhasesV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "payer",
+        desc = "User paying the mint price in 'token_mint' and solana."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "payer_ata",
+        desc = "Payer's ATA for 'token_mint' — source of payment."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "user_minted_pda",
+        desc = "Uninitialize user mint pda with seeds [\"user_minted_v1\", nft_collection, token_mint, payer, program_id]"
+    )]
+    #[account(4, name = "nft_authority", desc = "Controls: update all NFTs.")]
+    #[account(
+        5,
+        writable,
+        name = "nft_collection",
+        desc = "MPL Core Collection account that groups NFTs under this project."
+    )]
+    #[account(
+        6,
+        name = "token_mint",
+        desc = "Token mint — the token being escrowed (e.g. ZDLT)"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "revenue_wallet_0",
+        desc = "Revenue wallet #0 — corresponds to project.revenue_wallet(0)."
+    )]
+    #[account(
+        8,
+        writable,
+        name = "revenue_wallet_ata_0",
+        desc = "ATA for revenue wallet #0 — receives share from mint price."
+    )]
+    #[account(
+        9,
+        writable,
+        name = "revenue_wallet_1",
+        desc = "Revenue wallet #1 — corresponds to project.revenue_wallet(1)."
+    )]
+    #[account(
+        10,
+        writable,
+        name = "revenue_wallet_ata_1",
+        desc = "ATA for revenue wallet #1 — receives share from mint price."
+    )]
+    #[account(
+        11,
+        writable,
+        name = "revenue_wallet_2",
+        desc = "Revenue wallet #2 — corresponds to project.revenue_wallet(2)."
+    )]
+    #[account(
+        12,
+        writable,
+        name = "revenue_wallet_ata_2",
+        desc = "ATA for revenue wallet #2 — receives share from mint price."
+    )]
+    #[account(
+        13,
+        writable,
+        name = "revenue_wallet_3",
+        desc = "Revenue wallet #3 — corresponds to project.revenue_wallet(3)."
+    )]
+    #[account(
+        14,
+        writable,
+        name = "revenue_wallet_ata_3",
+        desc = "ATA for revenue wallet #3 — receives share from mint price."
+    )]
+    #[account(
+        15,
+        writable,
+        name = "revenue_wallet_4",
+        desc = "Revenue wallet #4 — corresponds to project.revenue_wallet(4)."
+    )]
+    #[account(
+        16,
+        writable,
+        name = "revenue_wallet_ata_4",
+        desc = "ATA for revenue wallet #4 — receives share from mint price."
+    )]
+    #[account(
+        17,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        18,
+        writable,
+        name = "protocol_wallet",
+        desc = "Protocol wallet — receives the configurable SOL protocol fee."
+    )]
+    #[account(
+        19,
+        writable,
+        name = "protocol_wallet_ata",
+        desc = "ATA of the protocol wallet — receives the protocol cut of the mint price."
+    )]
+    #[account(
+        20,
+        name = "token_program",
+        desc = "SPL Token Program (legacy) or Token-2022 Program."
+    )]
+    #[account(
+        21,
+        name = "associated_token_program",
+        desc = "Associated Token Program"
+    )]
+    #[account(
+        22,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    #[account(
+        23,
+        name = "mpl_core",
+        desc = "Metaplex Core program — must be the official MPL Core program."
+    )]
+    #[account(
+        24,
+        optional,
+        name = "remaining_accounts",
+        desc = "Per NFT: [nft_asset (signer, writable, uninitialized), vault_pda (writable), vault_ata (writable), mint_record_pda (writable)]"
+    )]
+    MintUserBatchV1(MintUserBatchV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Authority as payer — the project admin or a wallet holding ProjectRole::Minter."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "admin_ata",
+        desc = "Admin's ATA for 'token_mint' — funds the escrow of every airdropped NFT."
+    )]
+    #[account(
+        2,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        3,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — only read when admin is not project.admin."
+    )]
+    #[account(4, name = "nft_authority", desc = "Controls: update all NFTs.")]
+    #[account(
+        5,
+        writable,
+        name = "nft_collection",
+        desc = "MPL Core Collection account that groups NFTs under this project."
+    )]
+    #[account(
+        6,
+        name = "token_mint",
+        desc = "Token mint — the token being escrowed (e.g. ZDLT)"
+    )]
+    #[account(
+        7,
+        name = "token_program",
+        desc = "SPL Token Program (legacy) or Token-2022 Program."
+    )]
+    #[account(
+        8,
+        name = "associated_token_program",
+        desc = "Associated Token Program — for ATA derivation and creation."
+    )]
+    #[account(
+        9,
+        name = "protocol_config_pda",
+        desc = "Initialized protocol config pda with seeds [\"protocol_config_v1\", program_id]"
+    )]
+    #[account(
+        10,
+        writable,
+        name = "protocol_wallet",
+        desc = "Protocol wallet — receives the configurable SOL protocol fee."
+    )]
+    #[account(
+        11,
+        name = "system_program",
+        desc = "System Program — required for PDA creation and rent."
+    )]
+    #[account(
+        12,
+        name = "mpl_core",
+        desc = "Metaplex Core program — must be the official MPL Core program."
+    )]
+    #[account(
+        13,
+        optional,
+        name = "remaining_accounts",
+        desc = "Per NFT: [recipient, nft_asset (signer, writable, uninitialized), vault_pda (writable), vault_ata (writable), mint_record_pda (writable)]"
+    )]
+    MintAdminAirdropV1(MintAdminAirdropV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin, or a holder of both ConfigManager and Treasurer roles — pays for the payment mint account. Any signer under multisig."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "payment_mint_pda",
+        desc = "Payment mint pda with seeds [\"payment_mint_v1\", project_pda, payment_mint, program_id] — created on first set"
+    )]
+    #[account(
+        4,
+        name = "payment_mint",
+        desc = "Token mint accepted as payment — must differ from token_mint."
+    )]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(7, name = "system_program", desc = "System Program")]
+    #[account(
+        8,
+        writable,
+        optional,
+        name = "multisig_proposal_pda",
+        desc = "Approved multisig proposal — required only when project.multisig is set, closed on execution."
+    )]
+    #[account(
+        9,
+        writable,
+        optional,
+        name = "proposer",
+        desc = "Proposal creator receiving its rent — required only when project.multisig is set."
+    )]
+    SetPaymentMintV1(SetPaymentMintV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder — receives the rent of the closed account."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "payment_mint_pda",
+        desc = "Payment mint pda with seeds [\"payment_mint_v1\", project_pda, payment_mint, program_id] — closed"
+    )]
+    #[account(
+        4,
+        name = "payment_mint",
+        desc = "Token mint no longer accepted as payment."
+    )]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(7, name = "system_program", desc = "System Program")]
+    RemovePaymentMintV1,
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or Treasurer role holder — pays for the referrer account."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "referrer_pda",
+        desc = "Referrer pda with seeds [\"referrer_v1\", project_pda, wallet, program_id] — created on first registration"
+    )]
+    #[account(4, name = "wallet", desc = "Wallet receiving the referral kickback.")]
+    #[account(5, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(6, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(7, name = "system_program", desc = "System Program")]
+    RegisterReferrerV1(RegisterReferrerV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or Treasurer role holder — pays for the coupon account."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "coupon_pda",
+        desc = "Coupon pda with seeds [\"coupon_v1\", project_pda, code, program_id] — must be uninitialized"
+    )]
+    #[account(4, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(5, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(6, name = "system_program", desc = "System Program")]
+    CreateCouponV1(CreateCouponV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or Treasurer role holder — receives the rent of the closed coupon."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "coupon_pda",
+        desc = "Coupon pda with seeds [\"coupon_v1\", project_pda, code, program_id] — closed"
+    )]
+    #[account(4, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(5, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(6, name = "system_program", desc = "System Program")]
+    CloseCouponV1,
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    SetNftNamingV1(SetNftNamingV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(3, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(4, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    SetMetadataPolicyV1(SetMetadataPolicyV1InstructionData),
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "admin",
+        desc = "Project admin or ConfigManager role holder — pays for any asset resize."
+    )]
+    #[account(
+        1,
+        name = "project_pda",
+        desc = "Initialized project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(
+        2,
+        name = "project_role_pda",
+        desc = "Project role pda with seeds [\"project_role_v1\", project_pda, admin, program_id] — read only when admin is not project.admin"
+    )]
+    #[account(
+        3,
+        name = "nft_authority",
+        desc = "NFT authority pda with seeds [\"nft_authority_v1\", program_id]"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "nft_collection",
+        desc = "MPL Core collection account."
+    )]
+    #[account(5, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(6, name = "system_program", desc = "System Program")]
+    #[account(
+        7,
+        name = "mpl_core",
+        desc = "Metaplex Core program — must be the official MPL Core program."
+    )]
+    #[account(
+        8,
+        optional,
+        name = "remaining_accounts",
+        desc = "Per NFT: [nft_asset (writable), mint_record_pda]"
+    )]
+    RevealV1(RevealV1InstructionData),
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Any wallet — pays the rent of the grown project account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "project_pda",
+        desc = "Legacy-length project pda with seeds [\"project_v1\", nft_collection, token_mint, program_id]"
+    )]
+    #[account(2, name = "nft_collection", desc = "MPL Core collection account.")]
+    #[account(3, name = "token_mint", desc = "Token mint (SPL Token or Token-2022).")]
+    #[account(4, name = "system_program", desc = "System Program")]
+    MigrateProjectV1,
+
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "payer",
+        desc = "Any wallet — pays the rent of the grown trait account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "trait_pda",
+        desc = "Legacy-length trait pda with seeds [\"trait_item_v1\", trait_collection, program_id]"
+    )]
+    #[account(2, name = "trait_collection", desc = "MPL Core collection account.")]
+    #[account(3, name = "system_program", desc = "System Program")]
+    MigrateTraitV1,
 }
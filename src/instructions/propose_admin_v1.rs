@@ -0,0 +1,179 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{AdminProposalV1, AdminTarget, InitAdminProposalAccounts, InitAdminProposalArgs},
+    utils::{
+        AccountCheck, AdminProposalAccount, InitPdaAccounts, InitPdaArgs, Pda, ProcessInstruction,
+        ProjectAuthority, SignerAccount, SystemProgram, UninitializedAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct ProposeAdminV1Accounts<'a, 'info> {
+    /// Current admin of `target_pda` — must sign and pay for the proposal account.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// The `ProjectV1` or `TraitItemV1` PDA being handed over.
+    /// Must be owned by program, kind given by `target_kind`.
+    pub target_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["admin_proposal_v1", target_pda, program_id]` — stores the pending admin.
+    /// Must be writable. Created on first proposal, overwritten on re-proposal.
+    pub admin_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet proposed as the new admin. Does not need to sign here.
+    pub new_admin: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for ProposeAdminV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, target_pda, admin_proposal_pda, new_admin, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(admin_proposal_pda)?;
+
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            target_pda,
+            admin_proposal_pda,
+            new_admin,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ProposeAdminV1InstructionData {
+    pub target_kind: AdminTarget,
+}
+
+#[derive(Debug)]
+pub struct ProposeAdminV1<'a, 'info> {
+    pub accounts: ProposeAdminV1Accounts<'a, 'info>,
+    pub instruction_data: ProposeAdminV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        ProposeAdminV1InstructionData,
+        &'a Pubkey,
+    )> for ProposeAdminV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            ProposeAdminV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ProposeAdminV1Accounts::try_from(accounts)?;
+
+        instruction_data.target_kind.check(accounts.target_pda)?;
+
+        Pda::validate(
+            accounts.admin_proposal_pda,
+            &[AdminProposalV1::SEED, accounts.target_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> ProposeAdminV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let target_data = self.accounts.target_pda.try_borrow_data()?;
+        let current_admin = self.instruction_data.target_kind.admin(&target_data)?;
+
+        ProjectAuthority::check_admin(self.accounts.admin, &current_admin)?;
+
+        let new_admin = self.accounts.new_admin.key;
+
+        if *new_admin == Pubkey::default() || *new_admin == current_admin {
+            msg!("Invalid new admin {}", new_admin);
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    fn init_proposal(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[AdminProposalV1::SEED, self.accounts.target_pda.key.as_ref()];
+
+        AdminProposalV1::init(
+            InitAdminProposalAccounts {
+                pda: self.accounts.admin_proposal_pda,
+            },
+            InitAdminProposalArgs {
+                target: *self.accounts.target_pda.key,
+                proposer: *self.accounts.admin.key,
+                pending_admin: *self.accounts.new_admin.key,
+                target_kind: self.instruction_data.target_kind,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.admin_proposal_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: AdminProposalV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn replace_proposal(&self) -> ProgramResult {
+        AdminProposalAccount::check(self.accounts.admin_proposal_pda)?;
+
+        let mut proposal_data = self.accounts.admin_proposal_pda.try_borrow_mut_data()?;
+        let proposal = AdminProposalV1::load_mut(&mut proposal_data)?;
+        proposal.proposer = *self.accounts.admin.key;
+        proposal.pending_admin = *self.accounts.new_admin.key;
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for ProposeAdminV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+
+        if UninitializedAccount::check(self.accounts.admin_proposal_pda).is_ok() {
+            self.init_proposal()?;
+        } else {
+            self.replace_proposal()?;
+        }
+
+        msg!(
+            "Proposed {} as admin of {}",
+            self.accounts.new_admin.key,
+            self.accounts.target_pda.key
+        );
+
+        Ok(())
+    }
+}
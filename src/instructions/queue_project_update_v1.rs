@@ -0,0 +1,317 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{
+        InitProjectUpdateAccounts, InitProjectUpdateArgs, MultisigAction, Pricing, ProjectRole,
+        ProjectUpdateV1, ProjectV1, UpdateProjectArgs, VestingMode, VipGateArgs,
+    },
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, ProjectMultisig,
+        ProjectMultisigCheck, SignerAccount, SystemProgram, UninitializedAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct QueueProjectUpdateV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the pending update account.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    /// Changing revenue wallets or shares additionally requires `ProjectRole::Treasurer`.
+    /// Under multisig mode any signer may queue an approved proposal.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must have `update_delay_secs` set.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_update_v1", project_pda, program_id]` — stores the pending update.
+    /// Must be uninitialized and writable.
+    pub project_update_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for QueueProjectUpdateV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, project_update_pda, nft_collection, token_mint, system_program, multisig_proposal_pda, proposer] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(project_update_pda)?;
+
+        UninitializedAccount::check(project_update_pda)?;
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            project_update_pda,
+            nft_collection,
+            token_mint,
+            system_program,
+            multisig_proposal_pda,
+            proposer,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct QueueProjectUpdateV1InstructionData {
+    pub max_supply: u64,
+    pub released: u64,
+    pub max_mint_per_user: u64,
+    pub max_mint_per_vip_user: u64,
+    pub vesting_mode: VestingMode,
+    pub vesting_unlock_ts: i64,
+    pub mint_nft_fee_lamports: u64,
+    pub update_nft_fee_lamports: u64,
+    pub mint_price_total: u64,
+    pub escrow_amount: u64,
+    pub num_revenue_wallets: u8,
+    pub revenue_wallets: [Pubkey; 5],
+    pub revenue_shares: [u64; 5],
+    pub pricing: Pricing,
+    pub vip_supply: u64,
+    pub vip_rollover_ts: i64,
+    pub vip_merkle_root: [u8; 32],
+    pub vip_gate: VipGateArgs,
+}
+
+#[derive(Debug)]
+pub struct QueueProjectUpdateV1<'a, 'info> {
+    pub accounts: QueueProjectUpdateV1Accounts<'a, 'info>,
+    pub instruction_data: QueueProjectUpdateV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        QueueProjectUpdateV1InstructionData,
+        &'a Pubkey,
+    )> for QueueProjectUpdateV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            QueueProjectUpdateV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = QueueProjectUpdateV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.project_update_pda,
+            &[ProjectUpdateV1::SEED, accounts.project_pda.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> QueueProjectUpdateV1<'a, 'info> {
+    fn update_args(&self) -> UpdateProjectArgs {
+        UpdateProjectArgs {
+            max_supply: self.instruction_data.max_supply,
+            released: self.instruction_data.released,
+            max_mint_per_user: self.instruction_data.max_mint_per_user,
+            max_mint_per_vip_user: self.instruction_data.max_mint_per_vip_user,
+            vesting_mode: self.instruction_data.vesting_mode,
+            vesting_unlock_ts: self.instruction_data.vesting_unlock_ts,
+            mint_nft_fee_lamports: self.instruction_data.mint_nft_fee_lamports,
+            update_nft_fee_lamports: self.instruction_data.update_nft_fee_lamports,
+            mint_price_total: self.instruction_data.mint_price_total,
+            escrow_amount: self.instruction_data.escrow_amount,
+            num_revenue_wallets: self.instruction_data.num_revenue_wallets,
+            revenue_wallets: self.instruction_data.revenue_wallets,
+            revenue_shares: self.instruction_data.revenue_shares,
+            pricing: self.instruction_data.pricing,
+            vip_supply: self.instruction_data.vip_supply,
+            vip_rollover_ts: self.instruction_data.vip_rollover_ts,
+            vip_merkle_root: self.instruction_data.vip_merkle_root,
+            vip_gate: self.instruction_data.vip_gate,
+        }
+    }
+
+    fn check_authority(&self, project: &ProjectV1) -> Result<bool, ProgramError> {
+        if project.has_multisig() {
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::QueueProjectUpdate,
+                &self.instruction_data.try_to_vec()?,
+            )?;
+
+            return Ok(true);
+        }
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        let revenue_changed = project.num_revenue_wallets
+            != self.instruction_data.num_revenue_wallets
+            || project.revenue_wallets != self.instruction_data.revenue_wallets
+            || project.revenue_shares != self.instruction_data.revenue_shares;
+
+        if revenue_changed {
+            ProjectAuthority::check(
+                self.accounts.admin,
+                self.accounts.project_pda,
+                self.accounts.project_role_pda,
+                project,
+                ProjectRole::Treasurer,
+            )?;
+        }
+
+        Ok(false)
+    }
+
+    fn check_project_data(&self, project: &ProjectV1) -> ProgramResult {
+        if !project.has_timelock() {
+            msg!("Project has no timelock: apply changes with update_project_v1");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        ProjectV1::check_revenue_wallets(
+            self.instruction_data.mint_price_total,
+            self.instruction_data.escrow_amount,
+            self.instruction_data.num_revenue_wallets,
+            self.instruction_data.revenue_wallets,
+            self.instruction_data.revenue_shares,
+            project.protocol_fee_bps,
+        )?;
+
+        self.instruction_data
+            .pricing
+            .check(self.instruction_data.mint_price_total)?;
+
+        ProjectV1::check_vip_supply(
+            self.instruction_data.released,
+            self.instruction_data.vip_supply,
+        )?;
+
+        ProjectV1::check_vip_gate(&self.instruction_data.vip_gate)
+    }
+
+    fn init_project_update(&self, eta: i64) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            ProjectUpdateV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+        ];
+
+        ProjectUpdateV1::init(
+            InitProjectUpdateAccounts {
+                pda: self.accounts.project_update_pda,
+            },
+            InitProjectUpdateArgs {
+                project: *self.accounts.project_pda.key,
+                queued_by: *self.accounts.admin.key,
+                eta,
+                args: self.update_args(),
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.project_update_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: ProjectUpdateV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for QueueProjectUpdateV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        let via_multisig = self.check_authority(project)?;
+        self.check_project_data(project)?;
+
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(project.update_delay_secs)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.init_project_update(eta)?;
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        msg!(
+            "Queued project update for {}, executable at {}",
+            self.accounts.project_pda.key,
+            eta
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{
+        InitReferrerAccounts, InitReferrerArgs, ProjectRole, ProjectV1, ReferrerV1,
+        MAX_BASIS_POINTS,
+    },
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, Pda, ProcessInstruction,
+        ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, ReferrerAccount, SignerAccount,
+        SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct RegisterReferrerV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the referrer account.
+    /// Must match `project.admin` or hold `ProjectRole::Treasurer`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["referrer_v1", project_pda, wallet, program_id]` — stores the referrer.
+    /// Must be writable. Created on first registration, updated afterwards.
+    pub referrer_pda: &'a AccountInfo<'info>,
+
+    /// Wallet receiving the referral kickback.
+    pub wallet: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for RegisterReferrerV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, referrer_pda, wallet, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(referrer_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            referrer_pda,
+            wallet,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RegisterReferrerV1InstructionData {
+    /// Share of every revenue wallet payment diverted to the referrer, in basis points.
+    pub referral_bps: u16,
+}
+
+#[derive(Debug)]
+pub struct RegisterReferrerV1<'a, 'info> {
+    pub accounts: RegisterReferrerV1Accounts<'a, 'info>,
+    pub instruction_data: RegisterReferrerV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        RegisterReferrerV1InstructionData,
+        &'a Pubkey,
+    )> for RegisterReferrerV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            RegisterReferrerV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RegisterReferrerV1Accounts::try_from(accounts)?;
+
+        if instruction_data.referral_bps > MAX_BASIS_POINTS {
+            msg!(
+                "Referral bps {} exceeds {}",
+                instruction_data.referral_bps,
+                MAX_BASIS_POINTS
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.referrer_pda,
+            &[
+                ReferrerV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.wallet.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> RegisterReferrerV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Treasurer,
+        )
+    }
+
+    fn init_referrer_if_needed(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            ReferrerV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.accounts.wallet.key.as_ref(),
+        ];
+
+        ReferrerV1::init_if_needed(
+            InitReferrerAccounts {
+                pda: self.accounts.referrer_pda,
+            },
+            InitReferrerArgs {
+                project: *self.accounts.project_pda.key,
+                wallet: *self.accounts.wallet.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.referrer_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: ReferrerV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn set_referrer(&self) -> ProgramResult {
+        ReferrerAccount::check(self.accounts.referrer_pda)?;
+
+        let mut referrer_data = self.accounts.referrer_pda.try_borrow_mut_data()?;
+        let referrer = ReferrerV1::load_mut(&mut referrer_data)?;
+        referrer.referral_bps = self.instruction_data.referral_bps;
+
+        msg!(
+            "Registered referrer {} with {} bps",
+            self.accounts.wallet.key,
+            referrer.referral_bps
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for RegisterReferrerV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.init_referrer_if_needed()?;
+        self.set_referrer()
+    }
+}
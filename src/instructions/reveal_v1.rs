@@ -0,0 +1,288 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{MintRecordV1, NftAuthorityV1, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, MerkleProof, MintAccount, MintRecordAccount, MplCoreProgram, Pda,
+        ProcessInstruction, ProjectAccount, ProjectAuthority, ProjectAuthorityCheck, SignerAccount,
+        SystemProgram, UpdateMplCoreAssetAccounts, UpdateMplCoreAssetArgs, WritableAccount,
+    },
+};
+
+/// Accounts of a single revealed NFT, passed as remaining accounts.
+#[derive(Debug)]
+pub struct RevealV1NftAccounts<'a, 'info> {
+    /// NFT asset (MPL Core) — the asset whose URI is revealed.
+    /// Must be writable, owned by `mpl_core`.
+    pub nft_asset: &'a AccountInfo<'info>,
+
+    /// PDA: `["mint_record_v1", nft_asset, program_id]` — holds the NFT's mint index.
+    /// Must be initialized, owned by program.
+    pub mint_record_pda: &'a AccountInfo<'info>,
+}
+
+#[derive(Debug)]
+pub struct RevealV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pays for any asset resize.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["nft_authority_v1", program_id]`
+    /// Controls: update/burn all NFTs.
+    /// Only program can sign
+    pub nft_authority: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Must be writable.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — for potential realloc.
+    pub system_program: &'a AccountInfo<'info>,
+
+    /// Metaplex Core program — performs the update.
+    /// Must be the official MPL Core program.
+    pub mpl_core: &'a AccountInfo<'info>,
+
+    /// Remaining accounts: one `[nft_asset, mint_record_pda]` pair per NFT.
+    pub nfts: Vec<RevealV1NftAccounts<'a, 'info>>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for RevealV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, nft_authority, nft_collection, token_mint, system_program, mpl_core, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if remaining_accounts.is_empty() || remaining_accounts.len() % 2 != 0 {
+            msg!("Reveal expects [nft_asset, mint_record_pda] per NFT");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(nft_collection)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+        MplCoreProgram::check(mpl_core)?;
+
+        let mut nfts = Vec::with_capacity(remaining_accounts.len() / 2);
+        for chunk in remaining_accounts.chunks_exact(2) {
+            let [nft_asset, mint_record_pda] = chunk else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            WritableAccount::check(nft_asset)?;
+            MintRecordAccount::check(mint_record_pda)?;
+
+            nfts.push(RevealV1NftAccounts {
+                nft_asset,
+                mint_record_pda,
+            });
+        }
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            nft_authority,
+            nft_collection,
+            token_mint,
+            system_program,
+            mpl_core,
+            nfts,
+        })
+    }
+}
+
+/// Final URI of one NFT and its proof against `project.reveal_root`.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct RevealItem {
+    pub uri: String,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RevealV1InstructionData {
+    /// One entry per `[nft_asset, mint_record_pda]` pair, in the same order.
+    pub nfts: Vec<RevealItem>,
+}
+
+#[derive(Debug)]
+pub struct RevealV1<'a, 'info> {
+    pub accounts: RevealV1Accounts<'a, 'info>,
+    pub instruction_data: RevealV1InstructionData,
+    pub program_id: &'a Pubkey,
+    pub nft_authority_bump: u8,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        RevealV1InstructionData,
+        &'a Pubkey,
+    )> for RevealV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            RevealV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RevealV1Accounts::try_from(accounts)?;
+
+        if instruction_data.nfts.len() != accounts.nfts.len() {
+            msg!(
+                "Reveal has {} NFTs but {} account pairs",
+                instruction_data.nfts.len(),
+                accounts.nfts.len(),
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        let (_, nft_authority_bump) =
+            Pda::validate(accounts.nft_authority, &[NftAuthorityV1::SEED], program_id)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+            nft_authority_bump,
+        })
+    }
+}
+
+impl<'a, 'info> RevealV1<'a, 'info> {
+    fn check_reveal_eligibility(&self, project: &ProjectV1) -> ProgramResult {
+        if !project.has_reveal() {
+            msg!("Project has no committed reveal manifest");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !project.released_sold_out() {
+            msg!(
+                "Released supply not sold out yet. Released: {}. Minted: {}",
+                project.released,
+                project.released_minted(),
+            );
+            return Err(ProgramError::Custom(18));
+        }
+
+        Ok(())
+    }
+
+    fn check_manifest_entry(
+        &self,
+        project: &ProjectV1,
+        nft: &RevealV1NftAccounts<'a, 'info>,
+        item: &RevealItem,
+    ) -> ProgramResult {
+        Pda::validate(
+            nft.mint_record_pda,
+            &[MintRecordV1::SEED, nft.nft_asset.key.as_ref()],
+            self.program_id,
+        )?;
+
+        let record_data = nft.mint_record_pda.try_borrow_data()?;
+        let record = MintRecordV1::load(&record_data)?;
+
+        if record.project != *self.accounts.project_pda.key || record.nft != *nft.nft_asset.key {
+            msg!(
+                "Mint record {} does not belong to this project",
+                nft.mint_record_pda.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let leaf = MerkleProof::manifest_leaf(record.index, &item.uri);
+        if !MerkleProof::verify(&item.proof, &project.reveal_root, leaf) {
+            msg!(
+                "URI of NFT #{} does not match the reveal manifest",
+                record.index
+            );
+            return Err(ProgramError::Custom(19));
+        }
+
+        Ok(())
+    }
+
+    fn reveal_nft(&self, nft: &RevealV1NftAccounts<'a, 'info>, uri: &str) -> ProgramResult {
+        MplCoreProgram::update(
+            UpdateMplCoreAssetAccounts {
+                asset: nft.nft_asset,
+                collection: self.accounts.nft_collection,
+                payer: self.accounts.admin,
+                update_authority: self.accounts.nft_authority,
+                mpl_core: self.accounts.mpl_core,
+                system_program: self.accounts.system_program,
+            },
+            UpdateMplCoreAssetArgs {
+                name: None,
+                uri: Some(uri.to_string()),
+            },
+            &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for RevealV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        self.check_reveal_eligibility(project)?;
+
+        for (nft, item) in self.accounts.nfts.iter().zip(&self.instruction_data.nfts) {
+            self.check_manifest_entry(project, nft, item)?;
+            self.reveal_nft(nft, &item.uri)?;
+        }
+
+        msg!("Revealed {} NFTs", self.accounts.nfts.len());
+
+        Ok(())
+    }
+}
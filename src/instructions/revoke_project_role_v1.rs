@@ -0,0 +1,178 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{ProjectRole, ProjectRoleV1, ProjectV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectRoleAccount, SignerAccount, SystemProgram, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct RevokeProjectRoleV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin`. Receives the rent when the role account is closed.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, wallet, program_id]` — stores granted roles.
+    /// Must be initialized and writable. Closed once no roles remain.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// Wallet losing the roles.
+    pub wallet: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for RevokeProjectRoleV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, wallet, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(project_role_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        ProjectRoleAccount::check(project_role_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            wallet,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RevokeProjectRoleV1InstructionData {
+    /// Bitmask of `ProjectRole` values to remove.
+    pub roles: u8,
+}
+
+#[derive(Debug)]
+pub struct RevokeProjectRoleV1<'a, 'info> {
+    pub accounts: RevokeProjectRoleV1Accounts<'a, 'info>,
+    pub instruction_data: RevokeProjectRoleV1InstructionData,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        RevokeProjectRoleV1InstructionData,
+        &'a Pubkey,
+    )> for RevokeProjectRoleV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            RevokeProjectRoleV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RevokeProjectRoleV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.project_role_pda,
+            &[
+                ProjectRoleV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.wallet.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a, 'info> RevokeProjectRoleV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check_admin(self.accounts.admin, &project.admin)?;
+
+        if !ProjectRole::is_valid_mask(self.instruction_data.roles) {
+            msg!("Invalid role mask {:#010b}", self.instruction_data.roles);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    fn revoke_roles(&self) -> Result<bool, ProgramError> {
+        let mut role_data = self.accounts.project_role_pda.try_borrow_mut_data()?;
+        let project_role = ProjectRoleV1::load_mut(&mut role_data)?;
+        project_role.revoke(self.instruction_data.roles);
+
+        msg!(
+            "Revoked roles {:#010b} from {} (now {:#010b})",
+            self.instruction_data.roles,
+            self.accounts.wallet.key,
+            project_role.roles
+        );
+
+        Ok(project_role.has_no_roles())
+    }
+
+    fn close_project_role(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.project_role_pda, self.accounts.admin)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for RevokeProjectRoleV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+
+        if self.revoke_roles()? {
+            self.close_project_role()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{ProjectRole, ProjectV1, VipPassV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, SignerAccount, SystemProgram, VipPassAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct RevokeVipPassV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    /// Receives the rent of the closed pass.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["vip_pass_v1", project_pda, wallet, program_id]` — stores the VIP pass.
+    /// Must be initialized and writable. Closed by this instruction.
+    pub vip_pass_pda: &'a AccountInfo<'info>,
+
+    /// Wallet losing the VIP pass.
+    pub wallet: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for RevokeVipPassV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, vip_pass_pda, wallet, nft_collection, token_mint, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(vip_pass_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        VipPassAccount::check(vip_pass_pda)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            vip_pass_pda,
+            wallet,
+            nft_collection,
+            token_mint,
+            system_program,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct RevokeVipPassV1<'a, 'info> {
+    pub accounts: RevokeVipPassV1Accounts<'a, 'info>,
+}
+
+impl<'a, 'info> TryFrom<(&'a [AccountInfo<'info>], &'a Pubkey)> for RevokeVipPassV1<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, program_id): (&'a [AccountInfo<'info>], &'a Pubkey),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RevokeVipPassV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.vip_pass_pda,
+            &[
+                VipPassV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.wallet.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a, 'info> RevokeVipPassV1<'a, 'info> {
+    fn check_authority(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )
+    }
+
+    fn close_vip_pass(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(self.accounts.vip_pass_pda, self.accounts.admin)
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for RevokeVipPassV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        self.check_authority()?;
+        self.close_vip_pass()?;
+
+        msg!("Revoked VIP pass of {}", self.accounts.wallet.key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{MetadataPolicy, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, SignerAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetMetadataPolicyV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must be writable.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetMetadataPolicyV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, nft_collection, token_mint] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(project_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            nft_collection,
+            token_mint,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMetadataPolicyV1InstructionData {
+    /// Max NFT name length in bytes. `0` means no limit.
+    pub max_name_len: u8,
+    /// Max NFT URI length in bytes. `0` means no limit.
+    pub max_uri_len: u16,
+    /// Required NFT URI prefix (e.g. `https://arweave.net/`). Empty accepts any URI.
+    pub uri_prefix: String,
+    /// Every character allowed in NFT names (ASCII only). Empty accepts any character.
+    pub name_charset: String,
+}
+
+#[derive(Debug)]
+pub struct SetMetadataPolicyV1<'a, 'info> {
+    pub accounts: SetMetadataPolicyV1Accounts<'a, 'info>,
+    pub policy: MetadataPolicy,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetMetadataPolicyV1InstructionData,
+        &'a Pubkey,
+    )> for SetMetadataPolicyV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetMetadataPolicyV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetMetadataPolicyV1Accounts::try_from(accounts)?;
+
+        let policy = MetadataPolicy::new(
+            instruction_data.max_name_len,
+            instruction_data.max_uri_len,
+            &instruction_data.uri_prefix,
+            &instruction_data.name_charset,
+        )?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self { accounts, policy })
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetMetadataPolicyV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        project.metadata_policy = self.policy;
+
+        msg!(
+            "Project metadata policy set: max name {}, max URI {}, URI prefix {} bytes, name charset {}",
+            project.metadata_policy.max_name_len,
+            project.metadata_policy.max_uri_len,
+            project.metadata_policy.uri_prefix_len,
+            project.metadata_policy.has_name_charset()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{MintPhaseArgs, ProjectRole, ProjectV1, MAX_MINT_PHASES},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, SignerAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetMintPhasesV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must be writable.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetMintPhasesV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, nft_collection, token_mint] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(project_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            nft_collection,
+            token_mint,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMintPhasesV1InstructionData {
+    /// New mint schedule, ordered by `start_ts`. Replaces the current one; empty removes it.
+    /// Phases that already started must be resent unchanged.
+    pub phases: Vec<MintPhaseArgs>,
+}
+
+#[derive(Debug)]
+pub struct SetMintPhasesV1<'a, 'info> {
+    pub accounts: SetMintPhasesV1Accounts<'a, 'info>,
+    pub instruction_data: SetMintPhasesV1InstructionData,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetMintPhasesV1InstructionData,
+        &'a Pubkey,
+    )> for SetMintPhasesV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetMintPhasesV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetMintPhasesV1Accounts::try_from(accounts)?;
+
+        if instruction_data.phases.len() > MAX_MINT_PHASES {
+            msg!("Too many mint phases. Max: {}", MAX_MINT_PHASES);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetMintPhasesV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        project.set_mint_phases(&self.instruction_data.phases, Clock::get()?.unix_timestamp)?;
+
+        msg!(
+            "Project mint schedule set to {} phases",
+            project.num_mint_phases
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{NftNaming, ProjectRole, ProjectV1},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, SignerAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetNftNamingV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must be writable.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetNftNamingV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, nft_collection, token_mint] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(project_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            nft_collection,
+            token_mint,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetNftNamingV1InstructionData {
+    /// NFT name prefix — minted NFTs are named `"<name_prefix> #<index>"`.
+    pub name_prefix: String,
+    /// Metadata base URI — minted NFTs point to `<base_uri>/<index>.json`.
+    /// Both empty turns generated naming off.
+    pub base_uri: String,
+}
+
+#[derive(Debug)]
+pub struct SetNftNamingV1<'a, 'info> {
+    pub accounts: SetNftNamingV1Accounts<'a, 'info>,
+    pub naming: NftNaming,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetNftNamingV1InstructionData,
+        &'a Pubkey,
+    )> for SetNftNamingV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetNftNamingV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetNftNamingV1Accounts::try_from(accounts)?;
+
+        let naming = NftNaming::new(&instruction_data.name_prefix, &instruction_data.base_uri)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self { accounts, naming })
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetNftNamingV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        project.naming = self.naming;
+
+        if project.naming.is_enabled() {
+            let index = project.next_nft_index()?;
+            msg!(
+                "Project NFT naming set. Next NFT: \"{}\" at {}",
+                project.naming.name(index),
+                project.naming.uri(index)
+            );
+        } else {
+            msg!("Project NFT naming disabled");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    states::{
+        InitPaymentMintAccounts, InitPaymentMintArgs, MultisigAction, PaymentMintV1, ProjectRole,
+        ProjectV1,
+    },
+    utils::{
+        AccountCheck, InitPdaAccounts, InitPdaArgs, MintAccount, PaymentMintAccount, Pda,
+        ProcessInstruction, ProjectAccount, ProjectAuthority, ProjectAuthorityCheck,
+        ProjectMultisig, ProjectMultisigCheck, SignerAccount, SystemProgram, TokenProgram,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetPaymentMintV1Accounts<'a, 'info> {
+    /// The project authority — must sign and pay for the payment mint account.
+    /// Must match `project.admin` or hold both `ProjectRole::ConfigManager` and `ProjectRole::Treasurer`.
+    /// Under multisig mode any signer may execute an approved proposal.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
+    /// Must be readable, owned by program.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["payment_mint_v1", project_pda, payment_mint, program_id]` — stores the price table entry.
+    /// Must be writable. Created on first set, updated afterwards.
+    pub payment_mint_pda: &'a AccountInfo<'info>,
+
+    /// Token mint accepted as payment.
+    /// Must be valid mint, different from `token_mint`.
+    pub payment_mint: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    /// Determines the project scope for mint rules, royalties, and limits.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// System program — required for PDA creation and rent.
+    pub system_program: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetPaymentMintV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, payment_mint_pda, payment_mint, nft_collection, token_mint, system_program, multisig_proposal_pda, proposer] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(admin)?;
+        WritableAccount::check(payment_mint_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(payment_mint)?;
+        MintAccount::check(token_mint)?;
+        SystemProgram::check(system_program)?;
+
+        if payment_mint.key == token_mint.key {
+            msg!("Payment mint must differ from the project mint");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            payment_mint_pda,
+            payment_mint,
+            nft_collection,
+            token_mint,
+            system_program,
+            multisig_proposal_pda,
+            proposer,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetPaymentMintV1InstructionData {
+    /// Full mint price in raw units of `payment_mint`.
+    pub mint_price_total: u64,
+    /// Portion of the price escrowed in the NFT vault, in raw units of `payment_mint`.
+    pub escrow_amount: u64,
+    /// Amount each project revenue wallet receives, by index.
+    pub revenue_shares: [u64; 5],
+}
+
+#[derive(Debug)]
+pub struct SetPaymentMintV1<'a, 'info> {
+    pub accounts: SetPaymentMintV1Accounts<'a, 'info>,
+    pub instruction_data: SetPaymentMintV1InstructionData,
+    pub program_id: &'a Pubkey,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetPaymentMintV1InstructionData,
+        &'a Pubkey,
+    )> for SetPaymentMintV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetPaymentMintV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetPaymentMintV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Pda::validate(
+            accounts.payment_mint_pda,
+            &[
+                PaymentMintV1::SEED,
+                accounts.project_pda.key.as_ref(),
+                accounts.payment_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+            program_id,
+        })
+    }
+}
+
+impl<'a, 'info> SetPaymentMintV1<'a, 'info> {
+    fn check_authority(&self, project: &ProjectV1) -> Result<bool, ProgramError> {
+        if project.has_multisig() {
+            // Bind the approval to the payment mint, which is not part of the instruction data.
+            let mut payload = self.accounts.payment_mint.key.to_bytes().to_vec();
+            payload.extend(self.instruction_data.try_to_vec()?);
+
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::SetPaymentMint,
+                &payload,
+            )?;
+
+            return Ok(true);
+        }
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        // Every entry carries its own revenue shares.
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::Treasurer,
+        )?;
+
+        Ok(false)
+    }
+
+    fn init_payment_mint_if_needed(&self) -> ProgramResult {
+        let seeds: &[&[u8]] = &[
+            PaymentMintV1::SEED,
+            self.accounts.project_pda.key.as_ref(),
+            self.accounts.payment_mint.key.as_ref(),
+        ];
+
+        PaymentMintV1::init_if_needed(
+            InitPaymentMintAccounts {
+                pda: self.accounts.payment_mint_pda,
+            },
+            InitPaymentMintArgs {
+                project: *self.accounts.project_pda.key,
+                mint: *self.accounts.payment_mint.key,
+            },
+            InitPdaAccounts {
+                payer: self.accounts.admin,
+                pda: self.accounts.payment_mint_pda,
+                system_program: self.accounts.system_program,
+            },
+            InitPdaArgs {
+                seeds,
+                space: PaymentMintV1::LEN,
+                program_id: self.program_id,
+            },
+        )
+    }
+
+    fn set_payment_mint(&self, project: &ProjectV1) -> ProgramResult {
+        PaymentMintAccount::check(self.accounts.payment_mint_pda)?;
+
+        let mint_decimals = TokenProgram::get_decimal(self.accounts.payment_mint)?;
+        let active_from_ts = Clock::get()?
+            .unix_timestamp
+            .saturating_add(project.update_delay_secs);
+
+        let mut payment_mint_data = self.accounts.payment_mint_pda.try_borrow_mut_data()?;
+        let payment_mint = PaymentMintV1::load_mut(&mut payment_mint_data)?;
+        payment_mint.mint_decimals = mint_decimals;
+        payment_mint.mint_price_total = self.instruction_data.mint_price_total;
+        payment_mint.escrow_amount = self.instruction_data.escrow_amount;
+        payment_mint.revenue_shares = self.instruction_data.revenue_shares;
+        payment_mint.active_from_ts = active_from_ts;
+
+        msg!(
+            "Payment mint {} set to price {} (active from {})",
+            payment_mint.mint,
+            payment_mint.mint_price_total,
+            payment_mint.active_from_ts
+        );
+
+        Ok(())
+    }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetPaymentMintV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        let via_multisig = self.check_authority(project)?;
+
+        ProjectV1::check_revenue_wallets(
+            self.instruction_data.mint_price_total,
+            self.instruction_data.escrow_amount,
+            project.num_revenue_wallets,
+            project.revenue_wallets,
+            self.instruction_data.revenue_shares,
+            project.protocol_fee_bps,
+        )?;
+
+        self.init_payment_mint_if_needed()?;
+        self.set_payment_mint(project)?;
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        Ok(())
+    }
+}
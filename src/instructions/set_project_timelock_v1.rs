@@ -0,0 +1,203 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{MultisigAction, ProjectRole, ProjectV1, MAX_UPDATE_DELAY_SECS},
+    utils::{
+        AccountCheck, MintAccount, Pda, ProcessInstruction, ProjectAccount, ProjectAuthority,
+        ProjectAuthorityCheck, ProjectMultisig, ProjectMultisigCheck, SignerAccount, SystemProgram,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetProjectTimelockV1Accounts<'a, 'info> {
+    /// The project authority — must sign.
+    /// Must match `project.admin` or hold `ProjectRole::ConfigManager`.
+    /// Under multisig mode any signer may execute an approved proposal.
+    pub admin: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, token_mint, program_id]`.
+    /// Must be writable.
+    pub project_pda: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this project.
+    pub nft_collection: &'a AccountInfo<'info>,
+
+    /// Token mint (fungible token used for minting/refunding e.g. ZDLT).
+    /// Must be valid mint (82 or 90+ bytes), owned by SPL Token or Token-2022.
+    pub token_mint: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetProjectTimelockV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [admin, project_pda, project_role_pda, nft_collection, token_mint, multisig_proposal_pda, proposer] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+
+        WritableAccount::check(project_pda)?;
+
+        ProjectAccount::check(project_pda)?;
+        MintAccount::check(token_mint)?;
+
+        Ok(Self {
+            admin,
+            project_pda,
+            project_role_pda,
+            nft_collection,
+            token_mint,
+            multisig_proposal_pda,
+            proposer,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetProjectTimelockV1InstructionData {
+    /// New delay in seconds. Must be greater than the current one.
+    pub update_delay_secs: i64,
+}
+
+#[derive(Debug)]
+pub struct SetProjectTimelockV1<'a, 'info> {
+    pub accounts: SetProjectTimelockV1Accounts<'a, 'info>,
+    pub instruction_data: SetProjectTimelockV1InstructionData,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetProjectTimelockV1InstructionData,
+        &'a Pubkey,
+    )> for SetProjectTimelockV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetProjectTimelockV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetProjectTimelockV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a, 'info> SetProjectTimelockV1<'a, 'info> {
+    fn check_authority(&self, project: &ProjectV1) -> Result<bool, ProgramError> {
+        if project.has_multisig() {
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::SetProjectTimelock,
+                &self.instruction_data.try_to_vec()?,
+            )?;
+
+            return Ok(true);
+        }
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        Ok(false)
+    }
+
+    fn check_delay(&self, project: &ProjectV1) -> ProgramResult {
+        let delay = self.instruction_data.update_delay_secs;
+
+        if delay <= project.update_delay_secs {
+            msg!(
+                "Timelock can only increase: current {}s, requested {}s",
+                project.update_delay_secs,
+                delay
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if delay > MAX_UPDATE_DELAY_SECS {
+            msg!(
+                "Timelock {}s exceeds maximum {}s",
+                delay,
+                MAX_UPDATE_DELAY_SECS
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetProjectTimelockV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
+        let project = ProjectV1::load_mut(&mut project_data)?;
+
+        let via_multisig = self.check_authority(project)?;
+        self.check_delay(project)?;
+
+        let old_delay = project.update_delay_secs;
+        project.update_delay_secs = self.instruction_data.update_delay_secs;
+
+        msg!(
+            "Project timelock raised from {}s to {}s",
+            old_delay,
+            project.update_delay_secs
+        );
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{PauseFlag, TraitItemV1},
+    utils::{
+        AccountCheck, Pda, ProcessInstruction, SignerAccount, TraitItemAccount, WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct SetTraitPauseV1Accounts<'a, 'info> {
+    /// Trait authority — must sign and match `trait_item.authority`.
+    pub authority: &'a AccountInfo<'info>,
+
+    /// PDA: `["trait_item_v1", trait_collection, program_id]` — stores `TraitItem` struct.
+    /// Must be writable, owned by this program.
+    pub trait_pda: &'a AccountInfo<'info>,
+
+    /// MPL Core Collection account that groups NFTs under this trait.
+    pub trait_collection: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for SetTraitPauseV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [authority, trait_pda, trait_collection] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+
+        WritableAccount::check(trait_pda)?;
+
+        TraitItemAccount::check(trait_pda)?;
+
+        Ok(Self {
+            authority,
+            trait_pda,
+            trait_collection,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetTraitPauseV1InstructionData {
+    /// Bitmask of `PauseFlag` values to halt. Replaces the current mask; `0` resumes everything.
+    pub paused: u8,
+}
+
+#[derive(Debug)]
+pub struct SetTraitPauseV1<'a, 'info> {
+    pub accounts: SetTraitPauseV1Accounts<'a, 'info>,
+    pub instruction_data: SetTraitPauseV1InstructionData,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        SetTraitPauseV1InstructionData,
+        &'a Pubkey,
+    )> for SetTraitPauseV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            SetTraitPauseV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetTraitPauseV1Accounts::try_from(accounts)?;
+
+        if !PauseFlag::is_valid_mask(instruction_data.paused) {
+            msg!("Invalid pause mask {:#010b}", instruction_data.paused);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Pda::validate(
+            accounts.trait_pda,
+            &[TraitItemV1::SEED, accounts.trait_collection.key.as_ref()],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for SetTraitPauseV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut trait_data = self.accounts.trait_pda.try_borrow_mut_data()?;
+        let trait_item = TraitItemV1::load_mut(trait_data.as_mut())?;
+
+        if trait_item.authority != *self.accounts.authority.key {
+            msg!("Unauthorized authority for trait pause");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        trait_item.paused = self.instruction_data.paused;
+
+        msg!("Trait pause mask set to {:#010b}", trait_item.paused);
+
+        Ok(())
+    }
+}
@@ -5,11 +5,12 @@ use solana_program::{
 };
 
 use crate::{
-    states::VaultV1,
+    states::{PauseFlag, ProjectV1, VaultV1},
     utils::{
         AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
-        InitAssociatedTokenProgramAccounts, MintAccount, Pda, ProcessInstruction, SignerAccount,
-        SystemProgram, TokenProgram, TokenTransferAccounts, TokenTransferArgs, WritableAccount,
+        InitAssociatedTokenProgramAccounts, MintAccount, Pda, ProcessInstruction, ProjectAccount,
+        SignerAccount, SystemProgram, TokenProgram, TokenTransferAccounts, TokenTransferArgs,
+        WritableAccount,
     },
 };
 
@@ -58,13 +59,17 @@ pub struct TransferToVaultV1Accounts<'a, 'info> {
 
     /// System program — for account allocation.
     pub system_program: &'a AccountInfo<'info>,
+
+    /// PDA: `["project_v1", nft_collection, project_token_mint, program_id]`.
+    /// Read for `project.paused`.
+    pub project_pda: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for TransferToVaultV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, payer_ata, vault_pda, new_vault_ata, nft_collection, nft_asset, project_token_mint, new_token_mint, token_program, associated_token_program, system_program] =
+        let [payer, payer_ata, vault_pda, new_vault_ata, nft_collection, nft_asset, project_token_mint, new_token_mint, token_program, associated_token_program, system_program, project_pda] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -78,6 +83,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for TransferToVaultV1Accounts<
         MintAccount::check(project_token_mint)?;
         MintAccount::check(new_token_mint)?;
         SystemProgram::check(system_program)?;
+        ProjectAccount::check(project_pda)?;
 
         AssociatedTokenAccount::check(payer_ata, payer.key, new_token_mint.key, token_program.key)?;
 
@@ -93,6 +99,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for TransferToVaultV1Accounts<
             token_program,
             associated_token_program,
             system_program,
+            project_pda,
         })
     }
 }
@@ -126,6 +133,16 @@ impl<'a, 'info>
     ) -> Result<Self, Self::Error> {
         let accounts = TransferToVaultV1Accounts::try_from(accounts)?;
 
+        Pda::validate(
+            accounts.project_pda,
+            &[
+                ProjectV1::SEED,
+                accounts.nft_collection.key.as_ref(),
+                accounts.project_token_mint.key.as_ref(),
+            ],
+            program_id,
+        )?;
+
         Pda::validate(
             accounts.vault_pda,
             &[
@@ -178,6 +195,12 @@ impl<'a, 'info> TransferToVaultV1<'a, 'info> {
 
 impl<'a, 'info> ProcessInstruction for TransferToVaultV1<'a, 'info> {
     fn process(self) -> ProgramResult {
+        {
+            let project_data = self.accounts.project_pda.try_borrow_data()?;
+            let project = ProjectV1::load(&project_data)?;
+            PauseFlag::TransferToVault.check(project.paused)?;
+        }
+
         if self.instruction_data.amount == 0 {
             return Ok(());
         }
@@ -5,11 +5,11 @@ use solana_program::{
 };
 
 use crate::{
-    states::{NftAuthorityV1, ProjectV1},
+    states::{NftAuthorityV1, PauseFlag, ProjectV1},
     utils::{
         AccountCheck, MintAccount, MplCoreProgram, Pda, ProcessInstruction, ProjectAccount,
-        SignerAccount, SystemProgram, UpdateMplCoreAssetAccounts, UpdateMplCoreAssetArgs,
-        WritableAccount,
+        ProtocolFeeWallet, ProtocolFeeWalletCheck, SignerAccount, SystemProgram,
+        UpdateMplCoreAssetAccounts, UpdateMplCoreAssetArgs, WritableAccount,
     },
 };
 
@@ -40,8 +40,13 @@ pub struct UpdateNftV1Accounts<'a, 'info> {
     /// Must be mutable, owned by `mpl_core`.
     pub nft_asset: &'a AccountInfo<'info>,
 
+    /// PDA: `["protocol_config_v1", program_id]` — stores the canonical protocol fee wallet.
+    /// Must be initialized, owned by this program.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
     /// Protocol wallet — receives the configurable SOL protocol fee.
     /// Must writable, not zero address, owned by system_program.
+    /// Must match `protocol_config.fee_wallet`.
     pub protocol_wallet: &'a AccountInfo<'info>,
 
     /// System program — for potential realloc.
@@ -56,7 +61,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateNftV1Accounts<'a, 'i
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [payer, project_pda, token_mint, nft_authority, nft_collection, nft_asset, protocol_wallet, system_program, mpl_core] =
+        let [payer, project_pda, token_mint, nft_authority, nft_collection, nft_asset, protocol_config_pda, protocol_wallet, system_program, mpl_core] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -66,7 +71,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateNftV1Accounts<'a, 'i
 
         WritableAccount::check(project_pda)?;
         WritableAccount::check(nft_asset)?;
-        WritableAccount::check(protocol_wallet)?;
+        ProtocolFeeWallet::check(protocol_config_pda, protocol_wallet)?;
 
         ProjectAccount::check(project_pda)?;
         MintAccount::check(token_mint)?;
@@ -80,6 +85,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateNftV1Accounts<'a, 'i
             nft_authority,
             nft_collection,
             nft_asset,
+            protocol_config_pda,
             protocol_wallet,
             system_program,
             mpl_core,
@@ -90,6 +96,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateNftV1Accounts<'a, 'i
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct UpdateNftV1InstructionData {
     pub nft_name: String,
+    /// Must be empty when the project has a reveal (`project.reveal_root`); the URI is then kept.
     pub nft_uri: String,
 }
 
@@ -164,7 +171,10 @@ impl<'a, 'info> UpdateNftV1<'a, 'info> {
         )
     }
 
-    fn update_nft(self) -> ProgramResult {
+    /// `keep_uri` leaves the asset URI untouched (projects with a reveal).
+    fn update_nft(self, keep_uri: bool) -> ProgramResult {
+        let uri = (!keep_uri).then_some(self.instruction_data.nft_uri);
+
         MplCoreProgram::update(
             UpdateMplCoreAssetAccounts {
                 asset: self.accounts.nft_asset,
@@ -175,8 +185,8 @@ impl<'a, 'info> UpdateNftV1<'a, 'info> {
                 system_program: self.accounts.system_program,
             },
             UpdateMplCoreAssetArgs {
-                name: self.instruction_data.nft_name,
-                uri: self.instruction_data.nft_uri,
+                name: Some(self.instruction_data.nft_name),
+                uri,
             },
             &[&[NftAuthorityV1::SEED, &[self.nft_authority_bump]]],
         )
@@ -188,8 +198,14 @@ impl<'a, 'info> ProcessInstruction for UpdateNftV1<'a, 'info> {
         let project_data = self.accounts.project_pda.data.borrow_mut();
         let project = ProjectV1::load(&project_data)?;
 
+        PauseFlag::UpdateNft.check(project.paused)?;
+        project.check_nft_update(
+            &self.instruction_data.nft_name,
+            &self.instruction_data.nft_uri,
+        )?;
+
         self.check_ownership()?;
         self.pay_protocol_fee(project)?;
-        self.update_nft()
+        self.update_nft(project.has_reveal())
     }
 }
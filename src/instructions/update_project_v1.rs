@@ -5,10 +5,14 @@ use solana_program::{
 };
 
 use crate::{
-    states::{NftAuthorityV1, ProjectV1, UpdateProjectArgs, VestingMode},
+    states::{
+        MultisigAction, NftAuthorityV1, Pricing, ProjectRole, ProjectV1, UpdateProjectArgs,
+        VestingMode, VipGateArgs,
+    },
     utils::{
-        AccountCheck, MintAccount, MplCoreProgram, Pda, ProcessInstruction, SignerAccount,
-        SystemProgram, UpdateMplCoreCollectionAccounts, UpdateMplCoreCollectionArgs,
+        AccountCheck, MintAccount, MplCoreProgram, Pda, ProcessInstruction, ProjectAccount,
+        ProjectAuthority, ProjectAuthorityCheck, ProjectMultisig, ProjectMultisigCheck,
+        SignerAccount, SystemProgram, UpdateMplCoreCollectionAccounts, UpdateMplCoreCollectionArgs,
         WritableAccount,
     },
 };
@@ -16,13 +20,20 @@ use crate::{
 #[derive(Debug)]
 pub struct UpdateProjectV1Accounts<'a, 'info> {
     /// Authority that will control project updates (e.g. admin wallet).
-    /// Must be a signer.
+    /// Under multisig mode any signer may execute an approved proposal.
+    /// Must be a signer, and the project admin or a holder of `ProjectRole::ConfigManager`.
+    /// Changing revenue wallets or shares additionally requires `ProjectRole::Treasurer`.
+    /// Once `project.update_delay_secs` is set only collection metadata may change here.
     pub admin: &'a AccountInfo<'info>,
 
     /// PDA: `["project_v1", nft_collection, token_mint, program_id]` — stores global project config.
     /// Must be uninitialized, writable, owned by this program.
     pub project_pda: &'a AccountInfo<'info>,
 
+    /// PDA: `["project_role_v1", project_pda, admin, program_id]` — roles held by `admin`.
+    /// Only read when `admin` is not `project.admin`.
+    pub project_role_pda: &'a AccountInfo<'info>,
+
     /// PDA: `[program_id, "nft_authority"]`
     /// Controls: update/burn all NFTs.
     /// Only program can sign
@@ -43,13 +54,21 @@ pub struct UpdateProjectV1Accounts<'a, 'info> {
     /// Metaplex Core program — for NFT minting.
     /// Must be the official MPL Core program.
     pub mpl_core: &'a AccountInfo<'info>,
+
+    /// PDA: `["multisig_proposal_v1", multisig_pda, index, program_id]` — approved proposal.
+    /// Only read when `project.multisig` is set; closed after execution.
+    pub multisig_proposal_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that created `multisig_proposal_pda` — receives its rent.
+    /// Only read when `project.multisig` is set.
+    pub proposer: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateProjectV1Accounts<'a, 'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
-        let [admin, project_pda, nft_authority, nft_collection, token_mint, system_program, mpl_core] =
+        let [admin, project_pda, project_role_pda, nft_authority, nft_collection, token_mint, system_program, mpl_core, multisig_proposal_pda, proposer] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -60,6 +79,7 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateProjectV1Accounts<'a
         WritableAccount::check(project_pda)?;
         WritableAccount::check(nft_collection)?;
 
+        ProjectAccount::check(project_pda)?;
         MintAccount::check(token_mint)?;
         SystemProgram::check(system_program)?;
         MplCoreProgram::check(mpl_core)?;
@@ -67,11 +87,14 @@ impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateProjectV1Accounts<'a
         Ok(Self {
             admin,
             project_pda,
+            project_role_pda,
             nft_authority,
             nft_collection,
             token_mint,
             system_program,
             mpl_core,
+            multisig_proposal_pda,
+            proposer,
         })
     }
 }
@@ -91,9 +114,14 @@ pub struct UpdateProjectV1InstructionData {
     pub num_revenue_wallets: u8,
     pub revenue_wallets: [Pubkey; 5],
     pub revenue_shares: [u64; 5],
+    pub pricing: Pricing,
+    pub vip_supply: u64,
+    pub vip_rollover_ts: i64,
     pub num_royalty_recipients: u8,
     pub royalty_recipients: [Pubkey; 5],
     pub royalty_shares_bps: [u16; 5],
+    pub vip_merkle_root: [u8; 32],
+    pub vip_gate: VipGateArgs,
     pub collection_name: String,
     pub collection_uri: String,
 }
@@ -144,19 +172,111 @@ impl<'a, 'info>
 }
 
 impl<'a, 'info> UpdateProjectV1<'a, 'info> {
+    fn check_authority(&self) -> Result<bool, ProgramError> {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        if project.has_multisig() {
+            ProjectMultisig::check(
+                self.accounts.multisig_proposal_pda,
+                self.accounts.proposer,
+                project,
+                MultisigAction::UpdateProject,
+                &self.instruction_data.try_to_vec()?,
+            )?;
+
+            return Ok(true);
+        }
+
+        ProjectAuthority::check(
+            self.accounts.admin,
+            self.accounts.project_pda,
+            self.accounts.project_role_pda,
+            project,
+            ProjectRole::ConfigManager,
+        )?;
+
+        let revenue_changed = project.num_revenue_wallets
+            != self.instruction_data.num_revenue_wallets
+            || project.revenue_wallets != self.instruction_data.revenue_wallets
+            || project.revenue_shares != self.instruction_data.revenue_shares;
+
+        if revenue_changed {
+            ProjectAuthority::check(
+                self.accounts.admin,
+                self.accounts.project_pda,
+                self.accounts.project_role_pda,
+                project,
+                ProjectRole::Treasurer,
+            )?;
+        }
+
+        Ok(false)
+    }
+
+    fn update_args(&self) -> UpdateProjectArgs {
+        UpdateProjectArgs {
+            max_supply: self.instruction_data.max_supply,
+            released: self.instruction_data.released,
+            max_mint_per_user: self.instruction_data.max_mint_per_user,
+            max_mint_per_vip_user: self.instruction_data.max_mint_per_vip_user,
+            vesting_mode: self.instruction_data.vesting_mode,
+            vesting_unlock_ts: self.instruction_data.vesting_unlock_ts,
+            mint_nft_fee_lamports: self.instruction_data.mint_nft_fee_lamports,
+            update_nft_fee_lamports: self.instruction_data.update_nft_fee_lamports,
+            mint_price_total: self.instruction_data.mint_price_total,
+            escrow_amount: self.instruction_data.escrow_amount,
+            num_revenue_wallets: self.instruction_data.num_revenue_wallets,
+            revenue_wallets: self.instruction_data.revenue_wallets,
+            revenue_shares: self.instruction_data.revenue_shares,
+            pricing: self.instruction_data.pricing,
+            vip_supply: self.instruction_data.vip_supply,
+            vip_rollover_ts: self.instruction_data.vip_rollover_ts,
+            vip_merkle_root: self.instruction_data.vip_merkle_root,
+            vip_gate: self.instruction_data.vip_gate,
+        }
+    }
+
+    fn check_timelock(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
+        if project.has_timelock() && project.update_args() != self.update_args() {
+            msg!(
+                "Project config is timelocked ({}s): queue the change with queue_project_update_v1",
+                project.update_delay_secs
+            );
+            return Err(ProgramError::Custom(7));
+        }
+
+        Ok(())
+    }
+
     fn check_project_data(&self) -> ProgramResult {
+        let project_data = self.accounts.project_pda.try_borrow_data()?;
+        let project = ProjectV1::load(&project_data)?;
+
         ProjectV1::check_revenue_wallets(
             self.instruction_data.mint_price_total,
             self.instruction_data.escrow_amount,
             self.instruction_data.num_revenue_wallets,
             self.instruction_data.revenue_wallets,
             self.instruction_data.revenue_shares,
+            project.protocol_fee_bps,
         )?;
+        self.instruction_data
+            .pricing
+            .check(self.instruction_data.mint_price_total)?;
         ProjectV1::check_nft_royalties(
             self.instruction_data.num_royalty_recipients,
             self.instruction_data.royalty_recipients,
             self.instruction_data.royalty_shares_bps,
-        )
+        )?;
+        ProjectV1::check_vip_supply(
+            self.instruction_data.released,
+            self.instruction_data.vip_supply,
+        )?;
+        ProjectV1::check_vip_gate(&self.instruction_data.vip_gate)
     }
 
     fn update_collection(&self) -> ProgramResult {
@@ -183,35 +303,31 @@ impl<'a, 'info> UpdateProjectV1<'a, 'info> {
         let mut project_data = self.accounts.project_pda.try_borrow_mut_data()?;
         let project = ProjectV1::load_mut(project_data.as_mut())?;
 
-        if project.admin != *self.accounts.admin.key {
-            msg!("Unauthorized authority for project update");
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        project.update(UpdateProjectArgs {
-            max_supply: self.instruction_data.max_supply,
-            released: self.instruction_data.released,
-            max_mint_per_user: self.instruction_data.max_mint_per_user,
-            max_mint_per_vip_user: self.instruction_data.max_mint_per_vip_user,
-            vesting_mode: self.instruction_data.vesting_mode,
-            vesting_unlock_ts: self.instruction_data.vesting_unlock_ts,
-            mint_nft_fee_lamports: self.instruction_data.mint_nft_fee_lamports,
-            update_nft_fee_lamports: self.instruction_data.update_nft_fee_lamports,
-            mint_price_total: self.instruction_data.mint_price_total,
-            escrow_amount: self.instruction_data.escrow_amount,
-            num_revenue_wallets: self.instruction_data.num_revenue_wallets,
-            revenue_wallets: self.instruction_data.revenue_wallets,
-            revenue_shares: self.instruction_data.revenue_shares,
-        });
+        project.update(self.update_args());
 
         Ok(())
     }
+
+    fn close_multisig_proposal(&self) -> ProgramResult {
+        SystemProgram::close_account_pda(
+            self.accounts.multisig_proposal_pda,
+            self.accounts.proposer,
+        )
+    }
 }
 
 impl<'a, 'info> ProcessInstruction for UpdateProjectV1<'a, 'info> {
     fn process(self) -> ProgramResult {
+        let via_multisig = self.check_authority()?;
+        self.check_timelock()?;
         self.check_project_data()?;
         self.update_collection()?;
-        self.update_project()
+        self.update_project()?;
+
+        if via_multisig {
+            self.close_multisig_proposal()?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,121 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::ProtocolConfigV1,
+    utils::{
+        AccountCheck, Pda, ProcessInstruction, ProtocolConfigAccount, SignerAccount,
+        WritableAccount,
+    },
+};
+
+#[derive(Debug)]
+pub struct UpdateProtocolConfigV1Accounts<'a, 'info> {
+    /// Protocol authority — must sign and match `protocol_config.authority`.
+    pub authority: &'a AccountInfo<'info>,
+
+    /// PDA: `["protocol_config_v1", program_id]` — stores the protocol config.
+    /// Must be initialized and writable.
+    pub protocol_config_pda: &'a AccountInfo<'info>,
+
+    /// Wallet that will receive SOL protocol fees. Pass the current one to keep it.
+    /// Must be owned by the system program.
+    pub fee_wallet: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TryFrom<&'a [AccountInfo<'info>]> for UpdateProtocolConfigV1Accounts<'a, 'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        let [authority, protocol_config_pda, fee_wallet] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+
+        WritableAccount::check(protocol_config_pda)?;
+
+        ProtocolConfigAccount::check(protocol_config_pda)?;
+
+        Ok(Self {
+            authority,
+            protocol_config_pda,
+            fee_wallet,
+        })
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UpdateProtocolConfigV1InstructionData {
+    pub default_mint_nft_fee_lamports: u64,
+    pub default_update_nft_fee_lamports: u64,
+    pub default_trait_mint_fee_lamports: u64,
+    pub protocol_fee_bps: u16,
+}
+
+#[derive(Debug)]
+pub struct UpdateProtocolConfigV1<'a, 'info> {
+    pub accounts: UpdateProtocolConfigV1Accounts<'a, 'info>,
+    pub instruction_data: UpdateProtocolConfigV1InstructionData,
+}
+
+impl<'a, 'info>
+    TryFrom<(
+        &'a [AccountInfo<'info>],
+        UpdateProtocolConfigV1InstructionData,
+        &'a Pubkey,
+    )> for UpdateProtocolConfigV1<'a, 'info>
+{
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data, program_id): (
+            &'a [AccountInfo<'info>],
+            UpdateProtocolConfigV1InstructionData,
+            &'a Pubkey,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let accounts = UpdateProtocolConfigV1Accounts::try_from(accounts)?;
+
+        Pda::validate(
+            accounts.protocol_config_pda,
+            &[ProtocolConfigV1::SEED],
+            program_id,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a, 'info> ProcessInstruction for UpdateProtocolConfigV1<'a, 'info> {
+    fn process(self) -> ProgramResult {
+        let mut config_data = self.accounts.protocol_config_pda.try_borrow_mut_data()?;
+        let config = ProtocolConfigV1::load_mut(&mut config_data)?;
+
+        if config.authority != *self.accounts.authority.key {
+            msg!("Unauthorized: only the protocol authority may update the protocol config");
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        ProtocolConfigV1::check_fee_wallet(self.accounts.fee_wallet)?;
+        ProtocolConfigV1::check_protocol_fee_bps(self.instruction_data.protocol_fee_bps)?;
+
+        config.fee_wallet = *self.accounts.fee_wallet.key;
+        config.default_mint_nft_fee_lamports = self.instruction_data.default_mint_nft_fee_lamports;
+        config.default_update_nft_fee_lamports =
+            self.instruction_data.default_update_nft_fee_lamports;
+        config.default_trait_mint_fee_lamports =
+            self.instruction_data.default_trait_mint_fee_lamports;
+        config.protocol_fee_bps = self.instruction_data.protocol_fee_bps;
+
+        msg!("Protocol fee wallet set to {}", config.fee_wallet);
+
+        Ok(())
+    }
+}
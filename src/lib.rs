@@ -6,11 +6,28 @@ use solana_program::{
 
 use crate::{
     instructions::{
-        BurnAndRefundV1, ForceUnlockVestingV1, InitProjectV1, InitProjectV1InstructionData,
-        InitTraitV1, InitTraitV1InstructionData, MintAdminV1, MintAdminV1InstructionData,
-        MintTraitV1, MintTraitV1InstructionData, MintUserV1, MintUserV1InstructionData, MintVipV1,
-        MintVipV1InstructionData, TransferToVaultV1, TransferToVaultV1InstructionData, UpdateNftV1,
-        UpdateNftV1InstructionData, UpdateProjectV1, UpdateProjectV1InstructionData, UpdateTraitV1,
+        AcceptAdminV1, ApproveMultisigProposalV1, BurnAndRefundV1, CancelAdminProposalV1,
+        CancelProjectUpdateV1, CloseCouponV1, CreateCouponV1, CreateCouponV1InstructionData,
+        CreateMultisigProposalV1, CreateMultisigProposalV1InstructionData, ExecuteProjectUpdateV1,
+        ForceUnlockVestingV1, GrantProjectRoleV1, GrantProjectRoleV1InstructionData,
+        GrantVipPassV1, GrantVipPassV1InstructionData, InitMultisigV1,
+        InitMultisigV1InstructionData, InitProjectV1, InitProjectV1InstructionData,
+        InitProtocolConfigV1, InitProtocolConfigV1InstructionData, InitTraitV1,
+        InitTraitV1InstructionData, MigrateProjectV1, MigrateTraitV1, MintAdminAirdropV1,
+        MintAdminAirdropV1InstructionData, MintAdminV1, MintAdminV1InstructionData, MintTraitV1,
+        MintTraitV1InstructionData, MintUserBatchV1, MintUserBatchV1InstructionData, MintUserV1,
+        MintUserV1InstructionData, MintVipV1, MintVipV1InstructionData, ProposeAdminV1,
+        ProposeAdminV1InstructionData, QueueProjectUpdateV1, QueueProjectUpdateV1InstructionData,
+        RegisterReferrerV1, RegisterReferrerV1InstructionData, RemovePaymentMintV1, RevealV1,
+        RevealV1InstructionData, RevokeProjectRoleV1, RevokeProjectRoleV1InstructionData,
+        RevokeVipPassV1, SetMetadataPolicyV1, SetMetadataPolicyV1InstructionData, SetMintPhasesV1,
+        SetMintPhasesV1InstructionData, SetNftNamingV1, SetNftNamingV1InstructionData,
+        SetPaymentMintV1, SetPaymentMintV1InstructionData, SetProjectPauseV1,
+        SetProjectPauseV1InstructionData, SetProjectTimelockV1,
+        SetProjectTimelockV1InstructionData, SetTraitPauseV1, SetTraitPauseV1InstructionData,
+        TransferToVaultV1, TransferToVaultV1InstructionData, UpdateNftV1,
+        UpdateNftV1InstructionData, UpdateProjectV1, UpdateProjectV1InstructionData,
+        UpdateProtocolConfigV1, UpdateProtocolConfigV1InstructionData, UpdateTraitV1,
         UpdateTraitV1InstructionData,
     },
     utils::ProcessInstruction,
@@ -42,6 +59,37 @@ pub fn process_instruction(
         Some((9, _)) => process_burn_nft(program_id, accounts),
         Some((10, _)) => process_force_unlock_vesting(program_id, accounts),
         Some((11, data)) => process_transfer_to_vault(program_id, accounts, data),
+        Some((12, data)) => process_grant_project_role(program_id, accounts, data),
+        Some((13, data)) => process_revoke_project_role(program_id, accounts, data),
+        Some((14, data)) => process_propose_admin(program_id, accounts, data),
+        Some((15, _)) => process_accept_admin(program_id, accounts),
+        Some((16, _)) => process_cancel_admin_proposal(program_id, accounts),
+        Some((17, data)) => process_init_multisig(program_id, accounts, data),
+        Some((18, data)) => process_create_multisig_proposal(program_id, accounts, data),
+        Some((19, _)) => process_approve_multisig_proposal(program_id, accounts),
+        Some((20, data)) => process_set_project_timelock_v1(program_id, accounts, data),
+        Some((21, data)) => process_queue_project_update_v1(program_id, accounts, data),
+        Some((22, _)) => process_execute_project_update_v1(program_id, accounts),
+        Some((23, _)) => process_cancel_project_update_v1(program_id, accounts),
+        Some((24, data)) => process_set_project_pause_v1(program_id, accounts, data),
+        Some((25, data)) => process_set_trait_pause_v1(program_id, accounts, data),
+        Some((26, data)) => process_init_protocol_config_v1(program_id, accounts, data),
+        Some((27, data)) => process_update_protocol_config_v1(program_id, accounts, data),
+        Some((28, data)) => process_grant_vip_pass_v1(program_id, accounts, data),
+        Some((29, _)) => process_revoke_vip_pass_v1(program_id, accounts),
+        Some((30, data)) => process_set_mint_phases_v1(program_id, accounts, data),
+        Some((31, data)) => process_mint_user_batch_v1(program_id, accounts, data),
+        Some((32, data)) => process_mint_admin_airdrop_v1(program_id, accounts, data),
+        Some((33, data)) => process_set_payment_mint_v1(program_id, accounts, data),
+        Some((34, _)) => process_remove_payment_mint_v1(program_id, accounts),
+        Some((35, data)) => process_register_referrer_v1(program_id, accounts, data),
+        Some((36, data)) => process_create_coupon_v1(program_id, accounts, data),
+        Some((37, _)) => process_close_coupon_v1(program_id, accounts),
+        Some((38, data)) => process_set_nft_naming_v1(program_id, accounts, data),
+        Some((39, data)) => process_set_metadata_policy_v1(program_id, accounts, data),
+        Some((40, data)) => process_reveal_v1(program_id, accounts, data),
+        Some((41, _)) => process_migrate_project_v1(program_id, accounts),
+        Some((42, _)) => process_migrate_trait_v1(program_id, accounts),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -153,3 +201,320 @@ fn process_transfer_to_vault(
         .map_err(|_| ProgramError::InvalidInstructionData)?;
     TransferToVaultV1::try_from((accounts, data, program_id))?.process()
 }
+
+#[inline(never)]
+fn process_grant_project_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Grant Project Role");
+    let data = GrantProjectRoleV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    GrantProjectRoleV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_revoke_project_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Revoke Project Role");
+    let data = RevokeProjectRoleV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    RevokeProjectRoleV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_propose_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Propose Admin");
+    let data = ProposeAdminV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    ProposeAdminV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_accept_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Accept Admin");
+    AcceptAdminV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_cancel_admin_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Cancel Admin Proposal");
+    CancelAdminProposalV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_init_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Initialize Multisig");
+    let data = InitMultisigV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    InitMultisigV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_create_multisig_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Create Multisig Proposal");
+    let data = CreateMultisigProposalV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    CreateMultisigProposalV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_approve_multisig_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Approve Multisig Proposal");
+    ApproveMultisigProposalV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_project_timelock_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Project Timelock");
+    let data = SetProjectTimelockV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetProjectTimelockV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_queue_project_update_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Queue Project Update");
+    let data = QueueProjectUpdateV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    QueueProjectUpdateV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_execute_project_update_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Execute Project Update");
+    ExecuteProjectUpdateV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_cancel_project_update_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Cancel Project Update");
+    CancelProjectUpdateV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_project_pause_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Project Pause");
+    let data = SetProjectPauseV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetProjectPauseV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_trait_pause_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Trait Pause");
+    let data = SetTraitPauseV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetTraitPauseV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_init_protocol_config_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Initialize Protocol Config");
+    let data = InitProtocolConfigV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    InitProtocolConfigV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_update_protocol_config_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Update Protocol Config");
+    let data = UpdateProtocolConfigV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    UpdateProtocolConfigV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_grant_vip_pass_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Grant VIP Pass");
+    let data = GrantVipPassV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    GrantVipPassV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_revoke_vip_pass_v1(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Revoke VIP Pass");
+    RevokeVipPassV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_mint_phases_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Mint Phases");
+    let data = SetMintPhasesV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetMintPhasesV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_mint_user_batch_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Mint User Batch");
+    let data = MintUserBatchV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    MintUserBatchV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_mint_admin_airdrop_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Mint Admin Airdrop");
+    let data = MintAdminAirdropV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    MintAdminAirdropV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_payment_mint_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Payment Mint");
+    let data = SetPaymentMintV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetPaymentMintV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_remove_payment_mint_v1(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Remove Payment Mint");
+    RemovePaymentMintV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_register_referrer_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Register Referrer");
+    let data = RegisterReferrerV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    RegisterReferrerV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_create_coupon_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Create Coupon");
+    let data = CreateCouponV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    CreateCouponV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_close_coupon_v1(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Close Coupon");
+    CloseCouponV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_nft_naming_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set NFT Naming");
+    let data = SetNftNamingV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetNftNamingV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_set_metadata_policy_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    msg!("Set Metadata Policy");
+    let data = SetMetadataPolicyV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    SetMetadataPolicyV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_reveal_v1(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    msg!("Reveal");
+    let data = RevealV1InstructionData::try_from_slice(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    RevealV1::try_from((accounts, data, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_migrate_project_v1(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Migrate Project");
+    MigrateProjectV1::try_from((accounts, program_id))?.process()
+}
+
+#[inline(never)]
+fn process_migrate_trait_v1(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Migrate Trait");
+    MigrateTraitV1::try_from((accounts, program_id))?.process()
+}
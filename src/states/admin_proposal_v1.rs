@@ -0,0 +1,198 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::mem::transmute;
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{ProjectV1, TraitItemV1},
+    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, ProjectAccount, TraitItemAccount},
+};
+
+/// Kind of account whose admin is being handed over.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum AdminTarget {
+    /// `ProjectV1::admin`.
+    Project = 0,
+
+    /// `TraitItemV1::authority`.
+    TraitItem = 1,
+}
+
+impl AdminTarget {
+    #[inline(always)]
+    pub fn check<'info>(self, account: &AccountInfo<'info>) -> ProgramResult {
+        match self {
+            Self::Project => ProjectAccount::check(account),
+            Self::TraitItem => TraitItemAccount::check(account),
+        }
+    }
+
+    #[inline(always)]
+    pub fn admin(self, bytes: &[u8]) -> Result<Pubkey, ProgramError> {
+        match self {
+            Self::Project => Ok(ProjectV1::load(bytes)?.admin),
+            Self::TraitItem => Ok(TraitItemV1::load(bytes)?.authority),
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_admin(self, bytes: &mut [u8], admin: Pubkey) -> ProgramResult {
+        match self {
+            Self::Project => ProjectV1::load_mut(bytes)?.admin = admin,
+            Self::TraitItem => TraitItemV1::load_mut(bytes)?.authority = admin,
+        }
+
+        Ok(())
+    }
+}
+
+/// Pending admin handover for a `ProjectV1` or `TraitItemV1` account.
+///
+/// Written by `propose_admin_v1` and consumed by `accept_admin_v1`, which
+/// requires `pending_admin` to sign. Closed on accept or cancel.
+///
+/// PDA seed: `[program_id, "admin_proposal_v1", target]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct AdminProposalV1 {
+    /// The project or trait PDA being handed over.
+    pub target: Pubkey,
+
+    /// The admin that created the proposal. Receives the rent back on close.
+    ///
+    /// - Acceptance fails if the target's admin changed since the proposal.
+    pub proposer: Pubkey,
+
+    /// The wallet that becomes admin once it signs `accept_admin_v1`.
+    pub pending_admin: Pubkey,
+
+    /// Kind of `target` account.
+    pub target_kind: AdminTarget,
+}
+
+impl AdminProposalV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 17] = b"admin_proposal_v1";
+}
+
+impl AdminProposalV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitAdminProposalAccounts<'a, 'info>,
+        args: InitAdminProposalArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let proposal = Self::load_mut(&mut bytes)?;
+        proposal.target = args.target;
+        proposal.proposer = args.proposer;
+        proposal.pending_admin = args.pending_admin;
+        proposal.target_kind = args.target_kind;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load admin proposal with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut admin proposal with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+pub struct InitAdminProposalAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitAdminProposalArgs {
+    pub target: Pubkey,
+    pub proposer: Pubkey,
+    pub pending_admin: Pubkey,
+    pub target_kind: AdminTarget,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_admin_proposal_load_and_load_mut() {
+        let mut data = vec![0u8; AdminProposalV1::LEN];
+        let pending_admin = Pubkey::new_unique();
+
+        let proposal_mut = AdminProposalV1::load_mut(&mut data).unwrap();
+        proposal_mut.pending_admin = pending_admin;
+        proposal_mut.target_kind = AdminTarget::TraitItem;
+
+        let proposal_ref = AdminProposalV1::load(&data).unwrap();
+        assert_eq!(proposal_ref.pending_admin, pending_admin);
+        assert_eq!(proposal_ref.target_kind, AdminTarget::TraitItem);
+    }
+
+    #[test]
+    fn test_admin_proposal_load_invalid_length() {
+        let mut bad = vec![0u8; AdminProposalV1::LEN - 1];
+        assert!(AdminProposalV1::load(&bad).is_err());
+        assert!(AdminProposalV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_admin_target_get_and_set_admin() {
+        let new_admin = Pubkey::new_unique();
+
+        let mut project = vec![0u8; ProjectV1::LEN];
+        AdminTarget::Project
+            .set_admin(&mut project, new_admin)
+            .unwrap();
+        assert_eq!(AdminTarget::Project.admin(&project).unwrap(), new_admin);
+
+        let mut trait_item = vec![0u8; TraitItemV1::LEN];
+        AdminTarget::TraitItem
+            .set_admin(&mut trait_item, new_admin)
+            .unwrap();
+        assert_eq!(
+            AdminTarget::TraitItem.admin(&trait_item).unwrap(),
+            new_admin
+        );
+
+        assert!(AdminTarget::Project.admin(&trait_item).is_err());
+    }
+}
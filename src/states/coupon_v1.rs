@@ -0,0 +1,285 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::mem::transmute;
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::MAX_BASIS_POINTS,
+    utils::{InitPdaAccounts, InitPdaArgs, Pda},
+};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum CouponKind {
+    /// `CouponV1::amount` is a share of the mint price, in basis points.
+    Percent = 0,
+
+    /// `CouponV1::amount` is a flat discount in raw units of `ProjectV1::mint`.
+    Fixed = 1,
+}
+
+/// Discount coupon created by the project treasurer.
+///
+/// Created by `create_coupon_v1` and closed by `close_coupon_v1`.
+/// `mint_user_v1` and `mint_vip_v1` redeem it once per mint; the discount is
+/// taken out of the revenue shares only, never the escrow or protocol cut.
+///
+/// PDA seed: `[program_id, "coupon_v1", project_pda, code]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct CouponV1 {
+    /// The `ProjectV1` PDA this coupon belongs to.
+    pub project: Pubkey,
+
+    /// Only wallet allowed to redeem the coupon.
+    ///
+    /// - `Pubkey::default()` lets any payer redeem it.
+    pub wallet: Pubkey,
+
+    /// Client-chosen coupon code (e.g. a hash of the printed code), part of the PDA seeds.
+    pub code: [u8; 32],
+
+    /// Discount per mint; see `CouponKind`.
+    pub amount: u64,
+
+    /// Maximum number of redemptions.
+    ///
+    /// - `0` means unlimited.
+    pub max_redemptions: u64,
+
+    /// Number of redemptions so far.
+    pub redeemed: u64,
+
+    /// UNIX timestamp after which the coupon can no longer be redeemed.
+    ///
+    /// - `0` means the coupon never expires.
+    pub expires_at: i64,
+
+    pub kind: CouponKind,
+}
+
+impl CouponV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 9] = b"coupon_v1";
+}
+
+impl CouponV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitCouponAccounts<'a, 'info>,
+        args: InitCouponArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let coupon = Self::load_mut(&mut bytes)?;
+        coupon.project = args.project;
+        coupon.wallet = args.wallet;
+        coupon.code = args.code;
+        coupon.amount = args.amount;
+        coupon.max_redemptions = args.max_redemptions;
+        coupon.redeemed = 0;
+        coupon.expires_at = args.expires_at;
+        coupon.kind = args.kind;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load coupon with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut coupon with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl CouponV1 {
+    #[inline(always)]
+    pub fn check_args(kind: CouponKind, amount: u64) -> ProgramResult {
+        if amount == 0 || (kind == CouponKind::Percent && amount > MAX_BASIS_POINTS as u64) {
+            msg!("Coupon needs 0 < amount (and amount <= 10_000 for percent coupons)");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    #[inline(always)]
+    pub fn is_exhausted(&self) -> bool {
+        self.max_redemptions != 0 && self.redeemed >= self.max_redemptions
+    }
+
+    /// Discount granted on a mint costing `price`.
+    #[inline(always)]
+    pub fn discount(&self, price: u64) -> u64 {
+        match self.kind {
+            CouponKind::Percent => {
+                (price as u128 * self.amount as u128 / MAX_BASIS_POINTS as u128) as u64
+            }
+            CouponKind::Fixed => self.amount,
+        }
+    }
+
+    /// Counts one redemption by `payer` at `now`.
+    ///
+    /// Fails with `Custom(13)` when the coupon is expired, used up, or bound to
+    /// another wallet.
+    #[inline(always)]
+    pub fn redeem(&mut self, payer: &Pubkey, now: i64) -> ProgramResult {
+        if self.wallet != Pubkey::default() && self.wallet != *payer {
+            msg!("Coupon is bound to wallet {}", self.wallet);
+            return Err(ProgramError::Custom(13));
+        }
+
+        if self.is_expired(now) {
+            msg!("Coupon expired at {}", self.expires_at);
+            return Err(ProgramError::Custom(13));
+        }
+
+        if self.is_exhausted() {
+            msg!(
+                "Coupon used up. Allowed: {}. Redeemed: {}",
+                self.max_redemptions,
+                self.redeemed
+            );
+            return Err(ProgramError::Custom(13));
+        }
+
+        self.redeemed = self
+            .redeemed
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Unable to increment coupon.redeemed"))?;
+        Ok(())
+    }
+}
+
+pub struct InitCouponAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitCouponArgs {
+    pub project: Pubkey,
+    pub wallet: Pubkey,
+    pub code: [u8; 32],
+    pub kind: CouponKind,
+    pub amount: u64,
+    pub max_redemptions: u64,
+    pub expires_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn mock_coupon(kind: CouponKind, amount: u64, max_redemptions: u64) -> CouponV1 {
+        CouponV1 {
+            project: Pubkey::new_unique(),
+            wallet: Pubkey::default(),
+            code: [7u8; 32],
+            amount,
+            max_redemptions,
+            redeemed: 0,
+            expires_at: 0,
+            kind,
+        }
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_coupon_load_invalid_length() {
+        let mut bad = vec![0u8; CouponV1::LEN - 1];
+        assert!(CouponV1::load(&bad).is_err());
+        assert!(CouponV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_coupon_check_args() {
+        assert!(CouponV1::check_args(CouponKind::Percent, 2_500).is_ok());
+        assert!(CouponV1::check_args(CouponKind::Percent, 10_001).is_err());
+        assert!(CouponV1::check_args(CouponKind::Fixed, 10_001).is_ok());
+        assert!(CouponV1::check_args(CouponKind::Fixed, 0).is_err());
+    }
+
+    #[test]
+    fn test_coupon_discount() {
+        assert_eq!(
+            mock_coupon(CouponKind::Percent, 2_500, 0).discount(30_000_000),
+            7_500_000
+        );
+        assert_eq!(
+            mock_coupon(CouponKind::Fixed, 1_000, 0).discount(30_000_000),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_coupon_redeem() {
+        let payer = Pubkey::new_unique();
+
+        let mut coupon = mock_coupon(CouponKind::Fixed, 1_000, 2);
+        coupon.redeem(&payer, 0).unwrap();
+        coupon.redeem(&payer, 0).unwrap();
+        assert_eq!(coupon.redeemed, 2);
+        assert_eq!(coupon.redeem(&payer, 0), Err(ProgramError::Custom(13)));
+
+        let mut expired = CouponV1 {
+            expires_at: 100,
+            ..mock_coupon(CouponKind::Fixed, 1_000, 0)
+        };
+        assert!(expired.redeem(&payer, 100).is_ok());
+        assert_eq!(expired.redeem(&payer, 101), Err(ProgramError::Custom(13)));
+
+        let mut bound = CouponV1 {
+            wallet: payer,
+            ..mock_coupon(CouponKind::Fixed, 1_000, 0)
+        };
+        assert!(bound.redeem(&payer, 0).is_ok());
+        assert_eq!(
+            bound.redeem(&Pubkey::new_unique(), 0),
+            Err(ProgramError::Custom(13))
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use shank::ShankType;
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError};
+
+use crate::states::MAX_METADATA_URI_PREFIX_LEN;
+
+/// Validation rules for client-supplied NFT names and URIs, stored in
+/// `ProjectV1::metadata_policy`.
+///
+/// Checked by every mint path and `update_nft_v1` before the MPL Core CPI.
+/// The zeroed default accepts any metadata.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ShankType)]
+pub struct MetadataPolicy {
+    /// Max URI length in bytes. `0` means no limit.
+    pub max_uri_len: u16,
+
+    /// Max name length in bytes. `0` means no limit.
+    pub max_name_len: u8,
+
+    /// Used bytes of `uri_prefix`.
+    pub uri_prefix_len: u8,
+
+    /// Required URI prefix (e.g. `https://arweave.net/`), zero padded.
+    pub uri_prefix: [u8; 64],
+
+    /// ASCII allowlist for names, one bit per character code.
+    ///
+    /// - All zero means any character is allowed.
+    pub name_charset: [u8; 16],
+}
+
+impl Default for MetadataPolicy {
+    fn default() -> Self {
+        Self {
+            max_uri_len: 0,
+            max_name_len: 0,
+            uri_prefix_len: 0,
+            uri_prefix: [0u8; MAX_METADATA_URI_PREFIX_LEN],
+            name_charset: [0u8; 16],
+        }
+    }
+}
+
+impl MetadataPolicy {
+    /// Builds the policy from admin input.
+    ///
+    /// `name_charset` lists every allowed name character (ASCII only); empty allows any.
+    #[inline(always)]
+    pub fn new(
+        max_name_len: u8,
+        max_uri_len: u16,
+        uri_prefix: &str,
+        name_charset: &str,
+    ) -> Result<Self, ProgramError> {
+        if uri_prefix.len() > MAX_METADATA_URI_PREFIX_LEN {
+            msg!("URI prefix too long. Max: {}", MAX_METADATA_URI_PREFIX_LEN);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if max_uri_len != 0 && uri_prefix.len() > max_uri_len as usize {
+            msg!("URI prefix is longer than the max URI length");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if !name_charset.is_ascii() {
+            msg!("Name charset must be ASCII");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut policy = Self {
+            max_uri_len,
+            max_name_len,
+            uri_prefix_len: uri_prefix.len() as u8,
+            ..Self::default()
+        };
+        policy.uri_prefix[..uri_prefix.len()].copy_from_slice(uri_prefix.as_bytes());
+        for c in name_charset.bytes() {
+            policy.name_charset[(c / 8) as usize] |= 1 << (c % 8);
+        }
+
+        Ok(policy)
+    }
+
+    #[inline(always)]
+    pub fn uri_prefix(&self) -> &[u8] {
+        let len = (self.uri_prefix_len as usize).min(MAX_METADATA_URI_PREFIX_LEN);
+        &self.uri_prefix[..len]
+    }
+
+    #[inline(always)]
+    pub fn has_name_charset(&self) -> bool {
+        self.name_charset.iter().any(|b| *b != 0)
+    }
+
+    #[inline(always)]
+    pub fn allows_name_char(&self, c: char) -> bool {
+        if !self.has_name_charset() {
+            return true;
+        }
+
+        c.is_ascii() && self.name_charset[c as usize / 8] & (1 << (c as usize % 8)) != 0
+    }
+
+    /// Checks `name` and `uri` against the policy.
+    ///
+    /// Errors: `14` name too long, `15` URI too long, `16` URI prefix mismatch,
+    /// `17` name character not allowed.
+    #[inline(always)]
+    pub fn check(&self, name: &str, uri: &str) -> ProgramResult {
+        self.check_name(name)?;
+        self.check_uri(uri)
+    }
+
+    /// Checks `name` alone. Errors: `14` too long, `17` character not allowed.
+    #[inline(always)]
+    pub fn check_name(&self, name: &str) -> ProgramResult {
+        if self.max_name_len != 0 && name.len() > self.max_name_len as usize {
+            msg!(
+                "NFT name is {} bytes. Max: {}",
+                name.len(),
+                self.max_name_len
+            );
+            return Err(ProgramError::Custom(14));
+        }
+
+        if let Some(c) = name.chars().find(|c| !self.allows_name_char(*c)) {
+            msg!("NFT name character {:?} is not allowed", c);
+            return Err(ProgramError::Custom(17));
+        }
+
+        Ok(())
+    }
+
+    /// Checks `uri` alone. Errors: `15` too long, `16` prefix mismatch.
+    #[inline(always)]
+    pub fn check_uri(&self, uri: &str) -> ProgramResult {
+        if self.max_uri_len != 0 && uri.len() > self.max_uri_len as usize {
+            msg!("NFT URI is {} bytes. Max: {}", uri.len(), self.max_uri_len);
+            return Err(ProgramError::Custom(15));
+        }
+
+        if !uri.as_bytes().starts_with(self.uri_prefix()) {
+            msg!(
+                "NFT URI must start with {}",
+                core::str::from_utf8(self.uri_prefix()).unwrap_or_default()
+            );
+            return Err(ProgramError::Custom(16));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn arweave_policy() -> MetadataPolicy {
+        MetadataPolicy::new(
+            16,
+            64,
+            "https://arweave.net/",
+            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 #",
+        )
+        .unwrap()
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_default_policy_accepts_anything() {
+        let policy = MetadataPolicy::default();
+        assert!(policy.check("Ünïcode — name!", "ipfs://anything").is_ok());
+        assert!(policy.check("", "").is_ok());
+    }
+
+    #[test]
+    fn test_policy_accepts_valid_metadata() {
+        let policy = arweave_policy();
+        assert!(policy
+            .check("Tweetonium #1", "https://arweave.net/abc/1.json")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_policy_rejects_long_name() {
+        let policy = arweave_policy();
+        let res = policy.check("Tweetonium #1234567", "https://arweave.net/abc");
+        assert_eq!(res, Err(ProgramError::Custom(14)));
+    }
+
+    #[test]
+    fn test_policy_rejects_long_uri() {
+        let policy = arweave_policy();
+        let uri = format!("https://arweave.net/{}", "a".repeat(64));
+        let res = policy.check("Tweetonium #1", &uri);
+        assert_eq!(res, Err(ProgramError::Custom(15)));
+    }
+
+    #[test]
+    fn test_policy_rejects_uri_prefix_mismatch() {
+        let policy = arweave_policy();
+        let res = policy.check("Tweetonium #1", "https://evil.example/1.json");
+        assert_eq!(res, Err(ProgramError::Custom(16)));
+    }
+
+    #[test]
+    fn test_policy_rejects_name_charset_mismatch() {
+        let policy = arweave_policy();
+        assert_eq!(
+            policy.check("Tweet-1", "https://arweave.net/1"),
+            Err(ProgramError::Custom(17))
+        );
+        assert_eq!(
+            policy.check("Twéet", "https://arweave.net/1"),
+            Err(ProgramError::Custom(17))
+        );
+    }
+
+    #[test]
+    fn test_policy_new_rejects_invalid_args() {
+        let long_prefix = "a".repeat(MAX_METADATA_URI_PREFIX_LEN + 1);
+        assert!(MetadataPolicy::new(0, 0, &long_prefix, "").is_err());
+        assert!(MetadataPolicy::new(0, 8, "https://arweave.net/", "").is_err());
+        assert!(MetadataPolicy::new(0, 0, "", "abcé").is_err());
+    }
+}
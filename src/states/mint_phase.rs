@@ -0,0 +1,94 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum MintPhaseKind {
+    /// Only `mint_vip_v1` is open during the phase.
+    Vip = 0,
+
+    /// Only `mint_user_v1` is open during the phase.
+    Public = 1,
+}
+
+/// One window of a project's mint schedule, stored in `ProjectV1::mint_phases`.
+///
+/// Outside every scheduled window both `mint_user_v1` and `mint_vip_v1` are closed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ShankType)]
+pub struct MintPhase {
+    /// Which mint instruction the phase opens.
+    pub kind: MintPhaseKind,
+
+    /// UNIX timestamp the phase opens at (inclusive).
+    pub start_ts: i64,
+
+    /// UNIX timestamp the phase closes at (exclusive).
+    pub end_ts: i64,
+
+    /// Max NFTs minted during the phase.
+    ///
+    /// - `0` means the phase is only bounded by the project supply.
+    pub max_mints: u64,
+
+    /// NFTs minted during the phase so far.
+    pub minted: u64,
+}
+
+impl Default for MintPhase {
+    fn default() -> Self {
+        Self {
+            kind: MintPhaseKind::Vip,
+            start_ts: 0,
+            end_ts: 0,
+            max_mints: 0,
+            minted: 0,
+        }
+    }
+}
+
+impl MintPhase {
+    #[inline(always)]
+    pub fn has_started(&self, now: i64) -> bool {
+        self.start_ts <= now
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        self.start_ts <= now && now < self.end_ts
+    }
+
+    #[inline(always)]
+    pub fn has_reached_cap(&self) -> bool {
+        self.max_mints != 0 && self.minted >= self.max_mints
+    }
+
+    #[inline(always)]
+    pub fn matches(&self, args: &MintPhaseArgs) -> bool {
+        self.kind == args.kind
+            && self.start_ts == args.start_ts
+            && self.end_ts == args.end_ts
+            && self.max_mints == args.max_mints
+    }
+}
+
+/// Admin-supplied phase; `minted` is tracked on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct MintPhaseArgs {
+    pub kind: MintPhaseKind,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub max_mints: u64,
+}
+
+impl From<MintPhaseArgs> for MintPhase {
+    fn from(args: MintPhaseArgs) -> Self {
+        Self {
+            kind: args.kind,
+            start_ts: args.start_ts,
+            end_ts: args.end_ts,
+            max_mints: args.max_mints,
+            minted: 0,
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::mem::transmute;
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{InitPdaAccounts, InitPdaArgs, Pda};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum MintTier {
+    /// Minted by `mint_admin_v1` or `mint_admin_airdrop_v1` from the admin supply.
+    Admin = 0,
+
+    /// Minted by `mint_vip_v1`.
+    Vip = 1,
+
+    /// Minted by `mint_user_v1` or `mint_user_batch_v1`.
+    User = 2,
+}
+
+/// Per-NFT mint receipt written once by every mint instruction.
+///
+/// Records the terms the NFT was bought under, and pins it to its 1-based mint
+/// `index`, which `reveal_v1` uses to look up the NFT's entry in the committed
+/// reveal manifest.
+///
+/// PDA seed: `[program_id, "mint_record_v1", nft_asset]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct MintRecordV1 {
+    /// The `ProjectV1` PDA the NFT was minted from.
+    pub project: Pubkey,
+
+    /// The MPL Core asset this record belongs to.
+    pub nft: Pubkey,
+
+    /// Wallet that signed and paid for the mint (the admin for admin mints and airdrops).
+    pub minter: Pubkey,
+
+    /// Token mint `price_paid` and `escrow_amount` are denominated in.
+    pub mint: Pubkey,
+
+    /// 1-based mint number of `nft` within the project (`admin_minted + user_minted + vip_minted` at mint).
+    pub index: u64,
+
+    /// Price paid after discounts, in raw units of `mint`. `0` for admin mints.
+    pub price_paid: u64,
+
+    /// Portion of `price_paid` (or of the admin deposit) escrowed in the NFT vault.
+    pub escrow_amount: u64,
+
+    /// Slot the NFT was minted in.
+    pub slot: u64,
+
+    /// UNIX timestamp the NFT was minted at.
+    pub timestamp: i64,
+
+    /// Mint instruction family the NFT came from.
+    pub tier: MintTier,
+}
+
+impl MintRecordV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 14] = b"mint_record_v1";
+}
+
+impl MintRecordV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitMintRecordAccounts<'a, 'info>,
+        args: InitMintRecordArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let record = Self::load_mut(&mut bytes)?;
+        record.project = args.project;
+        record.nft = args.nft;
+        record.minter = args.minter;
+        record.mint = args.mint;
+        record.index = args.index;
+        record.price_paid = args.price_paid;
+        record.escrow_amount = args.escrow_amount;
+        record.slot = args.slot;
+        record.timestamp = args.timestamp;
+        record.tier = args.tier;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mint record with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut mint record with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+pub struct InitMintRecordAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitMintRecordArgs {
+    pub project: Pubkey,
+    pub nft: Pubkey,
+    pub minter: Pubkey,
+    pub mint: Pubkey,
+    pub index: u64,
+    pub price_paid: u64,
+    pub escrow_amount: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub tier: MintTier,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_mint_record_load_invalid_length() {
+        let mut bad = vec![0u8; MintRecordV1::LEN - 1];
+        assert!(MintRecordV1::load(&bad).is_err());
+        assert!(MintRecordV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_mint_record_roundtrip() {
+        let record = MintRecordV1 {
+            project: Pubkey::new_unique(),
+            nft: Pubkey::new_unique(),
+            minter: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            index: 42,
+            price_paid: 30_000_000,
+            escrow_amount: 15_000_000,
+            slot: 1_234,
+            timestamp: 1_700_000_000,
+            tier: MintTier::Vip,
+        };
+        let bytes = record.to_bytes();
+        let loaded = MintRecordV1::load(&bytes).unwrap();
+
+        assert_eq!(loaded.project, record.project);
+        assert_eq!(loaded.nft, record.nft);
+        assert_eq!(loaded.minter, record.minter);
+        assert_eq!(loaded.mint, record.mint);
+        assert_eq!(loaded.index, 42);
+        assert_eq!(loaded.price_paid, 30_000_000);
+        assert_eq!(loaded.escrow_amount, 15_000_000);
+        assert_eq!(loaded.slot, 1_234);
+        assert_eq!(loaded.timestamp, 1_700_000_000);
+        assert_eq!(loaded.tier, MintTier::Vip);
+    }
+}
@@ -1,17 +1,63 @@
+mod admin_proposal_v1;
 mod authorities_v1;
+mod coupon_v1;
+mod metadata_policy;
+mod mint_phase;
+mod mint_record_v1;
+mod multisig_proposal_v1;
+mod multisig_v1;
+mod nft_naming;
+mod pause;
+mod payment_mint_v1;
+mod pricing;
+mod project_role_v1;
+mod project_update_v1;
 mod project_v1;
+mod protocol_config_v1;
+mod referrer_earnings_v1;
+mod referrer_v1;
 mod trait_item_v1;
 mod user_minted_v1;
 mod vault_v1;
 mod vesting;
+mod vip_gate;
+mod vip_gate_record_v1;
+mod vip_pass_v1;
 
+pub use admin_proposal_v1::*;
 pub use authorities_v1::*;
+pub use coupon_v1::*;
+pub use metadata_policy::*;
+pub use mint_phase::*;
+pub use mint_record_v1::*;
+pub use multisig_proposal_v1::*;
+pub use multisig_v1::*;
+pub use nft_naming::*;
+pub use pause::*;
+pub use payment_mint_v1::*;
+pub use pricing::*;
+pub use project_role_v1::*;
+pub use project_update_v1::*;
 pub use project_v1::*;
+pub use protocol_config_v1::*;
+pub use referrer_earnings_v1::*;
+pub use referrer_v1::*;
 pub use trait_item_v1::*;
 pub use user_minted_v1::*;
 pub use vault_v1::*;
 pub use vesting::*;
+pub use vip_gate::*;
+pub use vip_gate_record_v1::*;
+pub use vip_pass_v1::*;
 
 pub const MAX_REVENUE_WALLETS: usize = 5;
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_UPDATE_DELAY_SECS: i64 = 30 * 24 * 60 * 60;
 pub const MAX_ROYALTY_RECIPIENTS: usize = 5;
 pub const MAX_BASIS_POINTS: u16 = 10_000;
+pub const MAX_MINT_PHASES: usize = 4;
+pub const MAX_MINT_BATCH_SIZE: usize = 10;
+pub const MAX_NFT_NAME_PREFIX_LEN: usize = 32;
+pub const MAX_NFT_BASE_URI_LEN: usize = 200;
+pub const MAX_METADATA_URI_PREFIX_LEN: usize = 64;
+pub const PROJECT_V1_RESERVED_LEN: usize = 960;
@@ -0,0 +1,210 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::mem::transmute;
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::hash, msg,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::utils::{InitPdaAccounts, InitPdaArgs, Pda};
+
+/// Sensitive instruction a `MultisigProposalV1` authorizes.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum MultisigAction {
+    /// `update_project_v1`; payload is the borsh-encoded `UpdateProjectV1InstructionData`.
+    UpdateProject = 0,
+
+    /// `force_unlock_vesting_v1`; payload is empty.
+    ForceUnlockVesting = 1,
+
+    /// `queue_project_update_v1`; payload is the borsh-encoded `QueueProjectUpdateV1InstructionData`.
+    QueueProjectUpdate = 2,
+
+    /// `cancel_project_update_v1`; payload is empty.
+    CancelProjectUpdate = 3,
+
+    /// `set_project_timelock_v1`; payload is the borsh-encoded `SetProjectTimelockV1InstructionData`.
+    SetProjectTimelock = 4,
+
+    /// `set_payment_mint_v1`; payload is the `payment_mint` address followed by the
+    /// borsh-encoded `SetPaymentMintV1InstructionData`.
+    SetPaymentMint = 5,
+}
+
+/// A pending sensitive instruction awaiting multisig approvals.
+///
+/// Created by `create_multisig_proposal_v1`, approved by `approve_multisig_proposal_v1`
+/// and consumed by the target instruction, which closes it and refunds `proposer`.
+///
+/// PDA seed: `[program_id, "multisig_proposal_v1", multisig_pda, index (u64 LE)]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct MultisigProposalV1 {
+    /// The `MultisigV1` PDA this proposal belongs to.
+    pub multisig: Pubkey,
+
+    /// The signer that created the proposal and paid its rent.
+    pub proposer: Pubkey,
+
+    /// `sha256` of the instruction data (without discriminator) that may be executed.
+    pub payload_hash: [u8; 32],
+
+    /// Index used in the PDA seed, taken from `MultisigV1::proposal_count`.
+    pub index: u64,
+
+    /// Bitmask of approvals; bit `i` is set once `MultisigV1::signers[i]` approved.
+    pub approvals: u16,
+
+    /// Approvals required, copied from `MultisigV1::threshold` at creation.
+    pub threshold: u8,
+
+    /// Instruction this proposal authorizes.
+    pub action: MultisigAction,
+}
+
+impl MultisigProposalV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 20] = b"multisig_proposal_v1";
+}
+
+impl MultisigProposalV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitMultisigProposalAccounts<'a, 'info>,
+        args: InitMultisigProposalArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let proposal = Self::load_mut(&mut bytes)?;
+        proposal.multisig = args.multisig;
+        proposal.proposer = args.proposer;
+        proposal.payload_hash = args.payload_hash;
+        proposal.index = args.index;
+        proposal.approvals = 0;
+        proposal.threshold = args.threshold;
+        proposal.action = args.action;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load multisig proposal with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut multisig proposal with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl MultisigProposalV1 {
+    #[inline(always)]
+    pub fn hash_payload(payload: &[u8]) -> [u8; 32] {
+        hash(payload).to_bytes()
+    }
+
+    #[inline(always)]
+    pub fn approve(&mut self, signer_index: usize) -> bool {
+        let bit = 1u16 << signer_index;
+        let is_new = self.approvals & bit == 0;
+        self.approvals |= bit;
+        is_new
+    }
+
+    #[inline(always)]
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    #[inline(always)]
+    pub fn is_approved(&self) -> bool {
+        self.approval_count() >= self.threshold as u32
+    }
+}
+
+pub struct InitMultisigProposalAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitMultisigProposalArgs {
+    pub multisig: Pubkey,
+    pub proposer: Pubkey,
+    pub payload_hash: [u8; 32],
+    pub index: u64,
+    pub threshold: u8,
+    pub action: MultisigAction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_multisig_proposal_load_invalid_length() {
+        let mut bad = vec![0u8; MultisigProposalV1::LEN - 1];
+        assert!(MultisigProposalV1::load(&bad).is_err());
+        assert!(MultisigProposalV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_multisig_proposal_approve() {
+        let mut data = vec![0u8; MultisigProposalV1::LEN];
+        let sut = MultisigProposalV1::load_mut(&mut data).unwrap();
+        sut.threshold = 2;
+
+        assert!(sut.approve(0));
+        assert!(!sut.is_approved());
+
+        assert!(!sut.approve(0));
+        assert_eq!(sut.approval_count(), 1);
+
+        assert!(sut.approve(3));
+        assert_eq!(sut.approval_count(), 2);
+        assert!(sut.is_approved());
+    }
+
+    #[test]
+    fn test_hash_payload() {
+        assert_eq!(
+            MultisigProposalV1::hash_payload(&[1, 2, 3]),
+            MultisigProposalV1::hash_payload(&[1, 2, 3])
+        );
+        assert_ne!(
+            MultisigProposalV1::hash_payload(&[1, 2, 3]),
+            MultisigProposalV1::hash_payload(&[])
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::MAX_MULTISIG_SIGNERS,
+    utils::{InitPdaAccounts, InitPdaArgs, Pda},
+};
+
+/// M-of-N signer set guarding sensitive instructions of a single project.
+///
+/// Created once by `init_multisig_v1`, after which `ProjectV1::multisig` points
+/// here and `update_project_v1` / `force_unlock_vesting_v1` only run through an
+/// approved `MultisigProposalV1`.
+///
+/// PDA seed: `[program_id, "multisig_v1", project_pda]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct MultisigV1 {
+    /// The `ProjectV1` PDA this signer set guards.
+    pub project: Pubkey,
+
+    /// Wallets allowed to create and approve proposals.
+    ///
+    /// - Indexed 0..`num_signers`; the index is the signer's bit in `MultisigProposalV1::approvals`.
+    pub signers: [Pubkey; 10],
+
+    /// Number of proposals created so far. Used as the next proposal PDA index.
+    pub proposal_count: u64,
+
+    /// Approvals required before a proposal can be executed.
+    ///
+    /// - `1 ≤ threshold ≤ num_signers`.
+    pub threshold: u8,
+
+    /// Number of valid entries in `signers`.
+    ///
+    /// - Must be ≤ `MAX_MULTISIG_SIGNERS`.
+    pub num_signers: u8,
+}
+
+impl MultisigV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 11] = b"multisig_v1";
+}
+
+impl MultisigV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitMultisigAccounts<'a, 'info>,
+        args: InitMultisigArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let multisig = Self::load_mut(&mut bytes)?;
+        multisig.project = args.project;
+        multisig.signers = args.signers;
+        multisig.proposal_count = 0;
+        multisig.threshold = args.threshold;
+        multisig.num_signers = args.num_signers;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load multisig with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut multisig with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl MultisigV1 {
+    #[inline(always)]
+    pub fn signer_index(&self, wallet: &Pubkey) -> Option<usize> {
+        self.signers
+            .iter()
+            .take(self.num_signers as usize)
+            .position(|signer| signer == wallet)
+    }
+
+    #[inline(always)]
+    pub fn next_proposal_index(&mut self) -> Result<u64, ProgramError> {
+        let index = self.proposal_count;
+        self.proposal_count = self
+            .proposal_count
+            .checked_add(1)
+            .inspect(|_| msg!("Unable to increment multisig.proposal_count"))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(index)
+    }
+
+    #[inline(always)]
+    pub fn check_signers(
+        threshold: u8,
+        num_signers: u8,
+        signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    ) -> ProgramResult {
+        let num_signers = num_signers as usize;
+
+        if num_signers == 0 || num_signers > MAX_MULTISIG_SIGNERS {
+            msg!(
+                "Multisig signers count ({}) must be between 1 and {}",
+                num_signers,
+                MAX_MULTISIG_SIGNERS
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if threshold == 0 || threshold as usize > num_signers {
+            msg!(
+                "Multisig threshold ({}) must be between 1 and signers count ({})",
+                threshold,
+                num_signers
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let input_signers_count = signers
+            .iter()
+            .filter(|pk| **pk != Pubkey::default())
+            .count();
+
+        if num_signers != input_signers_count {
+            msg!(
+                "Multisig signer mismatch: declared {} but found {} valid wallets",
+                num_signers,
+                input_signers_count,
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let active = &signers[..num_signers];
+
+        if active
+            .iter()
+            .enumerate()
+            .any(|(i, signer)| active[..i].contains(signer))
+        {
+            msg!("Multisig signers must be unique");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct InitMultisigAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitMultisigArgs {
+    pub project: Pubkey,
+    pub signers: [Pubkey; 10],
+    pub threshold: u8,
+    pub num_signers: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::default_pubkeys;
+
+    // --- Test Helpers ---
+
+    fn mock_signers(count: usize) -> [Pubkey; MAX_MULTISIG_SIGNERS] {
+        let mut signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        for signer in signers.iter_mut().take(count) {
+            *signer = Pubkey::new_unique();
+        }
+        signers
+    }
+
+    fn mock_multisig(signers: &[Pubkey]) -> MultisigV1 {
+        let mut all = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        all[..signers.len()].copy_from_slice(signers);
+
+        MultisigV1 {
+            project: Pubkey::new_unique(),
+            signers: all,
+            proposal_count: 0,
+            threshold: 1,
+            num_signers: signers.len() as u8,
+        }
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_multisig_load_invalid_length() {
+        let mut bad = vec![0u8; MultisigV1::LEN - 1];
+        assert!(MultisigV1::load(&bad).is_err());
+        assert!(MultisigV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_signer_index() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let sut = mock_multisig(&[a, b]);
+
+        assert_eq!(sut.signer_index(&a), Some(0));
+        assert_eq!(sut.signer_index(&b), Some(1));
+        assert_eq!(sut.signer_index(&Pubkey::new_unique()), None);
+        assert_eq!(sut.signer_index(&Pubkey::default()), None);
+    }
+
+    #[test]
+    fn test_next_proposal_index() {
+        let mut sut = mock_multisig(&[Pubkey::new_unique()]);
+
+        assert_eq!(sut.next_proposal_index().unwrap(), 0);
+        assert_eq!(sut.next_proposal_index().unwrap(), 1);
+        assert_eq!(sut.proposal_count, 2);
+
+        sut.proposal_count = u64::MAX;
+        assert!(sut.next_proposal_index().is_err());
+    }
+
+    #[test]
+    fn test_check_signers_valid() {
+        let signers = mock_signers(3);
+        assert!(MultisigV1::check_signers(2, 3, signers).is_ok());
+        assert!(MultisigV1::check_signers(3, 3, signers).is_ok());
+    }
+
+    #[test]
+    fn test_check_signers_invalid() {
+        let signers = mock_signers(3);
+
+        assert!(MultisigV1::check_signers(0, 3, signers).is_err());
+        assert!(MultisigV1::check_signers(4, 3, signers).is_err());
+        assert!(MultisigV1::check_signers(1, 2, signers).is_err());
+        assert!(MultisigV1::check_signers(1, 0, default_pubkeys()).is_err());
+
+        let mut duplicated = signers;
+        duplicated[2] = duplicated[0];
+        assert!(MultisigV1::check_signers(1, 3, duplicated).is_err());
+    }
+}
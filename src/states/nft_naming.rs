@@ -0,0 +1,124 @@
+use shank::ShankType;
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::states::{MAX_NFT_BASE_URI_LEN, MAX_NFT_NAME_PREFIX_LEN};
+
+/// Program-generated NFT names and URIs, stored in `ProjectV1::naming`.
+///
+/// When set, user and VIP mints get `"<name_prefix> #<index>"` and
+/// `<base_uri>/<index>.json`, where `index` is the 1-based mint number.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ShankType)]
+pub struct NftNaming {
+    /// Used bytes of `name_prefix`.
+    pub name_prefix_len: u8,
+
+    /// UTF-8 name prefix, zero padded.
+    pub name_prefix: [u8; 32],
+
+    /// Used bytes of `base_uri`.
+    ///
+    /// - `0` means naming is off and minters supply their own metadata.
+    pub base_uri_len: u8,
+
+    /// UTF-8 base URI without trailing `/`, zero padded.
+    pub base_uri: [u8; 200],
+}
+
+impl Default for NftNaming {
+    fn default() -> Self {
+        Self {
+            name_prefix_len: 0,
+            name_prefix: [0u8; MAX_NFT_NAME_PREFIX_LEN],
+            base_uri_len: 0,
+            base_uri: [0u8; MAX_NFT_BASE_URI_LEN],
+        }
+    }
+}
+
+impl NftNaming {
+    /// Builds the naming from admin input; two empty strings turn naming off.
+    #[inline(always)]
+    pub fn new(name_prefix: &str, base_uri: &str) -> Result<Self, ProgramError> {
+        let base_uri = base_uri.trim_end_matches('/');
+
+        if name_prefix.is_empty() != base_uri.is_empty() {
+            msg!("NFT name prefix and base URI must be set together");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if name_prefix.len() > MAX_NFT_NAME_PREFIX_LEN || base_uri.len() > MAX_NFT_BASE_URI_LEN {
+            msg!(
+                "NFT name prefix (max {}) or base URI (max {}) too long",
+                MAX_NFT_NAME_PREFIX_LEN,
+                MAX_NFT_BASE_URI_LEN
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut naming = Self {
+            name_prefix_len: name_prefix.len() as u8,
+            base_uri_len: base_uri.len() as u8,
+            ..Self::default()
+        };
+        naming.name_prefix[..name_prefix.len()].copy_from_slice(name_prefix.as_bytes());
+        naming.base_uri[..base_uri.len()].copy_from_slice(base_uri.as_bytes());
+
+        Ok(naming)
+    }
+
+    #[inline(always)]
+    pub fn is_enabled(&self) -> bool {
+        self.base_uri_len != 0
+    }
+
+    #[inline(always)]
+    pub fn name_prefix(&self) -> &str {
+        let len = (self.name_prefix_len as usize).min(MAX_NFT_NAME_PREFIX_LEN);
+        core::str::from_utf8(&self.name_prefix[..len]).unwrap_or_default()
+    }
+
+    #[inline(always)]
+    pub fn base_uri(&self) -> &str {
+        let len = (self.base_uri_len as usize).min(MAX_NFT_BASE_URI_LEN);
+        core::str::from_utf8(&self.base_uri[..len]).unwrap_or_default()
+    }
+
+    #[inline(always)]
+    pub fn name(&self, index: u64) -> String {
+        format!("{} #{}", self.name_prefix(), index)
+    }
+
+    #[inline(always)]
+    pub fn uri(&self, index: u64) -> String {
+        format!("{}/{}.json", self.base_uri(), index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_nft_naming_new() {
+        let naming = NftNaming::new("Tweetonium", "https://arweave.net/abc/").unwrap();
+        assert!(naming.is_enabled());
+        assert_eq!(naming.name_prefix(), "Tweetonium");
+        assert_eq!(naming.base_uri(), "https://arweave.net/abc");
+
+        assert!(!NftNaming::new("", "").unwrap().is_enabled());
+        assert!(NftNaming::new("Tweetonium", "").is_err());
+        assert!(NftNaming::new("", "https://arweave.net/abc").is_err());
+        assert!(NftNaming::new(&"x".repeat(MAX_NFT_NAME_PREFIX_LEN + 1), "https://a").is_err());
+        assert!(NftNaming::new("x", &"x".repeat(MAX_NFT_BASE_URI_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_nft_naming_name_and_uri() {
+        let naming = NftNaming::new("Tweetonium", "https://arweave.net/abc").unwrap();
+        assert_eq!(naming.name(42), "Tweetonium #42");
+        assert_eq!(naming.uri(42), "https://arweave.net/abc/42.json");
+    }
+}
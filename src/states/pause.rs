@@ -0,0 +1,88 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError};
+
+/// Operations that can be halted by the project or trait emergency switch.
+///
+/// Stored as a bitmask in `ProjectV1::paused` / `TraitItemV1::paused`; a set
+/// bit makes the matching instruction fail until it is cleared again.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum PauseFlag {
+    /// Halts `mint_user_v1` and `mint_user_batch_v1` (and `mint_trait_v1` for trait items).
+    MintUser = 1,
+
+    /// Halts `mint_vip_v1`.
+    MintVip = 2,
+
+    /// Halts `mint_admin_v1` and `mint_admin_airdrop_v1`.
+    MintAdmin = 4,
+
+    /// Halts `update_nft_v1`.
+    UpdateNft = 8,
+
+    /// Halts `burn_and_refund_v1`.
+    BurnAndRefund = 16,
+
+    /// Halts `transfer_to_vault_v1`.
+    TransferToVault = 32,
+}
+
+impl PauseFlag {
+    /// Bitmask containing every defined flag.
+    pub const ALL: u8 = Self::MintUser as u8
+        | Self::MintVip as u8
+        | Self::MintAdmin as u8
+        | Self::UpdateNft as u8
+        | Self::BurnAndRefund as u8
+        | Self::TransferToVault as u8;
+
+    #[inline(always)]
+    pub fn mask(self) -> u8 {
+        self as u8
+    }
+
+    /// `0` is valid and clears every pause.
+    #[inline(always)]
+    pub fn is_valid_mask(paused: u8) -> bool {
+        paused & !Self::ALL == 0
+    }
+
+    /// Fails with `Custom(8)` when `self` is set in `paused`.
+    #[inline(always)]
+    pub fn check(self, paused: u8) -> ProgramResult {
+        if paused & self.mask() != 0 {
+            msg!("{:?} is paused", self);
+            return Err(ProgramError::Custom(8));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_pause_flag_check() {
+        let paused = PauseFlag::MintUser.mask() | PauseFlag::BurnAndRefund.mask();
+
+        assert_eq!(
+            PauseFlag::MintUser.check(paused).unwrap_err(),
+            ProgramError::Custom(8)
+        );
+        assert!(PauseFlag::BurnAndRefund.check(paused).is_err());
+        assert!(PauseFlag::MintVip.check(paused).is_ok());
+        assert!(PauseFlag::MintUser.check(0).is_ok());
+    }
+
+    #[test]
+    fn test_pause_flag_is_valid_mask() {
+        assert!(PauseFlag::is_valid_mask(0));
+        assert!(PauseFlag::is_valid_mask(PauseFlag::ALL));
+        assert!(!PauseFlag::is_valid_mask(1 << 6));
+    }
+}
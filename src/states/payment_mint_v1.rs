@@ -0,0 +1,227 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::{PriceSplit, ProjectV1, MAX_REVENUE_WALLETS},
+    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount},
+};
+
+/// Additional payment currency accepted by a project.
+///
+/// Lets `mint_user_v1` charge in `mint` instead of `ProjectV1::mint`, while
+/// sharing the project's supply, per-user counter and revenue wallets.
+/// Created (or updated) by `set_payment_mint_v1` and closed by
+/// `remove_payment_mint_v1`.
+///
+/// PDA seed: `[program_id, "payment_mint_v1", project_pda, mint]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct PaymentMintV1 {
+    /// The `ProjectV1` PDA this payment mint belongs to.
+    pub project: Pubkey,
+
+    /// The SPL token mint accepted as payment.
+    pub mint: Pubkey,
+
+    /// Decimals of `mint`, read from the mint account when set.
+    pub mint_decimals: u8,
+
+    /// Full mint price in raw units of `mint`.
+    ///
+    /// - `escrow_amount + sum(revenue_shares) + protocol cut` must equal this value.
+    /// - Scaled like `ProjectV1::mint_price_total` under dynamic pricing.
+    pub mint_price_total: u64,
+
+    /// Portion of `mint_price_total` escrowed in the NFT vault.
+    pub escrow_amount: u64,
+
+    /// Amount each `ProjectV1::revenue_wallets` entry receives, by index.
+    pub revenue_shares: [u64; 5],
+
+    /// UNIX timestamp from which mints may pay with `mint`.
+    ///
+    /// - Set to `now + project.update_delay_secs` on every change, so price
+    ///   changes honour the project timelock.
+    pub active_from_ts: i64,
+}
+
+impl PaymentMintV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 15] = b"payment_mint_v1";
+}
+
+impl PaymentMintV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitPaymentMintAccounts<'a, 'info>,
+        args: InitPaymentMintArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let payment_mint = Self::load_mut(&mut bytes)?;
+        payment_mint.project = args.project;
+        payment_mint.mint = args.mint;
+        payment_mint.mint_decimals = 0;
+        payment_mint.mint_price_total = 0;
+        payment_mint.escrow_amount = 0;
+        payment_mint.revenue_shares = [0; MAX_REVENUE_WALLETS];
+        payment_mint.active_from_ts = i64::MAX;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn init_if_needed<'a, 'info>(
+        accounts: InitPaymentMintAccounts<'a, 'info>,
+        args: InitPaymentMintArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        if UninitializedAccount::check(pda_accounts.pda).is_ok() {
+            Self::init(accounts, args, pda_accounts, pda_args)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load payment mint with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut payment mint with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl PaymentMintV1 {
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.active_from_ts
+    }
+
+    #[inline(always)]
+    pub fn need_vault(&self) -> bool {
+        self.escrow_amount > 0
+    }
+
+    /// Splits a mint priced at `price` (in `ProjectV1::mint` units) into
+    /// amounts of this mint, keeping the ratio of `price` to the project's
+    /// `mint_price_total`.
+    #[inline(always)]
+    pub fn price_split(&self, project: &ProjectV1, price: u64) -> Result<PriceSplit, ProgramError> {
+        let protocol_fee =
+            ProjectV1::protocol_fee_for(self.mint_price_total, project.protocol_fee_bps)?;
+
+        Ok(PriceSplit {
+            escrow_amount: self.escrow_amount,
+            revenue_shares: self.revenue_shares,
+            protocol_fee,
+        }
+        .scaled(price, project.mint_price_total))
+    }
+}
+
+pub struct InitPaymentMintAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitPaymentMintArgs {
+    pub project: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn mock_project(mint_price_total: u64, protocol_fee_bps: u16) -> ProjectV1 {
+        let mut data = vec![0u8; ProjectV1::LEN];
+        let project = ProjectV1::load_mut(&mut data).unwrap();
+        project.mint_price_total = mint_price_total;
+        project.protocol_fee_bps = protocol_fee_bps;
+        *project
+    }
+
+    fn mock_payment_mint() -> PaymentMintV1 {
+        PaymentMintV1 {
+            project: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            mint_decimals: 9,
+            mint_price_total: 2_000,
+            escrow_amount: 1_000,
+            revenue_shares: [800, 0, 0, 0, 0],
+            active_from_ts: 100,
+        }
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_payment_mint_load_invalid_length() {
+        let mut bad = vec![0u8; PaymentMintV1::LEN - 1];
+        assert!(PaymentMintV1::load(&bad).is_err());
+        assert!(PaymentMintV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_payment_mint_is_active() {
+        let payment_mint = mock_payment_mint();
+        assert!(!payment_mint.is_active(99));
+        assert!(payment_mint.is_active(100));
+    }
+
+    #[test]
+    fn test_payment_mint_price_split() {
+        let project = mock_project(30_000, 1_000);
+        let payment_mint = mock_payment_mint();
+
+        let split = payment_mint.price_split(&project, 30_000).unwrap();
+        assert_eq!(split.escrow_amount, 1_000);
+        assert_eq!(split.revenue_shares, [800, 0, 0, 0, 0]);
+        assert_eq!(split.protocol_fee, 200);
+        assert_eq!(split.total().unwrap(), 2_000);
+
+        // project price halved (e.g. Dutch auction) halves every amount
+        let split = payment_mint.price_split(&project, 15_000).unwrap();
+        assert_eq!(split.escrow_amount, 500);
+        assert_eq!(split.revenue_shares, [400, 0, 0, 0, 0]);
+        assert_eq!(split.protocol_fee, 100);
+    }
+}
@@ -0,0 +1,511 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::states::{MAX_BASIS_POINTS, MAX_REVENUE_WALLETS};
+
+/// Fixed-point scale of the exponential curve multiplier.
+const CURVE_SCALE: u128 = 1_000_000_000;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum PricingMode {
+    /// Every public mint pays `mint_price_total`.
+    Fixed = 0,
+
+    /// The public mint price decays linearly from `mint_price_total` to
+    /// `auction_floor_price` over `auction_duration_secs`.
+    DutchAuction = 1,
+
+    /// User and VIP mints pay `mint_price_total + curve_step * user_minted`.
+    LinearCurve = 2,
+
+    /// User and VIP mints pay `mint_price_total * (1 + curve_growth_bps / 10_000) ^ user_minted`.
+    ExponentialCurve = 3,
+}
+
+/// Mint pricing, stored in `ProjectV1::pricing`.
+///
+/// Part of `UpdateProjectArgs`, so changes are timelocked like the price itself.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct Pricing {
+    pub mode: PricingMode,
+
+    /// UNIX timestamp the auction starts decaying at. Before it the start price applies.
+    pub auction_start_ts: i64,
+
+    /// Seconds from `auction_start_ts` until `auction_floor_price` is reached.
+    pub auction_duration_secs: i64,
+
+    /// Final price of the auction, in raw units of `ProjectV1::mint`.
+    pub auction_floor_price: u64,
+
+    /// Price increase per minted NFT for `PricingMode::LinearCurve`, in raw units.
+    pub curve_step: u64,
+
+    /// Price growth per minted NFT for `PricingMode::ExponentialCurve`, in basis points.
+    pub curve_growth_bps: u16,
+}
+
+impl Default for Pricing {
+    fn default() -> Self {
+        Self {
+            mode: PricingMode::Fixed,
+            auction_start_ts: 0,
+            auction_duration_secs: 0,
+            auction_floor_price: 0,
+            curve_step: 0,
+            curve_growth_bps: 0,
+        }
+    }
+}
+
+impl Pricing {
+    #[inline(always)]
+    pub fn check(&self, start_price: u64) -> Result<(), ProgramError> {
+        match self.mode {
+            PricingMode::Fixed => Ok(()),
+            PricingMode::DutchAuction
+                if self.auction_duration_secs <= 0 || self.auction_floor_price > start_price =>
+            {
+                msg!(
+                    "Dutch auction needs a positive duration and a floor price <= mint_price_total"
+                );
+                Err(ProgramError::InvalidInstructionData)
+            }
+            PricingMode::LinearCurve if self.curve_step == 0 || start_price == 0 => {
+                msg!("Linear curve needs a start price and a non-zero curve_step");
+                Err(ProgramError::InvalidInstructionData)
+            }
+            PricingMode::ExponentialCurve
+                if self.curve_growth_bps == 0
+                    || self.curve_growth_bps > MAX_BASIS_POINTS
+                    || start_price == 0 =>
+            {
+                msg!("Exponential curve needs a start price and 0 < curve_growth_bps <= 10_000");
+                Err(ProgramError::InvalidInstructionData)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_bonding_curve(&self) -> bool {
+        matches!(
+            self.mode,
+            PricingMode::LinearCurve | PricingMode::ExponentialCurve
+        )
+    }
+
+    /// Price of a mint at `now`, starting from `start_price`.
+    #[inline(always)]
+    pub fn price_at(&self, start_price: u64, now: i64) -> u64 {
+        if self.mode != PricingMode::DutchAuction || self.auction_duration_secs <= 0 {
+            return start_price;
+        }
+
+        let elapsed = now
+            .saturating_sub(self.auction_start_ts)
+            .clamp(0, self.auction_duration_secs);
+        let decay = start_price.saturating_sub(self.auction_floor_price) as u128 * elapsed as u128
+            / self.auction_duration_secs as u128;
+
+        start_price - decay as u64
+    }
+
+    /// Price of the next mint once `minted` NFTs were sold, starting from `start_price`.
+    #[inline(always)]
+    pub fn curve_price(&self, start_price: u64, minted: u64) -> Result<u64, ProgramError> {
+        let price = match self.mode {
+            PricingMode::LinearCurve => self
+                .curve_step
+                .checked_mul(minted)
+                .and_then(|increase| increase.checked_add(start_price)),
+            PricingMode::ExponentialCurve => {
+                let growth = CURVE_SCALE
+                    + CURVE_SCALE * self.curve_growth_bps as u128 / MAX_BASIS_POINTS as u128;
+
+                Self::pow_scaled(growth, minted)
+                    .and_then(|multiplier| (start_price as u128).checked_mul(multiplier))
+                    .and_then(|price| u64::try_from(price / CURVE_SCALE).ok())
+            }
+            _ => Some(start_price),
+        };
+
+        price
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Bonding curve price overflow at {} minted", minted))
+    }
+
+    /// `base ^ exp` for a `CURVE_SCALE` fixed-point `base`.
+    #[inline(always)]
+    fn pow_scaled(mut base: u128, mut exp: u64) -> Option<u128> {
+        let mut result = CURVE_SCALE;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)? / CURVE_SCALE;
+            }
+
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)? / CURVE_SCALE;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+/// Amounts a single mint pays to each destination.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PriceSplit {
+    pub escrow_amount: u64,
+    pub revenue_shares: [u64; MAX_REVENUE_WALLETS],
+    pub protocol_fee: u64,
+}
+
+impl PriceSplit {
+    /// Scales `amount` by `price / total`, rounding down.
+    #[inline(always)]
+    pub fn scale(amount: u64, price: u64, total: u64) -> u64 {
+        if total == 0 {
+            return amount;
+        }
+
+        (amount as u128 * price as u128 / total as u128) as u64
+    }
+
+    /// Scales every amount by `price / total`, rounding down.
+    #[inline(always)]
+    pub fn scaled(&self, price: u64, total: u64) -> Self {
+        if price == total {
+            return *self;
+        }
+
+        Self {
+            escrow_amount: Self::scale(self.escrow_amount, price, total),
+            revenue_shares: self
+                .revenue_shares
+                .map(|share| Self::scale(share, price, total)),
+            protocol_fee: Self::scale(self.protocol_fee, price, total),
+        }
+    }
+
+    /// Price actually paid: the sum of every destination.
+    #[inline(always)]
+    pub fn total(&self) -> Result<u64, ProgramError> {
+        self.revenue_shares
+            .iter()
+            .try_fold(self.escrow_amount, |sum, share| sum.checked_add(*share))
+            .and_then(|sum| sum.checked_add(self.protocol_fee))
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Mint price split overflow"))
+    }
+
+    /// Sums two splits, e.g. to aggregate the transfers of a batch mint.
+    #[inline(always)]
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ProgramError> {
+        let overflow = || {
+            msg!("Mint price split overflow");
+            ProgramError::ArithmeticOverflow
+        };
+        let mut revenue_shares = [0u64; MAX_REVENUE_WALLETS];
+
+        for (index, share) in revenue_shares.iter_mut().enumerate() {
+            *share = self.revenue_shares[index]
+                .checked_add(other.revenue_shares[index])
+                .ok_or_else(overflow)?;
+        }
+
+        Ok(Self {
+            escrow_amount: self
+                .escrow_amount
+                .checked_add(other.escrow_amount)
+                .ok_or_else(overflow)?,
+            revenue_shares,
+            protocol_fee: self
+                .protocol_fee
+                .checked_add(other.protocol_fee)
+                .ok_or_else(overflow)?,
+        })
+    }
+
+    /// Slippage guard: fails with `Custom(12)` when the price exceeds `max_price`.
+    #[inline(always)]
+    pub fn check_max_price(&self, max_price: u64) -> Result<(), ProgramError> {
+        let price = self.total()?;
+
+        if price > max_price {
+            msg!("Mint price {} exceeds max_price {}", price, max_price);
+            return Err(ProgramError::Custom(12));
+        }
+
+        Ok(())
+    }
+
+    /// Lowers the price by up to `discount`, taken from the revenue shares only.
+    ///
+    /// The cut is spread pro rata over the shares and capped at their sum.
+    /// Returns the discount actually applied.
+    #[inline(always)]
+    pub fn apply_discount(&mut self, discount: u64) -> Result<u64, ProgramError> {
+        let revenue = self
+            .revenue_shares
+            .iter()
+            .try_fold(0u64, |sum, share| sum.checked_add(*share))
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Mint price split overflow"))?;
+        let discount = discount.min(revenue);
+
+        if discount == 0 {
+            return Ok(0);
+        }
+
+        let mut remaining = discount;
+
+        for share in self.revenue_shares.iter_mut() {
+            let cut = (*share as u128 * discount as u128 / revenue as u128) as u64;
+            *share -= cut;
+            remaining -= cut;
+        }
+
+        // rounding leftovers, taken in wallet order
+        for share in self.revenue_shares.iter_mut() {
+            let cut = remaining.min(*share);
+            *share -= cut;
+            remaining -= cut;
+        }
+
+        Ok(discount)
+    }
+
+    /// Diverts `bps` of every revenue share to a referrer and returns the total cut.
+    ///
+    /// The price paid stays the same; escrow and protocol fee are untouched.
+    #[inline(always)]
+    pub fn take_referral(&mut self, bps: u16) -> Result<u64, ProgramError> {
+        if bps > MAX_BASIS_POINTS {
+            msg!("Referral bps {} exceeds {}", bps, MAX_BASIS_POINTS);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut referral = 0u64;
+
+        for share in self.revenue_shares.iter_mut() {
+            let cut = (*share as u128 * bps as u128 / MAX_BASIS_POINTS as u128) as u64;
+            *share -= cut;
+            referral = referral
+                .checked_add(cut)
+                .ok_or(ProgramError::ArithmeticOverflow)
+                .inspect_err(|_| msg!("Referral amount overflow"))?;
+        }
+
+        Ok(referral)
+    }
+
+    #[inline(always)]
+    pub fn revenue_share(&self, index: usize) -> Result<u64, ProgramError> {
+        self.revenue_shares
+            .get(index)
+            .cloned()
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn auction(floor_price: u64) -> Pricing {
+        Pricing {
+            mode: PricingMode::DutchAuction,
+            auction_start_ts: 1_000,
+            auction_duration_secs: 100,
+            auction_floor_price: floor_price,
+            ..Pricing::default()
+        }
+    }
+
+    fn curve(mode: PricingMode, curve_step: u64, curve_growth_bps: u16) -> Pricing {
+        Pricing {
+            mode,
+            curve_step,
+            curve_growth_bps,
+            ..Pricing::default()
+        }
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_pricing_check() {
+        assert!(Pricing::default().check(0).is_ok());
+        assert!(auction(500).check(1_000).is_ok());
+        assert!(auction(1_000).check(1_000).is_ok());
+        assert!(auction(1_001).check(1_000).is_err());
+
+        let no_duration = Pricing {
+            auction_duration_secs: 0,
+            ..auction(500)
+        };
+        assert!(no_duration.check(1_000).is_err());
+    }
+
+    #[test]
+    fn test_price_at() {
+        assert_eq!(Pricing::default().price_at(1_000, 5_000), 1_000);
+
+        let pricing = auction(200);
+        assert_eq!(pricing.price_at(1_000, 0), 1_000);
+        assert_eq!(pricing.price_at(1_000, 1_000), 1_000);
+        assert_eq!(pricing.price_at(1_000, 1_050), 600);
+        assert_eq!(pricing.price_at(1_000, 1_100), 200);
+        assert_eq!(pricing.price_at(1_000, i64::MAX), 200);
+    }
+
+    #[test]
+    fn test_bonding_curve_check() {
+        assert!(curve(PricingMode::LinearCurve, 1, 0).check(100).is_ok());
+        assert!(curve(PricingMode::LinearCurve, 0, 0).check(100).is_err());
+        assert!(curve(PricingMode::LinearCurve, 1, 0).check(0).is_err());
+
+        assert!(curve(PricingMode::ExponentialCurve, 0, 100)
+            .check(100)
+            .is_ok());
+        assert!(curve(PricingMode::ExponentialCurve, 0, 0)
+            .check(100)
+            .is_err());
+        assert!(curve(PricingMode::ExponentialCurve, 0, 100)
+            .check(0)
+            .is_err());
+        assert!(
+            curve(PricingMode::ExponentialCurve, 0, MAX_BASIS_POINTS + 1)
+                .check(100)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_curve_price() {
+        let linear = curve(PricingMode::LinearCurve, 250, 0);
+        assert_eq!(linear.curve_price(1_000, 0).unwrap(), 1_000);
+        assert_eq!(linear.curve_price(1_000, 4).unwrap(), 2_000);
+        assert!(linear.curve_price(1_000, u64::MAX).is_err());
+
+        // +10% per mint
+        let exponential = curve(PricingMode::ExponentialCurve, 0, 1_000);
+        assert_eq!(exponential.curve_price(1_000_000, 0).unwrap(), 1_000_000);
+        assert_eq!(exponential.curve_price(1_000_000, 1).unwrap(), 1_100_000);
+        assert_eq!(exponential.curve_price(1_000_000, 2).unwrap(), 1_210_000);
+        assert_eq!(exponential.curve_price(1_000_000, 10).unwrap(), 2_593_742);
+        assert!(exponential.curve_price(1_000_000, 1_000).is_err());
+
+        // +1% per mint: fixed-point rounding only ever lowers the price slightly
+        let slow = curve(PricingMode::ExponentialCurve, 0, 100);
+        let price = slow.curve_price(1_000_000, 1_000).unwrap();
+        assert!(price <= 20_959_155_637 && price > 20_959_000_000);
+
+        assert_eq!(Pricing::default().curve_price(1_000, 50).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_check_max_price() {
+        let split = PriceSplit {
+            escrow_amount: 10,
+            revenue_shares: [5, 0, 0, 0, 0],
+            protocol_fee: 0,
+        };
+
+        assert!(split.check_max_price(15).is_ok());
+        assert_eq!(split.check_max_price(14), Err(ProgramError::Custom(12)));
+    }
+
+    #[test]
+    fn test_price_split_total() {
+        let split = PriceSplit {
+            escrow_amount: 10,
+            revenue_shares: [1, 2, 3, 0, 0],
+            protocol_fee: 4,
+        };
+        assert_eq!(split.total().unwrap(), 20);
+
+        let overflow = PriceSplit {
+            protocol_fee: u64::MAX,
+            ..split
+        };
+        assert!(overflow.total().is_err());
+
+        assert_eq!(
+            PriceSplit::scale(15_000_000, 15_000_000, 30_000_000),
+            7_500_000
+        );
+        assert_eq!(PriceSplit::scale(u64::MAX, 1, 2), u64::MAX / 2);
+    }
+
+    #[test]
+    fn test_price_split_checked_add() {
+        let split = PriceSplit {
+            escrow_amount: 10,
+            revenue_shares: [1, 2, 3, 0, 0],
+            protocol_fee: 4,
+        };
+
+        let sum = PriceSplit::default()
+            .checked_add(&split)
+            .and_then(|sum| sum.checked_add(&split))
+            .unwrap();
+        assert_eq!(sum.escrow_amount, 20);
+        assert_eq!(sum.revenue_shares, [2, 4, 6, 0, 0]);
+        assert_eq!(sum.protocol_fee, 8);
+
+        let overflow = PriceSplit {
+            revenue_shares: [0, 0, 0, 0, u64::MAX],
+            ..split
+        };
+        assert!(overflow.checked_add(&overflow).is_err());
+    }
+
+    #[test]
+    fn test_price_split_take_referral() {
+        let mut split = PriceSplit {
+            escrow_amount: 10,
+            revenue_shares: [1_000, 333, 0, 0, 0],
+            protocol_fee: 4,
+        };
+        let total = split.total().unwrap();
+
+        assert_eq!(split.take_referral(1_000).unwrap(), 133);
+        assert_eq!(split.revenue_shares, [900, 300, 0, 0, 0]);
+        assert_eq!(split.escrow_amount, 10);
+        assert_eq!(split.protocol_fee, 4);
+        assert_eq!(split.total().unwrap() + 133, total);
+
+        assert_eq!(split.take_referral(0).unwrap(), 0);
+        assert!(split.take_referral(MAX_BASIS_POINTS + 1).is_err());
+    }
+
+    #[test]
+    fn test_price_split_apply_discount() {
+        let mut split = PriceSplit {
+            escrow_amount: 10,
+            revenue_shares: [5_000_000, 10_000_000, 0, 0, 0],
+            protocol_fee: 4,
+        };
+
+        assert_eq!(split.apply_discount(3_000_001).unwrap(), 3_000_001);
+        assert_eq!(split.revenue_shares, [3_999_999, 8_000_000, 0, 0, 0]);
+        assert_eq!(split.escrow_amount, 10);
+        assert_eq!(split.protocol_fee, 4);
+
+        // capped at the revenue portion
+        assert_eq!(split.apply_discount(u64::MAX).unwrap(), 11_999_999);
+        assert_eq!(split.revenue_shares, [0; 5]);
+        assert_eq!(split.total().unwrap(), 14);
+
+        assert_eq!(split.apply_discount(100).unwrap(), 0);
+    }
+}
@@ -0,0 +1,235 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::mem::transmute;
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount};
+
+/// Delegated permissions a project admin can hand out to other wallets.
+///
+/// Stored as a bitmask in `ProjectRoleV1::roles`, so a single wallet may hold
+/// several roles at once. The project admin implicitly holds every role.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum ProjectRole {
+    /// May call `mint_admin_v1` (team allocations, reserved supply).
+    Minter = 1,
+
+    /// May call `update_project_v1` (supply, limits, pricing, vesting, collection metadata).
+    ConfigManager = 2,
+
+    /// May call `force_unlock_vesting_v1`.
+    VestingManager = 4,
+
+    /// Must co-sign any change to revenue wallets or revenue shares.
+    Treasurer = 8,
+}
+
+impl ProjectRole {
+    /// Bitmask containing every defined role.
+    pub const ALL: u8 = Self::Minter as u8
+        | Self::ConfigManager as u8
+        | Self::VestingManager as u8
+        | Self::Treasurer as u8;
+
+    #[inline(always)]
+    pub fn mask(self) -> u8 {
+        self as u8
+    }
+
+    #[inline(always)]
+    pub fn is_valid_mask(roles: u8) -> bool {
+        roles != 0 && roles & !Self::ALL == 0
+    }
+}
+
+/// Per-wallet role assignment within a single project.
+///
+/// Created by `grant_project_role_v1` and closed by `revoke_project_role_v1`
+/// once the last role is removed. Privileged instructions accept either the
+/// project admin or a wallet whose role account carries the required role.
+///
+/// PDA seed: `[program_id, "project_role_v1", project_pda, wallet]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct ProjectRoleV1 {
+    /// The `ProjectV1` PDA this assignment belongs to.
+    pub project: Pubkey,
+
+    /// The wallet receiving the delegated roles.
+    pub wallet: Pubkey,
+
+    /// Bitmask of `ProjectRole` values held by `wallet`.
+    pub roles: u8,
+}
+
+impl ProjectRoleV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 15] = b"project_role_v1";
+}
+
+impl ProjectRoleV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitProjectRoleAccounts<'a, 'info>,
+        args: InitProjectRoleArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let project_role = Self::load_mut(&mut bytes)?;
+        project_role.project = args.project;
+        project_role.wallet = args.wallet;
+        project_role.roles = 0;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn init_if_needed<'a, 'info>(
+        accounts: InitProjectRoleAccounts<'a, 'info>,
+        args: InitProjectRoleArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        if UninitializedAccount::check(pda_accounts.pda).is_ok() {
+            Self::init(accounts, args, pda_accounts, pda_args)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load project role with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut project role with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl ProjectRoleV1 {
+    #[inline(always)]
+    pub fn has_role(&self, role: ProjectRole) -> bool {
+        self.roles & role.mask() != 0
+    }
+
+    #[inline(always)]
+    pub fn has_no_roles(&self) -> bool {
+        self.roles == 0
+    }
+
+    #[inline(always)]
+    pub fn grant(&mut self, roles: u8) {
+        self.roles |= roles & ProjectRole::ALL;
+    }
+
+    #[inline(always)]
+    pub fn revoke(&mut self, roles: u8) {
+        self.roles &= !roles;
+    }
+}
+
+pub struct InitProjectRoleAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitProjectRoleArgs {
+    pub project: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn zero_project_role() -> Vec<u8> {
+        vec![0u8; ProjectRoleV1::LEN]
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_project_role_load_and_load_mut() {
+        let mut data = zero_project_role();
+        let role_mut = ProjectRoleV1::load_mut(&mut data).unwrap();
+        role_mut.wallet = Pubkey::new_unique();
+        role_mut.roles = ProjectRole::Minter.mask();
+
+        let role_ref = ProjectRoleV1::load(&data).unwrap();
+        assert!(role_ref.has_role(ProjectRole::Minter));
+    }
+
+    #[test]
+    fn test_project_role_load_invalid_length() {
+        let mut bad = vec![0u8; ProjectRoleV1::LEN - 1];
+        assert!(ProjectRoleV1::load(&bad).is_err());
+        assert!(ProjectRoleV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_project_role_grant_and_revoke() {
+        let mut sut = ProjectRoleV1 {
+            project: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            roles: 0,
+        };
+
+        sut.grant(ProjectRole::Minter.mask() | ProjectRole::Treasurer.mask());
+        assert!(sut.has_role(ProjectRole::Minter));
+        assert!(sut.has_role(ProjectRole::Treasurer));
+        assert!(!sut.has_role(ProjectRole::ConfigManager));
+
+        sut.revoke(ProjectRole::Minter.mask());
+        assert!(!sut.has_role(ProjectRole::Minter));
+        assert!(sut.has_role(ProjectRole::Treasurer));
+
+        sut.revoke(ProjectRole::ALL);
+        assert!(sut.has_no_roles());
+    }
+
+    #[test]
+    fn test_project_role_is_valid_mask() {
+        assert!(ProjectRole::is_valid_mask(
+            ProjectRole::VestingManager.mask()
+        ));
+        assert!(ProjectRole::is_valid_mask(ProjectRole::ALL));
+        assert!(!ProjectRole::is_valid_mask(0));
+        assert!(!ProjectRole::is_valid_mask(1 << 7));
+    }
+}
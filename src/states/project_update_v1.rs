@@ -0,0 +1,141 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::UpdateProjectArgs,
+    utils::{InitPdaAccounts, InitPdaArgs, Pda},
+};
+
+/// A queued, timelocked project config update.
+///
+/// Created by `queue_project_update_v1`, applied by `execute_project_update_v1`
+/// once `eta` has passed, or dropped by `cancel_project_update_v1`. Only one
+/// update can be pending per project; the account is closed on execute or cancel.
+///
+/// PDA seed: `[program_id, "project_update_v1", project_pda]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct ProjectUpdateV1 {
+    /// The `ProjectV1` PDA the update applies to.
+    pub project: Pubkey,
+
+    /// The wallet that queued the update and paid its rent.
+    pub queued_by: Pubkey,
+
+    /// Unix timestamp after which the update may be executed.
+    ///
+    /// - Set to `queued_at + project.update_delay_secs`.
+    pub eta: i64,
+
+    /// The config values written to the project on execution.
+    pub args: UpdateProjectArgs,
+}
+
+impl ProjectUpdateV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 17] = b"project_update_v1";
+}
+
+impl ProjectUpdateV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitProjectUpdateAccounts<'a, 'info>,
+        args: InitProjectUpdateArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let project_update = Self::load_mut(&mut bytes)?;
+        project_update.project = args.project;
+        project_update.queued_by = args.queued_by;
+        project_update.eta = args.eta;
+        project_update.args = args.args;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load project update with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut project update with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl ProjectUpdateV1 {
+    #[inline(always)]
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+}
+
+pub struct InitProjectUpdateAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitProjectUpdateArgs {
+    pub project: Pubkey,
+    pub queued_by: Pubkey,
+    pub eta: i64,
+    pub args: UpdateProjectArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_project_update_load_invalid_length() {
+        let mut bad = vec![0u8; ProjectUpdateV1::LEN - 1];
+        assert!(ProjectUpdateV1::load(&bad).is_err());
+        assert!(ProjectUpdateV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_project_update_is_ready() {
+        let mut data = vec![0u8; ProjectUpdateV1::LEN];
+        let sut = ProjectUpdateV1::load_mut(&mut data).unwrap();
+        sut.eta = 1_000;
+
+        assert!(!sut.is_ready(999));
+        assert!(sut.is_ready(1_000));
+        assert!(sut.is_ready(1_001));
+    }
+}
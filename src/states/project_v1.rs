@@ -1,13 +1,18 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use core::mem::transmute;
-use shank::ShankAccount;
+use shank::{ShankAccount, ShankType};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
 use crate::{
-    states::{VestingMode, MAX_BASIS_POINTS, MAX_REVENUE_WALLETS, MAX_ROYALTY_RECIPIENTS},
-    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount},
+    states::{
+        MetadataPolicy, MintPhase, MintPhaseArgs, MintPhaseKind, NftNaming, PriceSplit, Pricing,
+        VestingMode, VipGateMode, MAX_BASIS_POINTS, MAX_MINT_PHASES, MAX_REVENUE_WALLETS,
+        MAX_ROYALTY_RECIPIENTS,
+    },
+    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, MerkleProof, Pda, UninitializedAccount},
 };
 
 /// Global configuration account that defines minting, payment, and vesting rules
@@ -21,6 +26,10 @@ use crate::{
 ///
 /// Each `Vault` and `MintedUser` record derives from this `ProjectV1` using its PDA.
 ///
+/// Layout: the first `LEGACY_LEN` bytes are the launch layout. Every field added
+/// since is taken out of `reserved`, so `LEN` never changes again; launch-era
+/// accounts are grown once by `migrate_project_v1`.
+///
 /// PDA seed: `[program_id, "project_v1", hashed_nft_symbol, token_mint]`
 #[repr(C)]
 #[derive(Debug, Clone, Copy, ShankAccount)]
@@ -42,16 +51,16 @@ pub struct ProjectV1 {
     /// - Used to normalize on-chain arithmetic and enforce exact token amounts.
     pub mint_decimals: u8,
 
-    /// The absolute cap on NFTs that can ever be created (Mac number of admin_minted + user_minted).
+    /// The absolute cap on NFTs that can ever be created (Mac number of admin_minted + user_minted + vip_minted).
     ///
     /// - This is the global maximum supply for the collection.
     /// - `max_supply - released` is implicitly reserved for DAO/admin mints (not available to public users).
-    /// - `admin_minted + user_minted` must never exceed this value.
+    /// - `admin_minted + user_minted + vip_minted` must never exceed this value.
     pub max_supply: u64,
 
-    /// Number of NFTs made available to public users (user-mintable supply).
+    /// Number of NFTs made available to VIP and public users (user-mintable supply).
     ///
-    /// - Public/user mints are permitted only while `user_minted < released`.
+    /// - `vip_supply` of it is reserved for `mint_vip_v1`; public mints get the rest.
     /// - The difference `max_supply - released` is reserved for DAO/admin operations (e.g. team mints,
     ///   allocations, airdrops) and cannot be minted by ordinary users.
     /// - Use this field to limit how many NFTs are exposed to the public sale.
@@ -79,10 +88,10 @@ pub struct ProjectV1 {
     /// - Enforced to never exceed `max_supply - released`.
     pub admin_minted: u64,
 
-    /// Current number of NFTs minted (user mints).
+    /// Current number of NFTs minted (public user mints).
     ///
-    /// - Incremented atomically on each successful user mint.
-    /// - Enforced to never exceed `released`.
+    /// - Incremented atomically on each successful public user mint.
+    /// - Enforced to never exceed `released - vip_supply` (see `public_supply_at`).
     pub user_minted: u64,
 
     /// Defines how vesting unlocks are handled for vault redemptions.
@@ -138,11 +147,123 @@ pub struct ProjectV1 {
     /// - Indexed 0..`num_revenue_wallets`.
     /// - Must sum up (with `escrow_amount`) to ≤ `mint_price_total`.
     pub revenue_shares: [u64; 5],
+
+    /// The `MultisigV1` signer set guarding sensitive instructions.
+    ///
+    /// - `Pubkey::default()` when multisig mode is off.
+    /// - Set once by `init_multisig_v1`.
+    /// - When set, `update_project_v1` and `force_unlock_vesting_v1` only execute an approved proposal.
+    pub multisig: Pubkey,
+
+    /// Mandatory delay (seconds) between queueing and applying a config update.
+    ///
+    /// - `0` disables the timelock; `update_project_v1` then applies changes immediately.
+    /// - When non-zero, config changes go through `queue_project_update_v1`.
+    /// - Can only increase, up to `MAX_UPDATE_DELAY_SECS`.
+    pub update_delay_secs: i64,
+
+    /// Emergency switch: bitmask of `PauseFlag` operations currently halted.
+    ///
+    /// - `0` means nothing is paused.
+    /// - Set by `set_project_pause_v1`.
+    pub paused: u8,
+
+    /// Protocol cut of `mint_price_total`, in basis points, paid in `mint`.
+    ///
+    /// - Copied from `ProtocolConfigV1::protocol_fee_bps` when the project is created.
+    /// - Transferred to the protocol fee wallet's ATA on every user / VIP mint.
+    /// - `escrow_amount + sum(revenue_shares) + protocol cut` must equal `mint_price_total`.
+    pub protocol_fee_bps: u16,
+
+    /// Merkle root of the VIP allowlist (leaf = `keccak256(wallet)`, sorted pairs).
+    ///
+    /// - `[0; 32]` means no allowlist; `mint_vip_v1` is then closed.
+    /// - Set by `init_project_v1` and rotated by `update_project_v1`; timelocked together with the other `UpdateProjectArgs`.
+    /// - `mint_vip_v1` verifies the payer's proof against this root.
+    pub vip_merkle_root: [u8; 32],
+
+    /// Holder gating for VIP mints (`VipGateMode::None` disables it).
+    ///
+    /// - Set by `init_project_v1` and `update_project_v1`; timelocked together with the other `UpdateProjectArgs`.
+    /// - `mint_vip_v1` checks the extra gate account against `vip_gate`.
+    pub vip_gate_mode: VipGateMode,
+
+    /// When `true`, each gate collection asset can only be used once per project.
+    ///
+    /// - Only applies to `VipGateMode::Collection`; must be `false` with `VipGateMode::Token`.
+    pub vip_gate_single_use: bool,
+
+    /// Minimum token balance required in `VipGateMode::Token`.
+    pub vip_gate_min_amount: u64,
+
+    /// Gate mint (`VipGateMode::Token`) or gate MPL Core collection (`VipGateMode::Collection`).
+    pub vip_gate: Pubkey,
+
+    /// Number of scheduled entries in `mint_phases`.
+    ///
+    /// - `0` means no schedule: user and VIP mints are always open.
+    pub num_mint_phases: u8,
+
+    /// Mint schedule, ordered by `start_ts` and non-overlapping.
+    ///
+    /// - Set by `set_mint_phases_v1`; phases that already started can't be changed.
+    /// - `mint_user_v1` / `mint_vip_v1` need an active phase of their kind.
+    pub mint_phases: [MintPhase; 4],
+
+    /// Public mint pricing (`PricingMode::Fixed` charges `mint_price_total`).
+    ///
+    /// - With `PricingMode::DutchAuction`, `mint_user_v1` scales the escrow,
+    ///   revenue shares and protocol cut by `current price / mint_price_total`.
+    /// - Timelocked together with the other `UpdateProjectArgs`.
+    pub pricing: Pricing,
+
+    /// Program-generated NFT names and URIs, set by `set_nft_naming_v1`.
+    ///
+    /// - When enabled, user and VIP mints must send empty `name` / `uri`.
+    /// - Admin mints may still send custom metadata.
+    pub naming: NftNaming,
+
+    /// Rules for client-supplied NFT names and URIs, set by `set_metadata_policy_v1`.
+    ///
+    /// - Enforced by every mint path and `update_nft_v1`; generated names are not checked.
+    pub metadata_policy: MetadataPolicy,
+
+    /// Merkle root of the delayed-reveal manifest, committed at init.
+    ///
+    /// - Leaves are `keccak256(index_le || uri)` for every NFT index.
+    /// - `reveal_v1` only sets URIs proven against this root; all zero disables reveal.
+    pub reveal_root: [u8; 32],
+
+    /// VIP allocation carved out of `released`.
+    ///
+    /// - `mint_vip_v1` only mints while `vip_minted < vip_supply`.
+    /// - Must be ≤ `released`; timelocked together with the other `UpdateProjectArgs`.
+    pub vip_supply: u64,
+
+    /// Current number of NFTs minted by `mint_vip_v1`.
+    ///
+    /// - Enforced to never exceed `vip_supply`.
+    pub vip_minted: u64,
+
+    /// UNIX timestamp after which unused VIP supply rolls into the public bucket.
+    ///
+    /// - `0` never rolls over.
+    /// - Once passed, `mint_vip_v1` is sold out and public mints may use `released - vip_minted`.
+    pub vip_rollover_ts: i64,
+
+    /// Zeroed space for future fields.
+    ///
+    /// - New fields are carved out of the front of this array and shrink it by
+    ///   their size, keeping `LEN` unchanged for deployed accounts.
+    pub reserved: [u8; 960],
 }
 
 impl ProjectV1 {
     pub const LEN: usize = size_of::<Self>();
     pub const SEED: &[u8; 10] = b"project_v1";
+
+    /// Size of projects created before any field past `revenue_shares` existed.
+    pub const LEGACY_LEN: usize = 376;
 }
 
 impl ProjectV1 {
@@ -167,6 +288,9 @@ impl ProjectV1 {
         project.max_mint_per_vip_user = args.max_mint_per_vip_user;
         project.admin_minted = args.admin_minted;
         project.user_minted = args.user_minted;
+        project.vip_minted = args.vip_minted;
+        project.vip_supply = args.vip_supply;
+        project.vip_rollover_ts = args.vip_rollover_ts;
         project.vesting_mode = args.vesting_mode;
         project.vesting_unlock_ts = args.vesting_unlock_ts;
         project.mint_nft_fee_lamports = args.mint_nft_fee_lamports;
@@ -176,6 +300,16 @@ impl ProjectV1 {
         project.num_revenue_wallets = args.num_revenue_wallets;
         project.revenue_wallets = args.revenue_wallets;
         project.revenue_shares = args.revenue_shares;
+        project.pricing = args.pricing;
+        project.naming = NftNaming::default();
+        project.metadata_policy = MetadataPolicy::default();
+        project.reveal_root = args.reveal_root;
+        project.multisig = Pubkey::default();
+        project.update_delay_secs = 0;
+        project.paused = 0;
+        project.protocol_fee_bps = args.protocol_fee_bps;
+        project.vip_merkle_root = args.vip_merkle_root;
+        project.set_vip_gate(args.vip_gate);
 
         Ok(())
     }
@@ -241,9 +375,135 @@ impl ProjectV1 {
         self.update_nft_fee_lamports == 0
     }
 
+    #[inline(always)]
+    pub fn protocol_fee_amount(&self) -> Result<u64, ProgramError> {
+        Self::protocol_fee_for(self.mint_price_total, self.protocol_fee_bps)
+    }
+
+    #[inline(always)]
+    pub fn protocol_fee_for(
+        mint_price_total: u64,
+        protocol_fee_bps: u16,
+    ) -> Result<u64, ProgramError> {
+        if protocol_fee_bps > MAX_BASIS_POINTS {
+            msg!("Protocol fee basis points exceeds 100% (10_000)");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amount = mint_price_total as u128 * protocol_fee_bps as u128 / MAX_BASIS_POINTS as u128;
+
+        Ok(amount as u64)
+    }
+
+    /// Public mint price at `now`, following `pricing`.
+    #[inline(always)]
+    pub fn mint_price_at(&self, now: i64) -> Result<u64, ProgramError> {
+        if self.pricing.is_bonding_curve() {
+            return self.vip_mint_price();
+        }
+
+        Ok(self.pricing.price_at(self.mint_price_total, now))
+    }
+
+    /// VIP mint price: follows the bonding curve, otherwise `mint_price_total`.
+    #[inline(always)]
+    pub fn vip_mint_price(&self) -> Result<u64, ProgramError> {
+        self.pricing
+            .curve_price(self.mint_price_total, self.released_minted())
+    }
+
+    /// Splits `price` across escrow, revenue wallets and protocol cut,
+    /// proportionally to the `mint_price_total` split.
+    #[inline(always)]
+    pub fn price_split(&self, price: u64) -> Result<PriceSplit, ProgramError> {
+        Ok(PriceSplit {
+            escrow_amount: self.escrow_amount,
+            revenue_shares: self.revenue_shares,
+            protocol_fee: self.protocol_fee_amount()?,
+        }
+        .scaled(price, self.mint_price_total))
+    }
+
     #[inline(always)]
     pub fn total_minted(&self) -> u64 {
-        self.admin_minted + self.user_minted
+        self.admin_minted + self.released_minted()
+    }
+
+    /// NFTs minted from `released` by VIP and public users.
+    #[inline(always)]
+    pub fn released_minted(&self) -> u64 {
+        self.user_minted + self.vip_minted
+    }
+
+    /// 1-based index of the next NFT minted from this project.
+    #[inline(always)]
+    pub fn next_nft_index(&self) -> Result<u64, ProgramError> {
+        self.total_minted()
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    /// Resolves the name and URI of the NFT minted at `index`.
+    ///
+    /// - Naming off: the client metadata is used, checked against `metadata_policy`.
+    /// - Naming on: generated from `naming`; only `allow_custom` (admin) mints
+    ///   may override it with non-empty client metadata.
+    #[inline(always)]
+    pub fn nft_metadata(
+        &self,
+        index: u64,
+        name: &str,
+        uri: &str,
+        allow_custom: bool,
+    ) -> Result<(String, String), ProgramError> {
+        if !self.naming.is_enabled() {
+            self.metadata_policy.check(name, uri)?;
+            return Ok((name.to_string(), uri.to_string()));
+        }
+
+        let custom = !name.is_empty() || !uri.is_empty();
+
+        if custom && !allow_custom {
+            msg!("NFT name and URI are generated by the project; send them empty");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if custom {
+            self.metadata_policy.check(name, uri)?;
+            return Ok((name.to_string(), uri.to_string()));
+        }
+
+        Ok((self.naming.name(index), self.naming.uri(index)))
+    }
+
+    /// Checks the client metadata `update_nft_v1` writes to an existing NFT.
+    ///
+    /// - Naming on: error `21`, holders can't overwrite the generated name and URI.
+    /// - Reveal on: the URI is fixed by `reveal_root`, so `uri` must be empty
+    ///   (error `22`) and only the name is updated.
+    /// - Otherwise both are checked against `metadata_policy`.
+    #[inline(always)]
+    pub fn check_nft_update(&self, name: &str, uri: &str) -> ProgramResult {
+        if self.naming.is_enabled() {
+            msg!("NFT name and URI are generated by the project and can't be updated");
+            return Err(ProgramError::Custom(21));
+        }
+
+        if self.has_reveal() {
+            if !uri.is_empty() {
+                msg!("NFT URI is committed by the project reveal and can't be updated");
+                return Err(ProgramError::Custom(22));
+            }
+
+            return self.metadata_policy.check_name(name);
+        }
+
+        self.metadata_policy.check(name, uri)
+    }
+
+    #[inline(always)]
+    pub fn has_reveal(&self) -> bool {
+        self.reveal_root != [0u8; 32]
     }
 
     #[inline(always)]
@@ -262,8 +522,208 @@ impl ProjectV1 {
     }
 
     #[inline(always)]
-    pub fn user_mint_available(&self) -> bool {
-        self.user_minted < self.released
+    pub fn vip_rolled_over(&self, now: i64) -> bool {
+        self.vip_rollover_ts != 0 && now >= self.vip_rollover_ts
+    }
+
+    /// VIP bucket at `now`: `vip_supply`, shrunk to `vip_minted` after the rollover.
+    #[inline(always)]
+    pub fn vip_supply_at(&self, now: i64) -> u64 {
+        if self.vip_rolled_over(now) {
+            return self.vip_minted.min(self.vip_supply);
+        }
+
+        self.vip_supply
+    }
+
+    /// Public bucket at `now`: `released` minus the VIP bucket.
+    #[inline(always)]
+    pub fn public_supply_at(&self, now: i64) -> u64 {
+        self.released.saturating_sub(self.vip_supply_at(now))
+    }
+
+    #[inline(always)]
+    pub fn user_mint_available(&self, now: i64) -> bool {
+        self.user_minted < self.public_supply_at(now)
+    }
+
+    #[inline(always)]
+    pub fn vip_mint_available(&self, now: i64) -> bool {
+        self.vip_minted < self.vip_supply_at(now)
+    }
+
+    /// Every NFT of `released` is minted, by VIP or public users.
+    #[inline(always)]
+    pub fn released_sold_out(&self) -> bool {
+        self.released_minted() >= self.released
+    }
+
+    #[inline(always)]
+    pub fn check_vip_supply(released: u64, vip_supply: u64) -> ProgramResult {
+        if vip_supply > released {
+            msg!(
+                "VIP supply ({}) exceeds released supply ({})",
+                vip_supply,
+                released
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn has_multisig(&self) -> bool {
+        self.multisig != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn has_timelock(&self) -> bool {
+        self.update_delay_secs > 0
+    }
+
+    #[inline(always)]
+    pub fn has_vip_allowlist(&self) -> bool {
+        self.vip_merkle_root != [0u8; 32]
+    }
+
+    #[inline(always)]
+    pub fn is_vip(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        self.has_vip_allowlist()
+            && MerkleProof::verify(proof, &self.vip_merkle_root, MerkleProof::leaf(wallet))
+    }
+
+    #[inline(always)]
+    pub fn has_vip_gate(&self) -> bool {
+        self.vip_gate_mode != VipGateMode::None
+    }
+
+    #[inline(always)]
+    pub fn set_vip_gate(&mut self, args: VipGateArgs) {
+        self.vip_gate_mode = args.mode;
+        self.vip_gate_single_use = args.single_use;
+        self.vip_gate_min_amount = args.min_amount;
+        self.vip_gate = args.gate;
+    }
+
+    #[inline(always)]
+    pub fn check_vip_gate(args: &VipGateArgs) -> ProgramResult {
+        match args.mode {
+            VipGateMode::None => Ok(()),
+            _ if args.gate == Pubkey::default() => {
+                msg!("VIP gate mint / collection must be set");
+                Err(ProgramError::InvalidInstructionData)
+            }
+            VipGateMode::Token if args.min_amount == 0 => {
+                msg!("VIP token gate requires a non-zero minimum amount");
+                Err(ProgramError::InvalidInstructionData)
+            }
+            VipGateMode::Token if args.single_use => {
+                // Fungible tokens move freely between accounts, so use can't be tracked.
+                msg!("VIP token gate can't be single use");
+                Err(ProgramError::InvalidInstructionData)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn has_mint_phases(&self) -> bool {
+        self.num_mint_phases > 0
+    }
+
+    #[inline(always)]
+    pub fn scheduled_mint_phases(&self) -> &[MintPhase] {
+        let num_phases = (self.num_mint_phases as usize).min(MAX_MINT_PHASES);
+        &self.mint_phases[..num_phases]
+    }
+
+    /// Replaces the mint schedule. Phases that already started must be kept
+    /// unchanged at the same index; every other phase must start after `now`.
+    #[inline(always)]
+    pub fn set_mint_phases(&mut self, phases: &[MintPhaseArgs], now: i64) -> ProgramResult {
+        if phases.len() > MAX_MINT_PHASES {
+            msg!("Too many mint phases. Max: {}", MAX_MINT_PHASES);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut prev_end_ts = i64::MIN;
+        for (index, phase) in phases.iter().enumerate() {
+            if phase.start_ts >= phase.end_ts || phase.start_ts < prev_end_ts {
+                msg!(
+                    "Mint phase {} must start before it ends, after the previous phase",
+                    index
+                );
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            prev_end_ts = phase.end_ts;
+        }
+
+        let scheduled = self.scheduled_mint_phases();
+        if scheduled
+            .iter()
+            .skip(phases.len())
+            .any(|current| current.has_started(now))
+        {
+            msg!("Mint phases that already started can't be removed");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut mint_phases = [MintPhase::default(); MAX_MINT_PHASES];
+        for (index, phase) in phases.iter().enumerate() {
+            mint_phases[index] = match scheduled.get(index) {
+                Some(current) if current.has_started(now) => {
+                    if !current.matches(phase) {
+                        msg!("Mint phase {} already started and can't be changed", index);
+                        return Err(ProgramError::InvalidInstructionData);
+                    }
+                    *current
+                }
+                _ if phase.start_ts <= now => {
+                    msg!("Mint phase {} must start in the future", index);
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                _ => MintPhase::from(*phase),
+            };
+        }
+
+        self.mint_phases = mint_phases;
+        self.num_mint_phases = phases.len() as u8;
+
+        Ok(())
+    }
+
+    /// Counts one mint against the active phase of `kind`.
+    ///
+    /// Fails with `Custom(10)` outside a `kind` window and `Custom(11)` once
+    /// the phase cap is reached. Without a schedule minting is always open.
+    #[inline(always)]
+    pub fn consume_mint_phase(&mut self, kind: MintPhaseKind, now: i64) -> ProgramResult {
+        if !self.has_mint_phases() {
+            return Ok(());
+        }
+
+        let num_phases = self.scheduled_mint_phases().len();
+        let Some(phase) = self.mint_phases[..num_phases]
+            .iter_mut()
+            .find(|phase| phase.is_active(now) && phase.kind == kind)
+        else {
+            msg!("No {:?} mint phase is active", kind);
+            return Err(ProgramError::Custom(10));
+        };
+
+        if phase.has_reached_cap() {
+            msg!("Mint phase cap reached. Allowed: {}", phase.max_mints);
+            return Err(ProgramError::Custom(11));
+        }
+
+        phase.minted = phase
+            .minted
+            .checked_add(1)
+            .ok_or(ProgramError::InvalidInstructionData)
+            .inspect_err(|_| msg!("Unable to increment mint phase minted"))?;
+
+        Ok(())
     }
 
     #[inline(always)]
@@ -312,6 +772,16 @@ impl ProjectV1 {
         Ok(())
     }
 
+    #[inline(always)]
+    pub fn increment_vip_minted(&mut self) -> ProgramResult {
+        self.vip_minted = self
+            .vip_minted
+            .checked_add(1)
+            .inspect(|_| msg!("Unable to increment config.vip_minted"))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(())
+    }
+
     #[inline(always)]
     pub fn check_revenue_wallets(
         mint_price_total: u64,
@@ -319,10 +789,11 @@ impl ProjectV1 {
         num_revenue_wallets: u8,
         revenue_wallets: [Pubkey; MAX_REVENUE_WALLETS],
         revenue_shares: [u64; MAX_REVENUE_WALLETS],
+        protocol_fee_bps: u16,
     ) -> ProgramResult {
         let num_wallets = num_revenue_wallets as usize;
 
-        if num_wallets == 0 {
+        if num_wallets == 0 && protocol_fee_bps == 0 {
             return Ok(());
         }
 
@@ -360,14 +831,21 @@ impl ProjectV1 {
             })
             .inspect_err(|_| msg!("Overflow while summing revenue shares"))?;
 
-        let total_mint_price = escrow_amount + total_revenue_shares;
+        let protocol_fee = Self::protocol_fee_for(mint_price_total, protocol_fee_bps)?;
+
+        let total_mint_price = escrow_amount
+            .checked_add(total_revenue_shares)
+            .and_then(|total| total.checked_add(protocol_fee))
+            .ok_or(ProgramError::InvalidInstructionData)
+            .inspect_err(|_| msg!("Overflow while summing mint price"))?;
 
         if total_mint_price != mint_price_total {
             msg!(
-                "Inconsistent pricing: expected mint_price_total ({}) = escrow_amount ({}) + total DAO revenue shares ({})",
+                "Inconsistent pricing: expected mint_price_total ({}) = escrow_amount ({}) + total DAO revenue shares ({}) + protocol fee ({})",
                 mint_price_total,
                 escrow_amount,
                 total_revenue_shares,
+                protocol_fee,
             );
             return Err(ProgramError::InvalidInstructionData);
         }
@@ -425,6 +903,35 @@ impl ProjectV1 {
         Ok(())
     }
 
+    #[inline(always)]
+    pub fn update_args(&self) -> UpdateProjectArgs {
+        UpdateProjectArgs {
+            max_supply: self.max_supply,
+            released: self.released,
+            max_mint_per_user: self.max_mint_per_user,
+            max_mint_per_vip_user: self.max_mint_per_vip_user,
+            vesting_mode: self.vesting_mode,
+            vesting_unlock_ts: self.vesting_unlock_ts,
+            mint_nft_fee_lamports: self.mint_nft_fee_lamports,
+            update_nft_fee_lamports: self.update_nft_fee_lamports,
+            mint_price_total: self.mint_price_total,
+            escrow_amount: self.escrow_amount,
+            num_revenue_wallets: self.num_revenue_wallets,
+            revenue_wallets: self.revenue_wallets,
+            revenue_shares: self.revenue_shares,
+            pricing: self.pricing,
+            vip_supply: self.vip_supply,
+            vip_rollover_ts: self.vip_rollover_ts,
+            vip_merkle_root: self.vip_merkle_root,
+            vip_gate: VipGateArgs {
+                mode: self.vip_gate_mode,
+                single_use: self.vip_gate_single_use,
+                min_amount: self.vip_gate_min_amount,
+                gate: self.vip_gate,
+            },
+        }
+    }
+
     #[inline(always)]
     pub fn update(&mut self, args: UpdateProjectArgs) {
         self.max_supply = args.max_supply;
@@ -440,9 +947,24 @@ impl ProjectV1 {
         self.num_revenue_wallets = args.num_revenue_wallets;
         self.revenue_wallets = args.revenue_wallets;
         self.revenue_shares = args.revenue_shares;
+        self.pricing = args.pricing;
+        self.vip_supply = args.vip_supply;
+        self.vip_rollover_ts = args.vip_rollover_ts;
+        self.vip_merkle_root = args.vip_merkle_root;
+        self.set_vip_gate(args.vip_gate);
     }
 }
 
+/// VIP holder-gating settings, shared by `init_project_v1` and `update_project_v1`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct VipGateArgs {
+    pub mode: VipGateMode,
+    pub single_use: bool,
+    pub min_amount: u64,
+    pub gate: Pubkey,
+}
+
 pub struct InitProjectAccounts<'a, 'info> {
     pub pda: &'a AccountInfo<'info>,
 }
@@ -457,6 +979,7 @@ pub struct InitProjectArgs {
     pub max_mint_per_vip_user: u64,
     pub admin_minted: u64,
     pub user_minted: u64,
+    pub vip_minted: u64,
     pub vesting_mode: VestingMode,
     pub vesting_unlock_ts: i64,
     pub mint_nft_fee_lamports: u64,
@@ -466,8 +989,19 @@ pub struct InitProjectArgs {
     pub num_revenue_wallets: u8,
     pub revenue_wallets: [Pubkey; MAX_REVENUE_WALLETS],
     pub revenue_shares: [u64; MAX_REVENUE_WALLETS],
+    pub pricing: Pricing,
+    pub protocol_fee_bps: u16,
+    pub vip_merkle_root: [u8; 32],
+    pub vip_gate: VipGateArgs,
+    pub reveal_root: [u8; 32],
+    pub vip_supply: u64,
+    pub vip_rollover_ts: i64,
 }
 
+/// Project config fields writable through `update_project_v1` or a timelocked
+/// `ProjectUpdateV1`. Stored verbatim in the pending-update account.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, ShankType)]
 pub struct UpdateProjectArgs {
     pub max_supply: u64,
     pub released: u64,
@@ -480,14 +1014,22 @@ pub struct UpdateProjectArgs {
     pub mint_price_total: u64,
     pub escrow_amount: u64,
     pub num_revenue_wallets: u8,
-    pub revenue_wallets: [Pubkey; MAX_REVENUE_WALLETS],
-    pub revenue_shares: [u64; MAX_REVENUE_WALLETS],
+    pub revenue_wallets: [Pubkey; 5],
+    pub revenue_shares: [u64; 5],
+    pub pricing: Pricing,
+    pub vip_supply: u64,
+    pub vip_rollover_ts: i64,
+    pub vip_merkle_root: [u8; 32],
+    pub vip_gate: VipGateArgs,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::{default_pubkeys, mock_pubkeys, mock_u16s, mock_u64s};
+    use crate::{
+        states::PROJECT_V1_RESERVED_LEN,
+        utils::{default_pubkeys, mock_pubkeys, mock_u16s, mock_u64s},
+    };
 
     // --- Test Helpers ---
 
@@ -497,6 +1039,19 @@ mod tests {
 
     // --- Test Cases ---
 
+    #[test]
+    fn test_layout_extends_legacy_prefix() {
+        assert_eq!(ProjectV1::LEN, 2048);
+        assert_eq!(
+            core::mem::offset_of!(ProjectV1, multisig),
+            ProjectV1::LEGACY_LEN
+        );
+        assert_eq!(
+            core::mem::offset_of!(ProjectV1, reserved) + PROJECT_V1_RESERVED_LEN,
+            ProjectV1::LEN
+        );
+    }
+
     #[test]
     fn test_free_mint_nft_fee() {
         let buf = zero_project();
@@ -525,7 +1080,122 @@ mod tests {
         let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
         cfg.admin_minted = 3;
         cfg.user_minted = 7;
-        assert_eq!(cfg.total_minted(), 10);
+        cfg.vip_minted = 2;
+        assert_eq!(cfg.total_minted(), 12);
+        assert_eq!(cfg.released_minted(), 9);
+    }
+
+    #[test]
+    fn test_next_nft_index() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        assert_eq!(cfg.next_nft_index().unwrap(), 1);
+
+        cfg.admin_minted = 3;
+        cfg.user_minted = 7;
+        assert_eq!(cfg.next_nft_index().unwrap(), 11);
+    }
+
+    #[test]
+    fn test_nft_metadata_without_naming() {
+        let buf = zero_project();
+        let cfg = ProjectV1::load(&buf).expect("load should succeed");
+
+        let (name, uri) = cfg.nft_metadata(1, "Custom", "https://x", false).unwrap();
+        assert_eq!(name, "Custom");
+        assert_eq!(uri, "https://x");
+    }
+
+    #[test]
+    fn test_nft_metadata_with_naming() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        cfg.naming = NftNaming::new("Tweet", "https://arweave.net/abc").unwrap();
+
+        let (name, uri) = cfg.nft_metadata(7, "", "", false).unwrap();
+        assert_eq!(name, "Tweet #7");
+        assert_eq!(uri, "https://arweave.net/abc/7.json");
+
+        assert!(cfg.nft_metadata(7, "Custom", "", false).is_err());
+        assert!(cfg.nft_metadata(7, "", "https://x", false).is_err());
+
+        let (name, uri) = cfg.nft_metadata(7, "Custom", "https://x", true).unwrap();
+        assert_eq!(name, "Custom");
+        assert_eq!(uri, "https://x");
+
+        let (name, _) = cfg.nft_metadata(7, "", "", true).unwrap();
+        assert_eq!(name, "Tweet #7");
+    }
+
+    #[test]
+    fn test_nft_metadata_checks_policy() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        cfg.metadata_policy = MetadataPolicy::new(8, 0, "https://arweave.net/", "").unwrap();
+
+        let res = cfg.nft_metadata(1, "Tweet", "https://x", false);
+        assert_eq!(res, Err(ProgramError::Custom(16)));
+
+        let res = cfg.nft_metadata(1, "Tweetonium", "https://arweave.net/1", false);
+        assert_eq!(res, Err(ProgramError::Custom(14)));
+
+        // generated metadata is not subject to the policy
+        cfg.naming = NftNaming::new("Tweetonium", "https://x").unwrap();
+        assert!(cfg.nft_metadata(1, "", "", false).is_ok());
+
+        // admin overrides are
+        let res = cfg.nft_metadata(1, "Tweet", "https://x", true);
+        assert_eq!(res, Err(ProgramError::Custom(16)));
+    }
+
+    #[test]
+    fn test_check_nft_update_with_naming() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        assert!(cfg.check_nft_update("Custom", "https://x").is_ok());
+
+        cfg.metadata_policy = MetadataPolicy::new(8, 0, "https://arweave.net/", "").unwrap();
+        assert_eq!(
+            cfg.check_nft_update("Custom", "https://x"),
+            Err(ProgramError::Custom(16))
+        );
+
+        cfg.naming = NftNaming::new("Tweet", "https://arweave.net/abc").unwrap();
+        assert_eq!(
+            cfg.check_nft_update("Custom", "https://arweave.net/1"),
+            Err(ProgramError::Custom(21))
+        );
+        assert_eq!(cfg.check_nft_update("", ""), Err(ProgramError::Custom(21)));
+    }
+
+    #[test]
+    fn test_check_nft_update_with_reveal() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        cfg.metadata_policy = MetadataPolicy::new(8, 0, "https://arweave.net/", "").unwrap();
+        cfg.reveal_root = [7u8; 32];
+
+        assert_eq!(
+            cfg.check_nft_update("Custom", "https://arweave.net/1"),
+            Err(ProgramError::Custom(22))
+        );
+
+        // the name can still change, within the policy
+        assert!(cfg.check_nft_update("Custom", "").is_ok());
+        assert_eq!(
+            cfg.check_nft_update("Tweetonium", ""),
+            Err(ProgramError::Custom(14))
+        );
+    }
+
+    #[test]
+    fn test_has_reveal() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        assert!(!cfg.has_reveal());
+
+        cfg.reveal_root = [7u8; 32];
+        assert!(cfg.has_reveal());
     }
 
     #[test]
@@ -583,14 +1253,64 @@ mod tests {
         let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
         cfg.released = 40;
         cfg.user_minted = 3;
-        assert!(cfg.user_mint_available());
+        assert!(cfg.user_mint_available(0));
     }
 
     #[test]
     fn test_user_mint_unavailable() {
         let buf = zero_project();
         let cfg = ProjectV1::load(&buf).expect("load should succeed");
-        assert!(!cfg.user_mint_available());
+        assert!(!cfg.user_mint_available(0));
+    }
+
+    #[test]
+    fn test_vip_bucket_carved_out_of_released() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        cfg.released = 40;
+        cfg.vip_supply = 10;
+        cfg.user_minted = 30;
+        cfg.vip_minted = 4;
+
+        assert_eq!(cfg.public_supply_at(0), 30);
+        assert!(!cfg.user_mint_available(0));
+        assert!(cfg.vip_mint_available(0));
+        assert!(!cfg.released_sold_out());
+
+        cfg.vip_minted = 10;
+        assert!(!cfg.vip_mint_available(0));
+        assert!(cfg.released_sold_out());
+    }
+
+    #[test]
+    fn test_vip_rollover() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        cfg.released = 40;
+        cfg.vip_supply = 10;
+        cfg.vip_minted = 4;
+        cfg.user_minted = 30;
+        cfg.vip_rollover_ts = 100;
+
+        assert!(!cfg.vip_rolled_over(99));
+        assert_eq!(cfg.vip_supply_at(99), 10);
+        assert!(!cfg.user_mint_available(99));
+
+        assert!(cfg.vip_rolled_over(100));
+        assert_eq!(cfg.vip_supply_at(100), 4);
+        assert_eq!(cfg.public_supply_at(100), 36);
+        assert!(cfg.user_mint_available(100));
+        assert!(!cfg.vip_mint_available(100));
+    }
+
+    #[test]
+    fn test_check_vip_supply() {
+        assert!(ProjectV1::check_vip_supply(40, 40).is_ok());
+        assert!(ProjectV1::check_vip_supply(40, 0).is_ok());
+        assert_eq!(
+            ProjectV1::check_vip_supply(40, 41).unwrap_err(),
+            ProgramError::InvalidInstructionData
+        );
     }
 
     #[test]
@@ -683,6 +1403,7 @@ mod tests {
             num_revenue_wallets,
             wallets,
             shares,
+            0,
         )
         .expect("check_revenue_wallets should succeed");
     }
@@ -705,6 +1426,7 @@ mod tests {
             num_revenue_wallets,
             wallets,
             shares,
+            0,
         );
 
         assert!(res.is_err());
@@ -730,11 +1452,203 @@ mod tests {
             num_revenue_wallets,
             wallets,
             shares,
+            0,
         );
 
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_check_revenue_wallet_with_protocol_fee() {
+        let mint_price_total = 1000u64;
+        let escrow_amount = 200u64;
+        let num_revenue_wallets = 1u8;
+
+        let mut wallets = default_pubkeys::<MAX_REVENUE_WALLETS>();
+        wallets[0] = Pubkey::new_unique();
+
+        let mut shares = mock_u64s::<MAX_REVENUE_WALLETS>(0);
+        shares[0] = 700;
+
+        // 10% protocol cut = 100
+        ProjectV1::check_revenue_wallets(
+            mint_price_total,
+            escrow_amount,
+            num_revenue_wallets,
+            wallets,
+            shares,
+            1_000,
+        )
+        .expect("escrow + revenue + protocol cut should match");
+
+        let res = ProjectV1::check_revenue_wallets(
+            mint_price_total,
+            escrow_amount,
+            num_revenue_wallets,
+            wallets,
+            shares,
+            500,
+        );
+        assert!(res.is_err());
+
+        let res = ProjectV1::check_revenue_wallets(
+            mint_price_total,
+            escrow_amount,
+            0,
+            default_pubkeys::<MAX_REVENUE_WALLETS>(),
+            mock_u64s::<MAX_REVENUE_WALLETS>(0),
+            1_000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_protocol_fee_for() {
+        assert_eq!(ProjectV1::protocol_fee_for(30_000_000, 0).unwrap(), 0);
+        assert_eq!(
+            ProjectV1::protocol_fee_for(30_000_000, 250).unwrap(),
+            750_000
+        );
+        assert_eq!(
+            ProjectV1::protocol_fee_for(u64::MAX, 10_000).unwrap(),
+            u64::MAX
+        );
+        assert!(ProjectV1::protocol_fee_for(1_000, 10_001).is_err());
+    }
+
+    #[test]
+    fn test_set_mint_phases() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).unwrap();
+        let phase = |kind, start_ts, end_ts| MintPhaseArgs {
+            kind,
+            start_ts,
+            end_ts,
+            max_mints: 0,
+        };
+        let vip = phase(MintPhaseKind::Vip, 100, 200);
+        let public = phase(MintPhaseKind::Public, 200, 300);
+
+        cfg.set_mint_phases(&[vip, public], 50).unwrap();
+        assert_eq!(cfg.num_mint_phases, 2);
+
+        // overlapping, empty window, in the past, too many
+        assert!(cfg
+            .set_mint_phases(&[vip, phase(MintPhaseKind::Public, 150, 300)], 50)
+            .is_err());
+        assert!(cfg
+            .set_mint_phases(&[phase(MintPhaseKind::Vip, 100, 100)], 50)
+            .is_err());
+        assert!(cfg
+            .set_mint_phases(&[phase(MintPhaseKind::Vip, 40, 100)], 50)
+            .is_err());
+        assert!(cfg
+            .set_mint_phases(&[vip; MAX_MINT_PHASES + 1], 50)
+            .is_err());
+
+        // once the VIP phase started it must be kept, later phases stay editable
+        cfg.consume_mint_phase(MintPhaseKind::Vip, 150).unwrap();
+        assert!(cfg.set_mint_phases(&[public], 150).is_err());
+        assert!(cfg
+            .set_mint_phases(&[phase(MintPhaseKind::Vip, 100, 250)], 150)
+            .is_err());
+        cfg.set_mint_phases(&[vip, phase(MintPhaseKind::Public, 250, 400)], 150)
+            .unwrap();
+        assert_eq!(cfg.mint_phases[0].minted, 1);
+        assert_eq!(cfg.mint_phases[1].start_ts, 250);
+    }
+
+    #[test]
+    fn test_consume_mint_phase() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).unwrap();
+
+        // no schedule: always open
+        cfg.consume_mint_phase(MintPhaseKind::Public, 0).unwrap();
+
+        let vip = MintPhaseArgs {
+            kind: MintPhaseKind::Vip,
+            start_ts: 100,
+            end_ts: 200,
+            max_mints: 1,
+        };
+        cfg.set_mint_phases(&[vip], 50).unwrap();
+
+        assert_eq!(
+            cfg.consume_mint_phase(MintPhaseKind::Vip, 50),
+            Err(ProgramError::Custom(10))
+        );
+        assert_eq!(
+            cfg.consume_mint_phase(MintPhaseKind::Public, 150),
+            Err(ProgramError::Custom(10))
+        );
+        cfg.consume_mint_phase(MintPhaseKind::Vip, 150).unwrap();
+        assert_eq!(
+            cfg.consume_mint_phase(MintPhaseKind::Vip, 150),
+            Err(ProgramError::Custom(11))
+        );
+        assert_eq!(
+            cfg.consume_mint_phase(MintPhaseKind::Vip, 200),
+            Err(ProgramError::Custom(10))
+        );
+    }
+
+    #[test]
+    fn test_price_split() {
+        let mut buf = zero_project();
+        let cfg = ProjectV1::load_mut(&mut buf).unwrap();
+        cfg.mint_price_total = 30_000_000;
+        cfg.escrow_amount = 15_000_000;
+        cfg.revenue_shares = [9_000_000, 3_000_000, 0, 0, 0];
+        cfg.protocol_fee_bps = 1_000;
+
+        let full = cfg.price_split(30_000_000).unwrap();
+        assert_eq!(full.escrow_amount, 15_000_000);
+        assert_eq!(full.protocol_fee, 3_000_000);
+        assert_eq!(full.total().unwrap(), 30_000_000);
+
+        let half = cfg.price_split(15_000_000).unwrap();
+        assert_eq!(half.escrow_amount, 7_500_000);
+        assert_eq!(half.revenue_shares[0], 4_500_000);
+        assert_eq!(half.revenue_shares[1], 1_500_000);
+        assert_eq!(half.protocol_fee, 1_500_000);
+        assert_eq!(half.total().unwrap(), 15_000_000);
+
+        // rounding never charges more than the requested price
+        let odd = cfg.price_split(10_000_001).unwrap();
+        assert!(odd.total().unwrap() <= 10_000_001);
+    }
+
+    #[test]
+    fn test_check_vip_gate() {
+        let gate = |mode, min_amount, gate| VipGateArgs {
+            mode,
+            single_use: false,
+            min_amount,
+            gate,
+        };
+        let mint = Pubkey::new_unique();
+
+        assert!(ProjectV1::check_vip_gate(&gate(VipGateMode::None, 0, Pubkey::default())).is_ok());
+        assert!(ProjectV1::check_vip_gate(&gate(VipGateMode::Token, 1, mint)).is_ok());
+        assert!(ProjectV1::check_vip_gate(&gate(VipGateMode::Collection, 0, mint)).is_ok());
+
+        // missing gate key, zero token minimum
+        assert!(
+            ProjectV1::check_vip_gate(&gate(VipGateMode::Collection, 0, Pubkey::default()))
+                .is_err()
+        );
+        assert!(ProjectV1::check_vip_gate(&gate(VipGateMode::Token, 0, mint)).is_err());
+
+        // single use only works for collection assets
+        let single_use = |mode| VipGateArgs {
+            single_use: true,
+            ..gate(mode, 1, mint)
+        };
+        assert!(ProjectV1::check_vip_gate(&single_use(VipGateMode::Collection)).is_ok());
+        assert!(ProjectV1::check_vip_gate(&single_use(VipGateMode::Token)).is_err());
+    }
+
     #[test]
     fn test_check_nft_royalties_zero_recipients_valid() {
         let recipients = default_pubkeys::<MAX_ROYALTY_RECIPIENTS>();
@@ -810,6 +1724,16 @@ mod tests {
             num_revenue_wallets: 1,
             revenue_wallets: new_wallets,
             revenue_shares: new_shares,
+            pricing: Pricing::default(),
+            vip_supply: 10,
+            vip_rollover_ts: 987654321,
+            vip_merkle_root: [7u8; 32],
+            vip_gate: VipGateArgs {
+                mode: VipGateMode::Token,
+                single_use: false,
+                min_amount: 5,
+                gate: Pubkey::new_unique(),
+            },
         };
 
         cfg.update(args);
@@ -824,5 +1748,11 @@ mod tests {
         assert_eq!(cfg.escrow_amount, 100);
         assert_eq!(cfg.num_revenue_wallets, 1);
         assert_eq!(cfg.revenue_shares[0], 100);
+        assert_eq!(cfg.vip_supply, 10);
+        assert_eq!(cfg.vip_rollover_ts, 987654321);
+        assert_eq!(cfg.vip_merkle_root, [7u8; 32]);
+        assert_eq!(cfg.vip_gate_mode, VipGateMode::Token);
+        assert_eq!(cfg.vip_gate_min_amount, 5);
+        assert_eq!(cfg.update_args(), args);
     }
 }
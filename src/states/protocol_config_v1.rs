@@ -0,0 +1,202 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    states::MAX_BASIS_POINTS,
+    utils::{InitPdaAccounts, InitPdaArgs, Pda},
+};
+
+/// Program-wide settings owned by the protocol authority.
+///
+/// Created once by `init_protocol_config_v1` (program upgrade authority only)
+/// and changed by `update_protocol_config_v1`. Every instruction that charges
+/// a SOL protocol fee checks its `protocol_wallet` against `fee_wallet`.
+///
+/// PDA seed: `[program_id, "protocol_config_v1"]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct ProtocolConfigV1 {
+    /// The wallet allowed to update this config.
+    pub authority: Pubkey,
+
+    /// The canonical receiver of SOL protocol fees.
+    ///
+    /// - Must be a system-owned wallet.
+    pub fee_wallet: Pubkey,
+
+    /// Default `ProjectV1::mint_nft_fee_lamports` for new projects.
+    ///
+    /// - Applied by `init_project_v1` when the requested fee is `0`.
+    pub default_mint_nft_fee_lamports: u64,
+
+    /// Default `ProjectV1::update_nft_fee_lamports` for new projects.
+    ///
+    /// - Applied by `init_project_v1` when the requested fee is `0`.
+    pub default_update_nft_fee_lamports: u64,
+
+    /// Default `TraitItemV1::mint_fee_lamports` for new trait items.
+    ///
+    /// - Applied by `init_trait_v1` when the requested fee is `0`.
+    pub default_trait_mint_fee_lamports: u64,
+
+    /// Protocol cut of each project's `mint_price_total`, in basis points.
+    ///
+    /// - Copied into `ProjectV1::protocol_fee_bps` by `init_project_v1`.
+    /// - Paid in the project's SPL mint to the `fee_wallet` ATA.
+    /// - `0` disables the token cut.
+    pub protocol_fee_bps: u16,
+}
+
+impl ProtocolConfigV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 18] = b"protocol_config_v1";
+}
+
+impl ProtocolConfigV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitProtocolConfigAccounts<'a, 'info>,
+        args: InitProtocolConfigArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let config = Self::load_mut(&mut bytes)?;
+        config.authority = args.authority;
+        config.fee_wallet = args.fee_wallet;
+        config.default_mint_nft_fee_lamports = args.default_mint_nft_fee_lamports;
+        config.default_update_nft_fee_lamports = args.default_update_nft_fee_lamports;
+        config.default_trait_mint_fee_lamports = args.default_trait_mint_fee_lamports;
+        config.protocol_fee_bps = args.protocol_fee_bps;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load protocol config with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut protocol config with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl ProtocolConfigV1 {
+    #[inline(always)]
+    pub fn check_fee_wallet(fee_wallet: &AccountInfo) -> ProgramResult {
+        if *fee_wallet.key == Pubkey::default() || fee_wallet.owner != &system_program::ID {
+            msg!("Invalid protocol fee wallet {}", fee_wallet.key);
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn check_protocol_fee_bps(protocol_fee_bps: u16) -> ProgramResult {
+        if protocol_fee_bps > MAX_BASIS_POINTS {
+            msg!("Protocol fee basis points exceeds 100% (10_000)");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `fee` unless it is `0`, in which case `default` is used.
+    #[inline(always)]
+    pub fn fee_or_default(fee: u64, default: u64) -> u64 {
+        if fee == 0 {
+            default
+        } else {
+            fee
+        }
+    }
+}
+
+pub struct InitProtocolConfigAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitProtocolConfigArgs {
+    pub authority: Pubkey,
+    pub fee_wallet: Pubkey,
+    pub default_mint_nft_fee_lamports: u64,
+    pub default_update_nft_fee_lamports: u64,
+    pub default_trait_mint_fee_lamports: u64,
+    pub protocol_fee_bps: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_protocol_config_load_and_load_mut() {
+        let mut data = vec![0u8; ProtocolConfigV1::LEN];
+        let fee_wallet = Pubkey::new_unique();
+
+        let config_mut = ProtocolConfigV1::load_mut(&mut data).unwrap();
+        config_mut.fee_wallet = fee_wallet;
+        config_mut.default_mint_nft_fee_lamports = 500_000;
+
+        let config_ref = ProtocolConfigV1::load(&data).unwrap();
+        assert_eq!(config_ref.fee_wallet, fee_wallet);
+        assert_eq!(config_ref.default_mint_nft_fee_lamports, 500_000);
+    }
+
+    #[test]
+    fn test_protocol_config_load_invalid_length() {
+        let mut bad = vec![0u8; ProtocolConfigV1::LEN - 1];
+        assert!(ProtocolConfigV1::load(&bad).is_err());
+        assert!(ProtocolConfigV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_check_protocol_fee_bps() {
+        assert!(ProtocolConfigV1::check_protocol_fee_bps(0).is_ok());
+        assert!(ProtocolConfigV1::check_protocol_fee_bps(MAX_BASIS_POINTS).is_ok());
+        assert!(ProtocolConfigV1::check_protocol_fee_bps(MAX_BASIS_POINTS + 1).is_err());
+    }
+
+    #[test]
+    fn test_fee_or_default() {
+        assert_eq!(ProtocolConfigV1::fee_or_default(0, 500), 500);
+        assert_eq!(ProtocolConfigV1::fee_or_default(100, 500), 100);
+    }
+}
@@ -0,0 +1,154 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount};
+
+/// Referral kickback a referrer earned in one additional payment mint.
+///
+/// `ReferrerV1::total_earned` only sums kickbacks paid in `ProjectV1::mint`;
+/// `mint_user_v1` creates one of these on the first referred mint paid with
+/// another payment mint (including native SOL) and adds every kickback to it.
+///
+/// PDA seed: `[program_id, "referrer_earnings_v1", referrer_pda, mint]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct ReferrerEarningsV1 {
+    /// The `ReferrerV1` PDA these earnings belong to.
+    pub referrer: Pubkey,
+
+    /// The payment mint the kickbacks were paid in.
+    pub mint: Pubkey,
+
+    /// Cumulative kickback paid in raw units of `mint`.
+    pub total_earned: u64,
+}
+
+impl ReferrerEarningsV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 20] = b"referrer_earnings_v1";
+}
+
+impl ReferrerEarningsV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitReferrerEarningsAccounts<'a, 'info>,
+        args: InitReferrerEarningsArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let earnings = Self::load_mut(&mut bytes)?;
+        earnings.referrer = args.referrer;
+        earnings.mint = args.mint;
+        earnings.total_earned = 0;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn init_if_needed<'a, 'info>(
+        accounts: InitReferrerEarningsAccounts<'a, 'info>,
+        args: InitReferrerEarningsArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        if UninitializedAccount::check(pda_accounts.pda).is_ok() {
+            Self::init(accounts, args, pda_accounts, pda_args)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load referrer earnings with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut referrer earnings with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl ReferrerEarningsV1 {
+    /// Adds a kickback of `earned` raw units of `mint`.
+    #[inline(always)]
+    pub fn record(&mut self, earned: u64) -> ProgramResult {
+        self.total_earned = self
+            .total_earned
+            .checked_add(earned)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Unable to increase referrer_earnings.total_earned"))?;
+        Ok(())
+    }
+}
+
+pub struct InitReferrerEarningsAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitReferrerEarningsArgs {
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referrer_earnings_load_invalid_length() {
+        let mut bad = vec![0u8; ReferrerEarningsV1::LEN + 1];
+        assert!(ReferrerEarningsV1::load(&bad).is_err());
+        assert!(ReferrerEarningsV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_referrer_earnings_record() {
+        let mut earnings = ReferrerEarningsV1 {
+            referrer: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            total_earned: 0,
+        };
+        earnings.record(250).unwrap();
+        earnings.record(50).unwrap();
+
+        let bytes = earnings.to_bytes();
+        assert_eq!(ReferrerEarningsV1::load(&bytes).unwrap().total_earned, 300);
+
+        earnings.total_earned = u64::MAX;
+        assert_eq!(earnings.record(1), Err(ProgramError::ArithmeticOverflow));
+    }
+}
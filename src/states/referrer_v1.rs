@@ -0,0 +1,219 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount};
+
+/// Per-wallet referrer registered by the project treasurer.
+///
+/// Created (or updated) by `register_referrer_v1`. `mint_user_v1` and
+/// `mint_vip_v1` divert `referral_bps` of the revenue portion to the referrer
+/// and record the referral here for leaderboards.
+///
+/// PDA seed: `[program_id, "referrer_v1", project_pda, wallet]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct ReferrerV1 {
+    /// The `ProjectV1` PDA this referrer belongs to.
+    pub project: Pubkey,
+
+    /// The wallet receiving the referral kickback.
+    pub wallet: Pubkey,
+
+    /// Number of mints made with this referrer.
+    pub referral_count: u64,
+
+    /// Cumulative kickback paid in raw units of `ProjectV1::mint`.
+    ///
+    /// - Kickbacks paid with another payment mint are summed in that mint's
+    ///   `ReferrerEarningsV1` instead.
+    pub total_earned: u64,
+
+    /// Share of every revenue wallet payment diverted to `wallet`, in basis points.
+    pub referral_bps: u16,
+}
+
+impl ReferrerV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 11] = b"referrer_v1";
+}
+
+impl ReferrerV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitReferrerAccounts<'a, 'info>,
+        args: InitReferrerArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let referrer = Self::load_mut(&mut bytes)?;
+        referrer.project = args.project;
+        referrer.wallet = args.wallet;
+        referrer.referral_count = 0;
+        referrer.total_earned = 0;
+        referrer.referral_bps = 0;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn init_if_needed<'a, 'info>(
+        accounts: InitReferrerAccounts<'a, 'info>,
+        args: InitReferrerArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        if UninitializedAccount::check(pda_accounts.pda).is_ok() {
+            Self::init(accounts, args, pda_accounts, pda_args)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load referrer with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut referrer with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl ReferrerV1 {
+    /// Rejects a referral by one of the wallets buying the mint.
+    #[inline(always)]
+    pub fn check_not_self_referral(wallet: &Pubkey, buyers: &[&Pubkey]) -> ProgramResult {
+        if buyers.contains(&wallet) {
+            msg!("Self-referral is not allowed");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    /// Records one referred mint that paid `earned` to the referrer.
+    #[inline(always)]
+    pub fn record(&mut self, earned: u64) -> ProgramResult {
+        self.referral_count = self
+            .referral_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Unable to increment referrer.referral_count"))?;
+        self.total_earned = self
+            .total_earned
+            .checked_add(earned)
+            .ok_or(ProgramError::ArithmeticOverflow)
+            .inspect_err(|_| msg!("Unable to increase referrer.total_earned"))?;
+        Ok(())
+    }
+}
+
+pub struct InitReferrerAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitReferrerArgs {
+    pub project: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn mock_referrer(referral_count: u64, total_earned: u64) -> ReferrerV1 {
+        ReferrerV1 {
+            project: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            referral_count,
+            total_earned,
+            referral_bps: 500,
+        }
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_referrer_load_invalid_length() {
+        let mut bad = vec![0u8; ReferrerV1::LEN - 1];
+        assert!(ReferrerV1::load(&bad).is_err());
+        assert!(ReferrerV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_referrer_roundtrip() {
+        let referrer = mock_referrer(3, 1_500);
+        let bytes = referrer.to_bytes();
+        let loaded = ReferrerV1::load(&bytes).unwrap();
+
+        assert_eq!(loaded.wallet, referrer.wallet);
+        assert_eq!(loaded.referral_count, 3);
+        assert_eq!(loaded.total_earned, 1_500);
+        assert_eq!(loaded.referral_bps, 500);
+    }
+
+    #[test]
+    fn test_referrer_record() {
+        let mut referrer = mock_referrer(0, 0);
+        referrer.record(100).unwrap();
+        referrer.record(0).unwrap();
+        assert_eq!(referrer.referral_count, 2);
+        assert_eq!(referrer.total_earned, 100);
+
+        let mut full = mock_referrer(0, u64::MAX);
+        assert_eq!(full.record(1), Err(ProgramError::ArithmeticOverflow));
+    }
+
+    #[test]
+    fn test_referrer_check_not_self_referral() {
+        let referrer = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        assert!(ReferrerV1::check_not_self_referral(&referrer, &[&payer, &recipient]).is_ok());
+        assert_eq!(
+            ReferrerV1::check_not_self_referral(&payer, &[&payer, &recipient]),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            ReferrerV1::check_not_self_referral(&recipient, &[&payer, &recipient]),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -16,6 +16,9 @@ use crate::{
 /// This account acts as the primary source of truth for the collection's
 /// token distribution and pricing parameters.
 ///
+/// Layout: trait items created before `paused` existed are only `LEGACY_LEN`
+/// bytes long and are grown once by `migrate_trait_v1`.
+///
 /// PDA seed: `[program_id, "trait_item_v1", collection]`
 #[repr(C)]
 #[derive(Debug, Clone, Copy, ShankAccount)]
@@ -44,11 +47,20 @@ pub struct TraitItemV1 {
     /// - Transferred to the protocol’s treasury wallet.
     /// - Example: `500_000` lamports = 0.0005 SOL.
     pub mint_fee_lamports: u64,
+
+    /// Emergency switch: bitmask of `PauseFlag` operations currently halted.
+    ///
+    /// - Only `PauseFlag::MintUser` applies to trait items (`mint_trait_v1`).
+    /// - Set by `set_trait_pause_v1`.
+    pub paused: u8,
 }
 
 impl TraitItemV1 {
     pub const LEN: usize = size_of::<Self>();
     pub const SEED: &[u8; 13] = b"trait_item_v1";
+
+    /// Size of trait items created before `paused` existed.
+    pub const LEGACY_LEN: usize = 56;
 }
 
 impl TraitItemV1 {
@@ -68,6 +80,7 @@ impl TraitItemV1 {
         trait_item.max_supply = args.max_supply;
         trait_item.user_minted = args.user_minted;
         trait_item.mint_fee_lamports = args.mint_fee_lamports;
+        trait_item.paused = 0;
 
         Ok(())
     }
@@ -86,6 +99,16 @@ impl TraitItemV1 {
         Ok(())
     }
 
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            msg!("Load trait item account data length wrong");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
     #[inline(always)]
     pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
         if bytes.len() < Self::LEN {
@@ -224,6 +247,36 @@ mod tests {
         assert_eq!(err, ProgramError::InvalidAccountData);
     }
 
+    #[test]
+    fn test_legacy_layout_loads_unpaused() {
+        assert_eq!(
+            core::mem::offset_of!(TraitItemV1, paused),
+            TraitItemV1::LEGACY_LEN
+        );
+
+        let authority = Pubkey::new_unique();
+        let legacy = TraitItemV1 {
+            authority,
+            max_supply: 10,
+            user_minted: 5,
+            mint_fee_lamports: 1_000_000,
+            paused: 0,
+        };
+        let legacy_bytes = legacy.to_bytes()[..TraitItemV1::LEGACY_LEN].to_vec();
+        assert!(TraitItemV1::load(&legacy_bytes).is_err());
+
+        // `migrate_trait_v1` grows the account in place with zeros.
+        let mut data = legacy_bytes;
+        data.resize(TraitItemV1::LEN, 0);
+        let trait_item = TraitItemV1::load(&data).unwrap();
+
+        assert_eq!(trait_item.authority, authority);
+        assert_eq!(trait_item.max_supply, 10);
+        assert_eq!(trait_item.user_minted, 5);
+        assert_eq!(trait_item.mint_fee_lamports, 1_000_000);
+        assert_eq!(trait_item.paused, 0);
+    }
+
     #[test]
     fn test_free_mint_fee() {
         let sut = TraitItemV1 {
@@ -231,6 +284,7 @@ mod tests {
             max_supply: 10,
             user_minted: 5,
             mint_fee_lamports: 0,
+            paused: 0,
         };
 
         assert!(sut.is_free_mint_fee());
@@ -243,6 +297,7 @@ mod tests {
             max_supply: 10,
             user_minted: 5,
             mint_fee_lamports: 1_000_000,
+            paused: 0,
         };
 
         assert!(!sut.is_free_mint_fee());
@@ -255,6 +310,7 @@ mod tests {
             max_supply: 10,
             user_minted: 0,
             mint_fee_lamports: 0,
+            paused: 0,
         };
 
         assert!(sut.stock_available());
@@ -267,6 +323,7 @@ mod tests {
             max_supply: 10,
             user_minted: 10,
             mint_fee_lamports: 0,
+            paused: 0,
         };
 
         assert!(!sut.stock_available());
@@ -279,6 +336,7 @@ mod tests {
             max_supply: 10,
             user_minted: 0,
             mint_fee_lamports: 1000,
+            paused: 0,
         };
 
         assert!(sut.increment_user_minted().is_ok());
@@ -333,6 +391,7 @@ mod tests {
             max_supply: 10,
             user_minted: 0,
             mint_fee_lamports: 1000,
+            paused: 0,
         };
 
         let args = UpdateTraitItemArgs {
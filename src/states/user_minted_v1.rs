@@ -67,6 +67,16 @@ impl UserMintedV1 {
         Ok(())
     }
 
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            msg!("Load UserMinted: invalid account data length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
     #[inline(always)]
     pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
         if bytes.len() < Self::LEN {
@@ -85,6 +95,17 @@ impl UserMintedV1 {
         self.minted_count >= config.max_mint_per_user
     }
 
+    /// Whether `count` more user mints still fit under `max_mint_per_user`.
+    #[inline(always)]
+    pub fn can_mint(&self, config: &ProjectV1, count: u64) -> bool {
+        if config.max_mint_per_user == 0 {
+            return true;
+        }
+        self.minted_count
+            .checked_add(count)
+            .is_some_and(|total| total <= config.max_mint_per_user)
+    }
+
     #[inline(always)]
     pub fn has_reached_vip_limit(&self, config: &ProjectV1) -> bool {
         if config.max_mint_per_vip_user == 0 {
@@ -164,6 +185,25 @@ mod tests {
         assert!(user.has_reached_vip_limit(config));
     }
 
+    #[test]
+    fn test_user_minted_can_mint() {
+        let mut buf = zero_config();
+        let config = ProjectV1::load_mut(&mut buf).expect("load_mut should succeed");
+        config.max_mint_per_user = 5;
+
+        let user = UserMintedV1 {
+            owner: Pubkey::new_unique(),
+            minted_count: 2,
+        };
+
+        assert!(user.can_mint(config, 3));
+        assert!(!user.can_mint(config, 4));
+        assert!(!user.can_mint(config, u64::MAX));
+
+        config.max_mint_per_user = 0;
+        assert!(user.can_mint(config, u64::MAX));
+    }
+
     #[test]
     fn test_user_minted_invalid_data_length() {
         let mut short_data = vec![0u8; UserMintedV1::LEN - 1];
@@ -4,7 +4,10 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount};
+use crate::{
+    states::ProjectV1,
+    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount},
+};
 
 /// Represents the escrow state for a minted NFT and its associated SPL tokens.
 ///
@@ -13,6 +16,9 @@ use crate::utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, Uninitialize
 /// and reclaim the escrowed tokens.
 ///
 /// PDA seed: `[program_id, project_pda, payer, "vault"]`
+///
+/// Vaults created before `price_paid` and `mint` existed are `LEGACY_LEN` long;
+/// read them with `VaultV1::read`.
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct VaultV1 {
@@ -22,7 +28,8 @@ pub struct VaultV1 {
 
     /// The total amount of ZDLT tokens escrowed for this NFT (raw units).
     ///
-    /// Matches the `config.escrow_amount` at mint time.
+    /// Matches the `config.escrow_amount` at mint time, scaled to the price
+    /// actually paid under Dutch auction pricing.
     /// These tokens are locked until vesting unlocks, then refunded to `owner`
     /// when the NFT is burned.
     pub amount: u64,
@@ -39,11 +46,27 @@ pub struct VaultV1 {
     ///
     /// Stored for replay protection and deterministic PDA re-derivation.
     pub bump: [u8; 1],
+
+    /// The full price paid for this NFT (raw units), including revenue shares
+    /// and the protocol cut.
+    ///
+    /// Differs from `config.mint_price_total` when the NFT was bought during a
+    /// Dutch auction; `amount` is then scaled down by the same ratio.
+    pub price_paid: u64,
+
+    /// The SPL token mint escrowed in this vault.
+    ///
+    /// `ProjectV1::mint`, or a `PaymentMintV1::mint` when the NFT was paid
+    /// with another accepted currency. `burn_and_refund_v1` refunds in it.
+    pub mint: Pubkey,
 }
 
 impl VaultV1 {
     pub const LEN: usize = size_of::<Self>();
     pub const SEED: &[u8; 8] = b"vault_v1";
+
+    /// Size of vaults created before `price_paid` and `mint` were added.
+    pub const LEGACY_LEN: usize = 48;
 }
 
 impl VaultV1 {
@@ -63,6 +86,8 @@ impl VaultV1 {
         vault.amount = args.amount;
         vault.is_unlocked = if args.is_unlocked { 1 } else { 0 };
         vault.bump = [bump];
+        vault.price_paid = args.price_paid;
+        vault.mint = args.mint;
 
         Ok(())
     }
@@ -101,6 +126,26 @@ impl VaultV1 {
         Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
     }
 
+    /// Copies a vault of either layout out of `bytes`.
+    ///
+    /// Legacy vaults predate payment mints and Dutch auctions, so they escrow
+    /// `project.mint` bought at `project.mint_price_total`.
+    #[inline(always)]
+    pub fn read(bytes: &[u8], project: &ProjectV1) -> Result<Self, ProgramError> {
+        if bytes.len() != Self::LEGACY_LEN {
+            return Self::load(bytes).copied();
+        }
+
+        let mut padded = [0u8; Self::LEN];
+        padded[..Self::LEGACY_LEN].copy_from_slice(bytes);
+
+        let mut vault = unsafe { std::ptr::read_unaligned(padded.as_ptr() as *const Self) };
+        vault.price_paid = project.mint_price_total;
+        vault.mint = project.mint;
+
+        Ok(vault)
+    }
+
     #[inline(always)]
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = vec![0u8; Self::LEN];
@@ -130,11 +175,14 @@ pub struct InitVaultArgs {
     pub nft: Pubkey,
     pub amount: u64,
     pub is_unlocked: bool,
+    pub price_paid: u64,
+    pub mint: Pubkey,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::mem::offset_of;
 
     // --- Test Helpers ---
 
@@ -167,6 +215,42 @@ mod tests {
         assert!(VaultV1::load_mut(&mut bad).is_err());
     }
 
+    #[test]
+    fn test_vault_layout_extends_legacy_prefix() {
+        assert_eq!(offset_of!(VaultV1, price_paid), VaultV1::LEGACY_LEN);
+    }
+
+    #[test]
+    fn test_vault_read_legacy() {
+        let mut project_data = vec![0u8; ProjectV1::LEN];
+        let project = ProjectV1::load_mut(&mut project_data).unwrap();
+        project.mint = Pubkey::new_unique();
+        project.mint_price_total = 30;
+
+        let vault = VaultV1 {
+            nft: Pubkey::new_unique(),
+            amount: 15,
+            is_unlocked: 0,
+            bump: [254],
+            price_paid: 0,
+            mint: Pubkey::default(),
+        };
+        let bytes = vault.to_bytes();
+
+        let legacy = VaultV1::read(&bytes[..VaultV1::LEGACY_LEN], project).unwrap();
+        assert_eq!(legacy.nft, vault.nft);
+        assert_eq!(legacy.amount, 15);
+        assert_eq!(legacy.bump, [254]);
+        assert_eq!(legacy.price_paid, 30);
+        assert_eq!(legacy.mint, project.mint);
+
+        let current = VaultV1::read(&bytes, project).unwrap();
+        assert_eq!(current.mint, Pubkey::default());
+        assert_eq!(current.price_paid, 0);
+
+        assert!(VaultV1::read(&bytes[..VaultV1::LEGACY_LEN - 1], project).is_err());
+    }
+
     #[test]
     fn test_vault_is_unlocked() {
         let locked = VaultV1 {
@@ -174,6 +258,8 @@ mod tests {
             amount: 10,
             is_unlocked: 0,
             bump: [0],
+            price_paid: 10,
+            mint: Pubkey::new_unique(),
         };
         let unlocked = VaultV1 {
             is_unlocked: 1,
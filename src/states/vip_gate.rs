@@ -0,0 +1,15 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub enum VipGateMode {
+    /// No holder gating. VIP status comes from the Merkle allowlist or a VIP pass.
+    None = 0,
+
+    /// Payer proves VIP status with a token account holding at least `vip_gate_min_amount` of `vip_gate`.
+    Token = 1,
+
+    /// Payer proves VIP status with an MPL Core asset of collection `vip_gate` they own.
+    Collection = 2,
+}
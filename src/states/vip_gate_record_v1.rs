@@ -0,0 +1,97 @@
+use core::mem::transmute;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{InitPdaAccounts, InitPdaArgs, Pda};
+
+/// Marks a gate collection asset as spent for VIP minting in one project.
+///
+/// Created by `mint_vip_v1` when `ProjectV1::vip_gate_single_use` is set.
+/// Its existence alone blocks a second use of the same asset.
+///
+/// PDA seed: `[program_id, "vip_gate_record_v1", project_pda, gate_asset]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VipGateRecordV1 {
+    /// The `ProjectV1` PDA this record belongs to.
+    pub project: Pubkey,
+
+    /// The gate collection asset that was used.
+    pub asset: Pubkey,
+
+    /// The wallet that used the asset.
+    pub wallet: Pubkey,
+}
+
+impl VipGateRecordV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 18] = b"vip_gate_record_v1";
+}
+
+impl VipGateRecordV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitVipGateRecordAccounts<'a, 'info>,
+        args: InitVipGateRecordArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let record = Self::load_mut(&mut bytes)?;
+        record.project = args.project;
+        record.asset = args.asset;
+        record.wallet = args.wallet;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load vip gate record with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut vip gate record with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+pub struct InitVipGateRecordAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitVipGateRecordArgs {
+    pub project: Pubkey,
+    pub asset: Pubkey,
+    pub wallet: Pubkey,
+}
@@ -0,0 +1,221 @@
+use core::mem::transmute;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    states::ProjectV1,
+    utils::{AccountCheck, InitPdaAccounts, InitPdaArgs, Pda, UninitializedAccount},
+};
+
+/// Per-wallet VIP pass issued by the project admin.
+///
+/// Alternative to `ProjectV1::vip_merkle_root` for small allowlists. Created
+/// (or updated) by `grant_vip_pass_v1` and closed by `revoke_vip_pass_v1`.
+/// `mint_vip_v1` consumes one allocation from the pass per mint.
+///
+/// PDA seed: `[program_id, "vip_pass_v1", project_pda, wallet]`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ShankAccount)]
+pub struct VipPassV1 {
+    /// The `ProjectV1` PDA this pass belongs to.
+    pub project: Pubkey,
+
+    /// The wallet allowed to mint as VIP.
+    pub wallet: Pubkey,
+
+    /// Allocation override for this wallet.
+    ///
+    /// - `0` falls back to `ProjectV1::max_mint_per_vip_user`.
+    pub max_mint: u64,
+
+    /// Number of VIP mints already made with this pass.
+    pub minted: u64,
+
+    /// UNIX timestamp after which the pass can no longer be used.
+    ///
+    /// - `0` means the pass never expires.
+    pub expires_at: i64,
+}
+
+impl VipPassV1 {
+    pub const LEN: usize = size_of::<Self>();
+    pub const SEED: &[u8; 11] = b"vip_pass_v1";
+}
+
+impl VipPassV1 {
+    #[inline(always)]
+    pub fn init<'a, 'info>(
+        accounts: InitVipPassAccounts<'a, 'info>,
+        args: InitVipPassArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        Pda::new(pda_accounts, pda_args)?.init()?;
+
+        let mut bytes = accounts.pda.try_borrow_mut_data()?;
+
+        let vip_pass = Self::load_mut(&mut bytes)?;
+        vip_pass.project = args.project;
+        vip_pass.wallet = args.wallet;
+        vip_pass.max_mint = 0;
+        vip_pass.minted = 0;
+        vip_pass.expires_at = 0;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn init_if_needed<'a, 'info>(
+        accounts: InitVipPassAccounts<'a, 'info>,
+        args: InitVipPassArgs,
+        pda_accounts: InitPdaAccounts<'a, 'info>,
+        pda_args: InitPdaArgs<'a>,
+    ) -> ProgramResult {
+        if UninitializedAccount::check(pda_accounts.pda).is_ok() {
+            Self::init(accounts, args, pda_accounts, pda_args)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load vip pass with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            msg!("Load mut vip pass with wrong bytes length");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::LEN];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self as *const Self as *const u8,
+                bytes.as_mut_ptr(),
+                Self::LEN,
+            );
+        }
+
+        bytes
+    }
+}
+
+impl VipPassV1 {
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    /// Effective allocation; `0` means unlimited.
+    #[inline(always)]
+    pub fn allocation(&self, project: &ProjectV1) -> u64 {
+        if self.max_mint != 0 {
+            self.max_mint
+        } else {
+            project.max_mint_per_vip_user
+        }
+    }
+
+    #[inline(always)]
+    pub fn has_reached_limit(&self, project: &ProjectV1) -> bool {
+        let allocation = self.allocation(project);
+        allocation != 0 && self.minted >= allocation
+    }
+
+    #[inline(always)]
+    pub fn consume(&mut self) -> ProgramResult {
+        self.minted = self
+            .minted
+            .checked_add(1)
+            .inspect(|_| msg!("Unable to increment vip_pass.minted"))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(())
+    }
+}
+
+pub struct InitVipPassAccounts<'a, 'info> {
+    pub pda: &'a AccountInfo<'info>,
+}
+
+pub struct InitVipPassArgs {
+    pub project: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Helpers ---
+
+    fn mock_vip_pass(max_mint: u64, minted: u64, expires_at: i64) -> VipPassV1 {
+        VipPassV1 {
+            project: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            max_mint,
+            minted,
+            expires_at,
+        }
+    }
+
+    fn mock_project(max_mint_per_vip_user: u64) -> ProjectV1 {
+        let mut data = vec![0u8; ProjectV1::LEN];
+        let project = ProjectV1::load_mut(&mut data).unwrap();
+        project.max_mint_per_vip_user = max_mint_per_vip_user;
+        *project
+    }
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_vip_pass_load_invalid_length() {
+        let mut bad = vec![0u8; VipPassV1::LEN - 1];
+        assert!(VipPassV1::load(&bad).is_err());
+        assert!(VipPassV1::load_mut(&mut bad).is_err());
+    }
+
+    #[test]
+    fn test_vip_pass_expiry() {
+        assert!(!mock_vip_pass(0, 0, 0).is_expired(i64::MAX));
+        assert!(!mock_vip_pass(0, 0, 100).is_expired(100));
+        assert!(mock_vip_pass(0, 0, 100).is_expired(101));
+    }
+
+    #[test]
+    fn test_vip_pass_allocation() {
+        let project = mock_project(3);
+
+        // falls back to project default
+        let mut pass = mock_vip_pass(0, 2, 0);
+        assert_eq!(pass.allocation(&project), 3);
+        assert!(!pass.has_reached_limit(&project));
+        pass.consume().unwrap();
+        assert!(pass.has_reached_limit(&project));
+
+        // override
+        let pass = mock_vip_pass(5, 3, 0);
+        assert_eq!(pass.allocation(&project), 5);
+        assert!(!pass.has_reached_limit(&project));
+
+        // unlimited
+        let pass = mock_vip_pass(0, 100, 0);
+        assert!(!pass.has_reached_limit(&mock_project(0)));
+    }
+}
@@ -1,12 +1,16 @@
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, bpf_loader_upgradeable, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 
 use crate::{
-    states::{ProjectV1, VaultV1},
+    states::{
+        AdminProposalV1, CouponV1, MintRecordV1, MultisigAction, MultisigProposalV1, MultisigV1,
+        PaymentMintV1, ProjectRole, ProjectRoleV1, ProjectUpdateV1, ProjectV1, ProtocolConfigV1,
+        ReferrerEarningsV1, ReferrerV1, TraitItemV1, VaultV1, VipPassV1,
+    },
     utils::{
-        AssociatedTokenProgram, MINT_2022_MIN_LEN, MINT_LEN, TOKEN_2022_PROGRAM_ID,
+        AssociatedTokenProgram, Pda, MINT_2022_MIN_LEN, MINT_LEN, TOKEN_2022_PROGRAM_ID,
         TOKEN_ACCOUNT_2022_MIN_LEN, TOKEN_ACCOUNT_LEN, TOKEN_PROGRAM_ID,
     },
 };
@@ -24,6 +28,40 @@ pub trait AssociatedTokenAccountCheck {
     ) -> ProgramResult;
 }
 
+pub trait ProjectAuthorityCheck {
+    fn check<'info>(
+        authority: &AccountInfo<'info>,
+        project_pda: &AccountInfo<'info>,
+        project_role_pda: &AccountInfo<'info>,
+        project: &ProjectV1,
+        role: ProjectRole,
+    ) -> ProgramResult;
+}
+
+pub trait ProtocolFeeWalletCheck {
+    fn check<'info>(
+        protocol_config_pda: &AccountInfo<'info>,
+        protocol_wallet: &AccountInfo<'info>,
+    ) -> ProgramResult;
+}
+
+pub trait UpgradeAuthorityCheck {
+    fn check<'info>(
+        authority: &AccountInfo<'info>,
+        program_data: &AccountInfo<'info>,
+    ) -> ProgramResult;
+}
+
+pub trait ProjectMultisigCheck {
+    fn check<'info>(
+        multisig_proposal_pda: &AccountInfo<'info>,
+        proposer: &AccountInfo<'info>,
+        project: &ProjectV1,
+        action: MultisigAction,
+        payload: &[u8],
+    ) -> ProgramResult;
+}
+
 pub struct SignerAccount;
 
 impl AccountCheck for SignerAccount {
@@ -162,6 +200,14 @@ impl AccountCheck for ProjectAccount {
             return Err(ProgramError::InvalidAccountOwner);
         }
 
+        if account.data_len() == ProjectV1::LEGACY_LEN {
+            msg!(
+                "ProjectAccount: account {} uses the legacy layout, run MigrateProjectV1 first",
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         if account.data_len() != ProjectV1::LEN {
             msg!(
                 "ProjectAccount: invalid data length (expected {}, found {}) for account {}",
@@ -176,48 +222,661 @@ impl AccountCheck for ProjectAccount {
     }
 }
 
-pub struct VaultAccount;
+/// A project created before the `ProjectV1` extension fields, still `LEGACY_LEN` long.
+pub struct LegacyProjectAccount;
+
+impl AccountCheck for LegacyProjectAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "LegacyProjectAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ProjectV1::LEGACY_LEN {
+            msg!(
+                "LegacyProjectAccount: invalid data length (expected {}, found {}) for account {}",
+                ProjectV1::LEGACY_LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct VaultAccount;
+
+impl AccountCheck for VaultAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "VaultAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Legacy vaults are still refundable; `VaultV1::read` fills in the missing fields.
+        if account.data_len() != VaultV1::LEN && account.data_len() != VaultV1::LEGACY_LEN {
+            msg!(
+                "VaultAccount: invalid data length (expected {}, found {}) for account {}",
+                VaultV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct TraitItemAccount;
+
+impl AccountCheck for TraitItemAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "TraitItemAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != TraitItemV1::LEN {
+            msg!(
+                "TraitItemAccount: invalid data length (expected {}, found {}) for account {}",
+                TraitItemV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct LegacyTraitItemAccount;
+
+impl AccountCheck for LegacyTraitItemAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "LegacyTraitItemAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != TraitItemV1::LEGACY_LEN {
+            msg!(
+                "LegacyTraitItemAccount: invalid data length (expected {}, found {}) for account {}",
+                TraitItemV1::LEGACY_LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ProjectRoleAccount;
+
+impl AccountCheck for ProjectRoleAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "ProjectRoleAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ProjectRoleV1::LEN {
+            msg!(
+                "ProjectRoleAccount: invalid data length (expected {}, found {}) for account {}",
+                ProjectRoleV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct VipPassAccount;
+
+impl AccountCheck for VipPassAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "VipPassAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != VipPassV1::LEN {
+            msg!(
+                "VipPassAccount: invalid data length (expected {}, found {}) for account {}",
+                VipPassV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ProtocolConfigAccount;
+
+impl AccountCheck for ProtocolConfigAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "ProtocolConfigAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ProtocolConfigV1::LEN {
+            msg!(
+                "ProtocolConfigAccount: invalid data length (expected {}, found {}) for account {}",
+                ProtocolConfigV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct AdminProposalAccount;
+
+impl AccountCheck for AdminProposalAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "AdminProposalAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != AdminProposalV1::LEN {
+            msg!(
+                "AdminProposalAccount: invalid data length (expected {}, found {}) for account {}",
+                AdminProposalV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct MultisigAccount;
+
+impl AccountCheck for MultisigAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "MultisigAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != MultisigV1::LEN {
+            msg!(
+                "MultisigAccount: invalid data length (expected {}, found {}) for account {}",
+                MultisigV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct MultisigProposalAccount;
+
+impl AccountCheck for MultisigProposalAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "MultisigProposalAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != MultisigProposalV1::LEN {
+            msg!(
+                "MultisigProposalAccount: invalid data length (expected {}, found {}) for account {}",
+                MultisigProposalV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ProjectUpdateAccount;
+
+impl AccountCheck for ProjectUpdateAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "ProjectUpdateAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ProjectUpdateV1::LEN {
+            msg!(
+                "ProjectUpdateAccount: invalid data length (expected {}, found {}) for account {}",
+                ProjectUpdateV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct PaymentMintAccount;
+
+impl AccountCheck for PaymentMintAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "PaymentMintAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != PaymentMintV1::LEN {
+            msg!(
+                "PaymentMintAccount: invalid data length (expected {}, found {}) for account {}",
+                PaymentMintV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ReferrerAccount;
+
+impl AccountCheck for ReferrerAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "ReferrerAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ReferrerV1::LEN {
+            msg!(
+                "ReferrerAccount: invalid data length (expected {}, found {}) for account {}",
+                ReferrerV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ReferrerEarningsAccount;
+
+impl AccountCheck for ReferrerEarningsAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "ReferrerEarningsAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != ReferrerEarningsV1::LEN {
+            msg!(
+                "ReferrerEarningsAccount: invalid data length (expected {}, found {}) for account {}",
+                ReferrerEarningsV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct CouponAccount;
+
+impl AccountCheck for CouponAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "CouponAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != CouponV1::LEN {
+            msg!(
+                "CouponAccount: invalid data length (expected {}, found {}) for account {}",
+                CouponV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct MintRecordAccount;
+
+impl AccountCheck for MintRecordAccount {
+    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
+        if account.owner != &crate::ID {
+            msg!(
+                "MintRecordAccount: invalid owner {} (expected program {})",
+                account.owner,
+                crate::ID
+            );
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != MintRecordV1::LEN {
+            msg!(
+                "MintRecordAccount: invalid data length (expected {}, found {}) for account {}",
+                MintRecordV1::LEN,
+                account.data_len(),
+                account.key
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+pub struct AssociatedTokenAccount;
+
+impl AssociatedTokenAccountCheck for AssociatedTokenAccount {
+    fn check<'info>(
+        account: &AccountInfo<'info>,
+        wallet: &Pubkey,
+        mint: &Pubkey,
+        token_program_id: &Pubkey,
+    ) -> ProgramResult {
+        TokenAccount::check(account)?;
+        AssociatedTokenProgram::check(account, wallet, mint, token_program_id)?;
+        Ok(())
+    }
+}
+
+/// Shared authorization for every privileged project instruction.
+///
+/// Passes when `authority` signed and is either `project.admin` or holds `role`
+/// in its `["project_role_v1", project_pda, authority]` account.
+pub struct ProjectAuthority;
+
+impl ProjectAuthorityCheck for ProjectAuthority {
+    fn check<'info>(
+        authority: &AccountInfo<'info>,
+        project_pda: &AccountInfo<'info>,
+        project_role_pda: &AccountInfo<'info>,
+        project: &ProjectV1,
+        role: ProjectRole,
+    ) -> ProgramResult {
+        SignerAccount::check(authority)?;
+
+        if project.admin == *authority.key {
+            return Ok(());
+        }
+
+        Pda::validate(
+            project_role_pda,
+            &[
+                ProjectRoleV1::SEED,
+                project_pda.key.as_ref(),
+                authority.key.as_ref(),
+            ],
+            &crate::ID,
+        )?;
+
+        if ProjectRoleAccount::check(project_role_pda).is_err() {
+            msg!(
+                "Unauthorized: {} is not the project admin and holds no project role",
+                authority.key
+            );
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let role_data = project_role_pda.try_borrow_data()?;
+        let project_role = ProjectRoleV1::load(&role_data)?;
+
+        if !project_role.has_role(role) {
+            msg!(
+                "Unauthorized: {} does not hold the {:?} role",
+                authority.key,
+                role
+            );
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        Ok(())
+    }
+}
+
+impl ProjectAuthority {
+    /// Admin-only variant of `check`: `authority` must sign and be `admin`,
+    /// project roles never pass.
+    pub fn check_admin<'info>(authority: &AccountInfo<'info>, admin: &Pubkey) -> ProgramResult {
+        SignerAccount::check(authority)?;
+
+        if *authority.key != *admin {
+            msg!("Unauthorized: {} is not the admin", authority.key);
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        Ok(())
+    }
+}
+
+/// Execution gate for sensitive instructions when `ProjectV1::multisig` is set.
+///
+/// Passes when `multisig_proposal_pda` belongs to the project's signer set,
+/// authorizes `action` with exactly `payload`, and reached its threshold.
+pub struct ProjectMultisig;
+
+impl ProjectMultisigCheck for ProjectMultisig {
+    fn check<'info>(
+        multisig_proposal_pda: &AccountInfo<'info>,
+        proposer: &AccountInfo<'info>,
+        project: &ProjectV1,
+        action: MultisigAction,
+        payload: &[u8],
+    ) -> ProgramResult {
+        WritableAccount::check(multisig_proposal_pda)?;
+        WritableAccount::check(proposer)?;
+        MultisigProposalAccount::check(multisig_proposal_pda)?;
+
+        let proposal_data = multisig_proposal_pda.try_borrow_data()?;
+        let proposal = MultisigProposalV1::load(&proposal_data)?;
+
+        if proposal.multisig != project.multisig {
+            msg!("Multisig proposal does not belong to this project's signer set");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if proposal.proposer != *proposer.key {
+            msg!("Proposer account does not match the multisig proposal");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if proposal.action != action {
+            msg!(
+                "Multisig proposal authorizes {:?}, not {:?}",
+                proposal.action,
+                action
+            );
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if proposal.payload_hash != MultisigProposalV1::hash_payload(payload) {
+            msg!("Instruction data does not match the approved multisig proposal");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if !proposal.is_approved() {
+            msg!(
+                "Multisig proposal not approved. Approvals: {}. Threshold: {}",
+                proposal.approval_count(),
+                proposal.threshold
+            );
+            return Err(ProgramError::Custom(5));
+        }
+
+        Ok(())
+    }
+}
+
+/// Ensures the SOL protocol fee goes to `ProtocolConfigV1::fee_wallet`.
+pub struct ProtocolFeeWallet;
+
+impl ProtocolFeeWalletCheck for ProtocolFeeWallet {
+    fn check<'info>(
+        protocol_config_pda: &AccountInfo<'info>,
+        protocol_wallet: &AccountInfo<'info>,
+    ) -> ProgramResult {
+        WritableAccount::check(protocol_wallet)?;
+        ProtocolConfigAccount::check(protocol_config_pda)?;
+
+        Pda::validate(protocol_config_pda, &[ProtocolConfigV1::SEED], &crate::ID)?;
+
+        let config_data = protocol_config_pda.try_borrow_data()?;
+        let config = ProtocolConfigV1::load(&config_data)?;
+
+        if config.fee_wallet != *protocol_wallet.key {
+            msg!(
+                "Invalid protocol wallet {} (expected {})",
+                protocol_wallet.key,
+                config.fee_wallet
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+/// Ensures `authority` signed and is this program's upgrade authority.
+///
+/// `program_data` is the BPF upgradeable loader's `ProgramData` account:
+/// `u32` state tag (`3`), `u64` slot, `Option<Pubkey>` upgrade authority.
+pub struct UpgradeAuthority;
 
-impl AccountCheck for VaultAccount {
-    fn check<'info>(account: &AccountInfo<'info>) -> ProgramResult {
-        if account.owner != &crate::ID {
+impl UpgradeAuthority {
+    const PROGRAM_DATA_TAG: u32 = 3;
+    const METADATA_LEN: usize = 4 + 8 + 1 + 32;
+}
+
+impl UpgradeAuthorityCheck for UpgradeAuthority {
+    fn check<'info>(
+        authority: &AccountInfo<'info>,
+        program_data: &AccountInfo<'info>,
+    ) -> ProgramResult {
+        SignerAccount::check(authority)?;
+
+        if program_data.owner != &bpf_loader_upgradeable::ID {
             msg!(
-                "VaultAccount: invalid owner {} (expected program {})",
-                account.owner,
-                crate::ID
+                "UpgradeAuthority: program data {} not owned by the upgradeable loader",
+                program_data.key
             );
             return Err(ProgramError::InvalidAccountOwner);
         }
 
-        if account.data_len() != VaultV1::LEN {
+        Pda::validate(
+            program_data,
+            &[crate::ID.as_ref()],
+            &bpf_loader_upgradeable::ID,
+        )?;
+
+        let data = program_data.try_borrow_data()?;
+
+        if data.len() < Self::METADATA_LEN
+            || data[..4] != Self::PROGRAM_DATA_TAG.to_le_bytes()
+            || data[12] != 1
+            || data[13..Self::METADATA_LEN] != authority.key.to_bytes()
+        {
             msg!(
-                "VaultAccount: invalid data length (expected {}, found {}) for account {}",
-                VaultV1::LEN,
-                account.data_len(),
-                account.key
+                "Unauthorized: {} is not the program upgrade authority",
+                authority.key
             );
-            return Err(ProgramError::InvalidAccountData);
+            return Err(ProgramError::IllegalOwner);
         }
 
         Ok(())
     }
 }
 
-pub struct AssociatedTokenAccount;
-
-impl AssociatedTokenAccountCheck for AssociatedTokenAccount {
-    fn check<'info>(
-        account: &AccountInfo<'info>,
-        wallet: &Pubkey,
-        mint: &Pubkey,
-        token_program_id: &Pubkey,
-    ) -> ProgramResult {
-        TokenAccount::check(account)?;
-        AssociatedTokenProgram::check(account, wallet, mint, token_program_id)?;
-        Ok(())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,6 +925,38 @@ mod tests {
         )
     }
 
+    fn mock_project(admin: Pubkey) -> ProjectV1 {
+        let mut data = vec![0u8; ProjectV1::LEN];
+        let project = ProjectV1::load_mut(&mut data).expect("load_mut should succeed");
+        project.admin = admin;
+        *project
+    }
+
+    fn mock_project_role_info(
+        project_pda: &Pubkey,
+        wallet: &Pubkey,
+        roles: u8,
+    ) -> AccountInfo<'static> {
+        let (key, _) = Pubkey::find_program_address(
+            &[ProjectRoleV1::SEED, project_pda.as_ref(), wallet.as_ref()],
+            &PROGRAM_ID,
+        );
+        let project_role = ProjectRoleV1 {
+            project: *project_pda,
+            wallet: *wallet,
+            roles,
+        };
+
+        crate::utils::mock::mock_account_with_data(
+            key,
+            false,
+            false,
+            1,
+            project_role.to_bytes(),
+            PROGRAM_ID,
+        )
+    }
+
     // --- Test Cases ---
 
     #[test]
@@ -391,6 +1082,9 @@ mod tests {
         let acc = mock_account_info(false, false, PROGRAM_ID, VaultV1::LEN);
         assert!(VaultAccount::check(&acc).is_ok());
 
+        let acc = mock_account_info(false, false, PROGRAM_ID, VaultV1::LEGACY_LEN);
+        assert!(VaultAccount::check(&acc).is_ok());
+
         let acc = mock_account_info(false, false, PROGRAM_ID, VaultV1::LEN + 1);
         assert_eq!(
             VaultAccount::check(&acc).unwrap_err(),
@@ -404,6 +1098,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_admin_proposal_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, AdminProposalV1::LEN);
+        assert!(AdminProposalAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, AdminProposalV1::LEN + 1);
+        assert_eq!(
+            AdminProposalAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, AdminProposalV1::LEN);
+        assert_eq!(
+            AdminProposalAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_protocol_config_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProtocolConfigV1::LEN);
+        assert!(ProtocolConfigAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProtocolConfigV1::LEN + 1);
+        assert_eq!(
+            ProtocolConfigAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ProtocolConfigV1::LEN);
+        assert_eq!(
+            ProtocolConfigAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_project_update_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectUpdateV1::LEN);
+        assert!(ProjectUpdateAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectUpdateV1::LEN + 1);
+        assert_eq!(
+            ProjectUpdateAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ProjectUpdateV1::LEN);
+        assert_eq!(
+            ProjectUpdateAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
     #[test]
     fn test_associated_token_account() {
         let wallet = Pubkey::new_unique();
@@ -425,4 +1173,360 @@ mod tests {
 
         assert!(AssociatedTokenAccount::check(&acc, &wallet, &mint, &token_program_id).is_ok());
     }
+
+    #[test]
+    fn test_legacy_project_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEGACY_LEN);
+        assert!(LegacyProjectAccount::check(&acc).is_ok());
+        assert_eq!(
+            ProjectAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEN);
+        assert_eq!(
+            LegacyProjectAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ProjectV1::LEGACY_LEN);
+        assert_eq!(
+            LegacyProjectAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_legacy_trait_item_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, TraitItemV1::LEGACY_LEN);
+        assert!(LegacyTraitItemAccount::check(&acc).is_ok());
+        assert_eq!(
+            TraitItemAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, TraitItemV1::LEN);
+        assert_eq!(
+            LegacyTraitItemAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, TraitItemV1::LEGACY_LEN);
+        assert_eq!(
+            LegacyTraitItemAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_trait_item_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, TraitItemV1::LEN);
+        assert!(TraitItemAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, TraitItemV1::LEN + 1);
+        assert_eq!(
+            TraitItemAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, TraitItemV1::LEN);
+        assert_eq!(
+            TraitItemAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_project_role_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectRoleV1::LEN);
+        assert!(ProjectRoleAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ProjectRoleV1::LEN + 1);
+        assert_eq!(
+            ProjectRoleAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ProjectRoleV1::LEN);
+        assert_eq!(
+            ProjectRoleAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_vip_pass_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, VipPassV1::LEN);
+        assert!(VipPassAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, VipPassV1::LEN + 1);
+        assert_eq!(
+            VipPassAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, VipPassV1::LEN);
+        assert_eq!(
+            VipPassAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_payment_mint_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, PaymentMintV1::LEN);
+        assert!(PaymentMintAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, PaymentMintV1::LEN + 1);
+        assert_eq!(
+            PaymentMintAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, PaymentMintV1::LEN);
+        assert_eq!(
+            PaymentMintAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_referrer_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ReferrerV1::LEN);
+        assert!(ReferrerAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ReferrerV1::LEN + 1);
+        assert_eq!(
+            ReferrerAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ReferrerV1::LEN);
+        assert_eq!(
+            ReferrerAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_referrer_earnings_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, ReferrerEarningsV1::LEN);
+        assert!(ReferrerEarningsAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, ReferrerEarningsV1::LEN + 1);
+        assert_eq!(
+            ReferrerEarningsAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, ReferrerEarningsV1::LEN);
+        assert_eq!(
+            ReferrerEarningsAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_coupon_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, CouponV1::LEN);
+        assert!(CouponAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, CouponV1::LEN + 1);
+        assert_eq!(
+            CouponAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, CouponV1::LEN);
+        assert_eq!(
+            CouponAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_mint_record_account() {
+        let acc = mock_account_info(false, false, PROGRAM_ID, MintRecordV1::LEN);
+        assert!(MintRecordAccount::check(&acc).is_ok());
+
+        let acc = mock_account_info(false, false, PROGRAM_ID, MintRecordV1::LEN + 1);
+        assert_eq!(
+            MintRecordAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let acc = mock_account_info(false, false, WRONG_PROGRAM_ID, MintRecordV1::LEN);
+        assert_eq!(
+            MintRecordAccount::check(&acc).unwrap_err(),
+            ProgramError::InvalidAccountOwner
+        );
+    }
+
+    #[test]
+    fn test_project_authority_admin() {
+        let admin = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let project_pda = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEN);
+        let role_pda = mock_uninitialized_account_info();
+        let project = mock_project(*admin.key);
+
+        assert!(ProjectAuthority::check(
+            &admin,
+            &project_pda,
+            &role_pda,
+            &project,
+            ProjectRole::ConfigManager
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_project_authority_requires_signer() {
+        let admin = mock_account_info(false, false, Pubkey::new_unique(), 0);
+        let project_pda = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEN);
+        let role_pda = mock_uninitialized_account_info();
+        let project = mock_project(*admin.key);
+
+        assert_eq!(
+            ProjectAuthority::check(
+                &admin,
+                &project_pda,
+                &role_pda,
+                &project,
+                ProjectRole::Minter
+            )
+            .unwrap_err(),
+            ProgramError::MissingRequiredSignature
+        );
+    }
+
+    #[test]
+    fn test_project_authority_check_admin() {
+        let admin = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let other = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let unsigned =
+            mock_account_info_from_key(*admin.key, false, false, Pubkey::new_unique(), 0);
+
+        assert!(ProjectAuthority::check_admin(&admin, admin.key).is_ok());
+        assert_eq!(
+            ProjectAuthority::check_admin(&other, admin.key).unwrap_err(),
+            ProgramError::IllegalOwner
+        );
+        assert_eq!(
+            ProjectAuthority::check_admin(&unsigned, admin.key).unwrap_err(),
+            ProgramError::MissingRequiredSignature
+        );
+    }
+
+    #[test]
+    fn test_project_authority_role_holder() {
+        let minter = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let project_pda = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEN);
+        let role_pda =
+            mock_project_role_info(project_pda.key, minter.key, ProjectRole::Minter.mask());
+        let project = mock_project(Pubkey::new_unique());
+
+        assert!(ProjectAuthority::check(
+            &minter,
+            &project_pda,
+            &role_pda,
+            &project,
+            ProjectRole::Minter
+        )
+        .is_ok());
+
+        assert_eq!(
+            ProjectAuthority::check(
+                &minter,
+                &project_pda,
+                &role_pda,
+                &project,
+                ProjectRole::ConfigManager
+            )
+            .unwrap_err(),
+            ProgramError::IllegalOwner
+        );
+    }
+
+    #[test]
+    fn test_project_authority_without_role_account() {
+        let stranger = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let project_pda = mock_account_info(false, false, PROGRAM_ID, ProjectV1::LEN);
+        let project = mock_project(Pubkey::new_unique());
+
+        let (role_key, _) = Pubkey::find_program_address(
+            &[
+                ProjectRoleV1::SEED,
+                project_pda.key.as_ref(),
+                stranger.key.as_ref(),
+            ],
+            &PROGRAM_ID,
+        );
+        let role_pda = mock_account_info_from_key(role_key, false, false, Pubkey::default(), 0);
+
+        assert_eq!(
+            ProjectAuthority::check(
+                &stranger,
+                &project_pda,
+                &role_pda,
+                &project,
+                ProjectRole::VestingManager
+            )
+            .unwrap_err(),
+            ProgramError::IllegalOwner
+        );
+    }
+
+    #[test]
+    fn test_protocol_fee_wallet() {
+        let fee_wallet = Pubkey::new_unique();
+        let (key, _) = Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &PROGRAM_ID);
+        let config = ProtocolConfigV1 {
+            authority: Pubkey::new_unique(),
+            fee_wallet,
+            default_mint_nft_fee_lamports: 0,
+            default_update_nft_fee_lamports: 0,
+            default_trait_mint_fee_lamports: 0,
+            protocol_fee_bps: 0,
+        };
+        let config_pda = crate::utils::mock::mock_account_with_data(
+            key,
+            false,
+            false,
+            1,
+            config.to_bytes(),
+            PROGRAM_ID,
+        );
+
+        let wallet = mock_account_info_from_key(fee_wallet, false, true, Pubkey::default(), 0);
+        assert!(ProtocolFeeWallet::check(&config_pda, &wallet).is_ok());
+
+        let other = mock_account_info(false, true, Pubkey::default(), 0);
+        assert_eq!(
+            ProtocolFeeWallet::check(&config_pda, &other).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn test_upgrade_authority() {
+        let authority = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        let (key, _) =
+            Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &bpf_loader_upgradeable::ID);
+        let program_data = crate::utils::mock::mock_account_with_data(
+            key,
+            false,
+            false,
+            1,
+            crate::utils::mock::mock_program_data(authority.key),
+            bpf_loader_upgradeable::ID,
+        );
+
+        assert!(UpgradeAuthority::check(&authority, &program_data).is_ok());
+
+        let other = mock_account_info(true, false, Pubkey::new_unique(), 0);
+        assert_eq!(
+            UpgradeAuthority::check(&other, &program_data).unwrap_err(),
+            ProgramError::IllegalOwner
+        );
+    }
 }
@@ -0,0 +1,80 @@
+use solana_program::{keccak, pubkey::Pubkey};
+
+pub struct MerkleProof;
+
+impl MerkleProof {
+    /// Leaf of an allowlist tree: `keccak256(wallet)`.
+    #[inline(always)]
+    pub fn leaf(wallet: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[wallet.as_ref()]).to_bytes()
+    }
+
+    /// Leaf of a reveal manifest tree: `keccak256(index_le || uri)`.
+    #[inline(always)]
+    pub fn manifest_leaf(index: u64, uri: &str) -> [u8; 32] {
+        keccak::hashv(&[&index.to_le_bytes(), uri.as_bytes()]).to_bytes()
+    }
+
+    /// Parent of two nodes. Pairs are sorted before hashing, so proofs don't
+    /// need to carry left/right positions.
+    #[inline(always)]
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[a, b]).to_bytes()
+        } else {
+            keccak::hashv(&[b, a]).to_bytes()
+        }
+    }
+
+    #[inline(always)]
+    pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+        let computed = proof
+            .iter()
+            .fold(leaf, |hash, sibling| Self::node(&hash, sibling));
+
+        computed == *root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Test Cases ---
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(MerkleProof::leaf).collect();
+
+        let left = MerkleProof::node(&leaves[0], &leaves[1]);
+        let right = MerkleProof::node(&leaves[2], &leaves[3]);
+        let root = MerkleProof::node(&left, &right);
+
+        assert!(MerkleProof::verify(&[leaves[1], right], &root, leaves[0]));
+        assert!(MerkleProof::verify(&[leaves[2], left], &root, leaves[3]));
+
+        // wrong sibling, wrong leaf, empty proof
+        assert!(!MerkleProof::verify(&[leaves[2], right], &root, leaves[0]));
+        assert!(!MerkleProof::verify(
+            &[leaves[1], right],
+            &root,
+            MerkleProof::leaf(&Pubkey::new_unique())
+        ));
+        assert!(!MerkleProof::verify(&[], &root, leaves[0]));
+    }
+
+    #[test]
+    fn test_verify_manifest_proof() {
+        let leaf_1 = MerkleProof::manifest_leaf(1, "https://arweave.net/1.json");
+        let leaf_2 = MerkleProof::manifest_leaf(2, "https://arweave.net/2.json");
+        let root = MerkleProof::node(&leaf_1, &leaf_2);
+
+        assert!(MerkleProof::verify(&[leaf_2], &root, leaf_1));
+        assert!(MerkleProof::verify(&[leaf_1], &root, leaf_2));
+
+        // swapped URIs don't verify
+        let swapped = MerkleProof::manifest_leaf(1, "https://arweave.net/2.json");
+        assert!(!MerkleProof::verify(&[leaf_2], &root, swapped));
+    }
+}
@@ -219,3 +219,33 @@ pub fn mock_base_asset(owner: Pubkey, name: &str, uri: &str) -> Vec<u8> {
 
     base.try_to_vec().expect("serialize BaseAssetV1")
 }
+
+pub fn mock_collection_asset(owner: Pubkey, collection: Pubkey) -> Vec<u8> {
+    let base = BaseAssetV1 {
+        key: Key::AssetV1,
+        owner,
+        update_authority: UpdateAuthority::Collection(collection),
+        name: "Gate".to_string(),
+        uri: "https://example.com/gate.json".to_string(),
+        seq: None,
+    };
+
+    base.try_to_vec().expect("serialize BaseAssetV1")
+}
+
+pub fn mock_program_data(upgrade_authority: &Pubkey) -> Vec<u8> {
+    let mut data = vec![0u8; 45];
+
+    // Offset 0..4: UpgradeableLoaderState::ProgramData tag (u32 LE = 3)
+    data[0..4].copy_from_slice(&3u32.to_le_bytes());
+
+    // Offset 4..12: slot (u64 LE = 0)
+
+    // Offset 12: upgrade_authority_address Option tag (1 = Some)
+    data[12] = 1;
+
+    // Offset 13..45: upgrade authority Pubkey
+    data[13..45].copy_from_slice(upgrade_authority.as_ref());
+
+    data
+}
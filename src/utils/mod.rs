@@ -1,5 +1,6 @@
 mod account_check;
 mod associated_token_program;
+mod merkle;
 mod mock;
 mod mpl_core_program;
 mod pda;
@@ -10,6 +11,7 @@ mod token_program;
 
 pub use account_check::*;
 pub use associated_token_program::*;
+pub use merkle::*;
 pub use mock::*;
 pub use mpl_core_program::*;
 pub use pda::*;
@@ -6,7 +6,7 @@ use mpl_core::{
     },
     types::{
         Creator, PermanentBurnDelegate, Plugin, PluginAuthority, PluginAuthorityPair, Royalties,
-        RuleSet,
+        RuleSet, UpdateAuthority,
     },
 };
 use solana_program::{
@@ -25,6 +25,19 @@ impl MplCoreProgram {
         Ok(base.owner)
     }
 
+    /// Returns the collection an asset belongs to, if any.
+    pub fn get_asset_collection<'info>(
+        account: &AccountInfo<'info>,
+    ) -> Result<Option<Pubkey>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let base = BaseAssetV1::from_bytes(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+
+        match base.update_authority {
+            UpdateAuthority::Collection(collection) => Ok(Some(collection)),
+            _ => Ok(None),
+        }
+    }
+
     pub fn get_royalties(
         num_royalty_recipients: u8,
         royalty_recipients: [Pubkey; MAX_ROYALTY_RECIPIENTS],
@@ -144,7 +157,7 @@ impl MplCoreProgram {
             .collection(Some(accounts.collection))
             .payer(accounts.payer)
             .authority(accounts.authority)
-            .owner(Some(accounts.payer))
+            .owner(Some(accounts.owner))
             .system_program(accounts.system_program)
             .name(args.name)
             .uri(args.uri)
@@ -156,15 +169,23 @@ impl MplCoreProgram {
         args: UpdateMplCoreAssetArgs,
         signers_seeds: &[&[&[u8]]],
     ) -> ProgramResult {
-        UpdateV1CpiBuilder::new(accounts.mpl_core)
+        let mut builder = UpdateV1CpiBuilder::new(accounts.mpl_core);
+        builder
             .asset(accounts.asset)
             .collection(Some(accounts.collection))
             .payer(accounts.payer)
             .authority(Some(accounts.update_authority))
-            .system_program(accounts.system_program)
-            .new_name(args.name)
-            .new_uri(args.uri)
-            .invoke_signed(signers_seeds)
+            .system_program(accounts.system_program);
+
+        if let Some(name) = args.name {
+            builder.new_name(name);
+        }
+
+        if let Some(uri) = args.uri {
+            builder.new_uri(uri);
+        }
+
+        builder.invoke_signed(signers_seeds)
     }
 
     pub fn burn<'a, 'info>(
@@ -226,6 +247,8 @@ pub struct UpdateMplCoreCollectionArgs {
 
 pub struct CreateMplCoreAssetAccounts<'a, 'info> {
     pub payer: &'a AccountInfo<'info>,
+    /// Wallet that will own the asset. May differ from `payer`.
+    pub owner: &'a AccountInfo<'info>,
     pub asset: &'a AccountInfo<'info>,
     pub collection: &'a AccountInfo<'info>,
     pub authority: Option<&'a AccountInfo<'info>>,
@@ -247,9 +270,10 @@ pub struct UpdateMplCoreAssetAccounts<'a, 'info> {
     pub system_program: &'a AccountInfo<'info>,
 }
 
+/// New asset metadata; `None` keeps the current value.
 pub struct UpdateMplCoreAssetArgs {
-    pub name: String,
-    pub uri: String,
+    pub name: Option<String>,
+    pub uri: Option<String>,
 }
 
 pub struct BurnMplCoreAssetAccounts<'a, 'info> {
@@ -2,7 +2,8 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
 use crate::utils::{
     AssociatedTokenAccount, AssociatedTokenAccountCheck, AssociatedTokenProgram,
-    InitAssociatedTokenProgramAccounts, TokenProgram, TokenTransferAccounts, TokenTransferArgs,
+    InitAssociatedTokenProgramAccounts, SystemProgram, TokenProgram, TokenTransferAccounts,
+    TokenTransferArgs,
 };
 
 pub struct RevenueWallet;
@@ -12,6 +13,15 @@ impl RevenueWallet {
         accounts: RevenueWalletAccounts<'a, 'info>,
         args: RevenueWalletArgs,
     ) -> ProgramResult {
+        if TokenProgram::is_native(accounts.mint.key) {
+            return SystemProgram::transfer(
+                accounts.payer,
+                accounts.wallet,
+                accounts.system_program,
+                args.amount,
+            );
+        }
+
         AssociatedTokenProgram::init_if_needed(InitAssociatedTokenProgramAccounts {
             payer: accounts.payer,
             wallet: accounts.wallet,
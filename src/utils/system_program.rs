@@ -44,6 +44,28 @@ impl SystemProgram {
         Ok(())
     }
 
+    /// Moves `lamports` out of a program-owned account, e.g. native SOL escrowed in a vault.
+    pub fn withdraw_from_pda<'info>(
+        account: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+        lamports: u64,
+    ) -> ProgramResult {
+        let remaining = account
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(ProgramError::InsufficientFunds)
+            .inspect_err(|_| msg!("Not enough lamports in {}", account.key))?;
+        let received = destination
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        **account.lamports.borrow_mut() = remaining;
+        **destination.lamports.borrow_mut() = received;
+
+        Ok(())
+    }
+
     pub fn close_ata<'info>(
         ata: &AccountInfo<'info>,
         destination: &AccountInfo<'info>,
@@ -152,6 +174,20 @@ mod tests {
         assert_eq!(**destination.lamports.borrow(), 0);
     }
 
+    #[test]
+    fn test_withdraw_from_pda_moves_lamports() {
+        let account = mock_account_info(Pubkey::new_unique(), 10_000, 16);
+        let destination = mock_account_info(Pubkey::new_unique(), 5_000, 0);
+
+        assert!(SystemProgram::withdraw_from_pda(&account, &destination, 4_000).is_ok());
+        assert_eq!(**account.lamports.borrow(), 6_000);
+        assert_eq!(**destination.lamports.borrow(), 9_000);
+
+        let result = SystemProgram::withdraw_from_pda(&account, &destination, 6_001);
+        assert_eq!(result.err(), Some(ProgramError::InsufficientFunds));
+        assert_eq!(**account.lamports.borrow(), 6_000);
+    }
+
     #[test]
     fn test_check_valid_system_program() {
         let account = mock_account_info(system_program::ID, 0, 0);
@@ -12,6 +12,10 @@ use solana_program::{
 pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
+/// Wrapped SOL mint. As a project or payment mint it means native SOL:
+/// amounts move as lamports and no token accounts are used.
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
 pub const MINT_LEN: usize = 82;
 pub const MINT_2022_MIN_LEN: usize = 90;
 
@@ -26,16 +30,24 @@ pub enum TokenProgram {
 
 impl TokenProgram {
     pub fn detect_token_program(account: &AccountInfo) -> Result<Self, ProgramError> {
-        if account.key == &TOKEN_PROGRAM_ID {
+        Self::from_program_id(account.key)
+    }
+
+    pub fn from_program_id(program_id: &Pubkey) -> Result<Self, ProgramError> {
+        if program_id == &TOKEN_PROGRAM_ID {
             Ok(Self::Token)
-        } else if account.key == &TOKEN_2022_PROGRAM_ID {
+        } else if program_id == &TOKEN_2022_PROGRAM_ID {
             Ok(Self::Token2022)
         } else {
-            msg!("Invalid token program {}", account.key);
+            msg!("Invalid token program {}", program_id);
             Err(ProgramError::InvalidAccountOwner)
         }
     }
 
+    pub fn is_native(mint: &Pubkey) -> bool {
+        *mint == NATIVE_MINT
+    }
+
     pub fn get_decimal<'info>(mint: &AccountInfo<'info>) -> Result<u8, ProgramError> {
         const DECIMALS_OFFSET: usize = 44;
 
@@ -48,12 +60,36 @@ impl TokenProgram {
         Ok(data[DECIMALS_OFFSET])
     }
 
+    /// Returns `(mint, owner)` of a token account (same layout for Token and Token-2022).
+    pub fn get_mint_and_owner<'info>(
+        token_account: &AccountInfo<'info>,
+    ) -> Result<(Pubkey, Pubkey), ProgramError> {
+        let data = token_account.try_borrow_data()?;
+        if data.len() < 64 {
+            msg!("Invalid token data {}", token_account.key);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mint = Pubkey::new_from_array(data[0..32].try_into().unwrap());
+        let owner = Pubkey::new_from_array(data[32..64].try_into().unwrap());
+
+        Ok((mint, owner))
+    }
+
     pub fn get_balance<'info>(
         token_account: &AccountInfo<'info>,
         token_program: &AccountInfo<'info>,
+    ) -> Result<u64, ProgramError> {
+        Self::get_balance_of(token_account, token_program.key)
+    }
+
+    /// Same as `get_balance`, for a token account of the token program `program_id`.
+    pub fn get_balance_of(
+        token_account: &AccountInfo,
+        program_id: &Pubkey,
     ) -> Result<u64, ProgramError> {
         let data = token_account.try_borrow_data()?;
-        let balance_offset = match Self::detect_token_program(token_program)? {
+        let balance_offset = match Self::from_program_id(program_id)? {
             Self::Token => 64, // SplTokenAccount::amount at byte 64
             Self::Token2022 => {
                 let mut offset = 64;
@@ -248,6 +284,12 @@ mod tests {
         assert_eq!(err, ProgramError::InvalidAccountOwner);
     }
 
+    #[test]
+    fn test_is_native() {
+        assert!(TokenProgram::is_native(&NATIVE_MINT));
+        assert!(!TokenProgram::is_native(&Pubkey::new_unique()));
+    }
+
     #[test]
     fn test_get_decimal_valid() {
         let mut data = vec![0u8; 82];
@@ -281,6 +323,19 @@ mod tests {
         assert_eq!(result, 123_456_789);
     }
 
+    #[test]
+    fn test_get_balance_of_token_2022() {
+        let mut data = vec![0u8; 170];
+        data[64..72].copy_from_slice(&42u64.to_le_bytes());
+        let token_account_info = mock_account_info(Pubkey::new_unique(), data);
+
+        let result = TokenProgram::get_balance_of(&token_account_info, &TOKEN_2022_PROGRAM_ID);
+        assert_eq!(result.unwrap(), 42);
+
+        let err = TokenProgram::get_balance_of(&token_account_info, &Pubkey::new_unique());
+        assert_eq!(err.unwrap_err(), ProgramError::InvalidAccountOwner);
+    }
+
     #[test]
     fn test_get_balance_invalid_len() {
         let data = vec![0u8; 10]; // too short for balance
@@ -0,0 +1,131 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    process_instruction,
+    states::{AdminProposalV1, AdminTarget, TraitItemV1},
+};
+
+#[tokio::test]
+async fn test_accept_admin_v1_trait_item() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let new_admin = Keypair::new();
+    let new_admin_pubkey = new_admin.pubkey();
+
+    let authority = Pubkey::new_unique();
+
+    let trait_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (trait_pda, _) =
+        Pubkey::find_program_address(&[TraitItemV1::SEED, trait_collection.as_ref()], &program_id);
+
+    let (admin_proposal_pda, _) =
+        Pubkey::find_program_address(&[AdminProposalV1::SEED, trait_pda.as_ref()], &program_id);
+
+    let trait_item = TraitItemV1 {
+        authority,
+        max_supply: 10_000,
+        user_minted: 0,
+        mint_fee_lamports: 10_000,
+        paused: 0,
+    };
+
+    let proposal = AdminProposalV1 {
+        target: trait_pda,
+        proposer: authority,
+        pending_admin: new_admin_pubkey,
+        target_kind: AdminTarget::TraitItem,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        new_admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_pda,
+        Account {
+            lamports,
+            data: trait_item.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        admin_proposal_pda,
+        Account {
+            lamports,
+            data: proposal.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(new_admin_pubkey, true),
+            AccountMeta::new(trait_pda, false),
+            AccountMeta::new(admin_proposal_pda, false),
+            AccountMeta::new(authority, false),
+        ],
+        data: vec![15u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_admin_pubkey),
+        &[&new_admin],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "AcceptAdminV1 failed: {:?}", result.err());
+
+    let account = banks_client.get_account(trait_pda).await.unwrap().unwrap();
+    let trait_item = TraitItemV1::load(&account.data).unwrap();
+
+    assert_eq!(trait_item.authority, new_admin_pubkey);
+
+    let account = banks_client.get_account(admin_proposal_pda).await.unwrap();
+
+    assert!(account.is_none(), "admin proposal should be closed");
+}
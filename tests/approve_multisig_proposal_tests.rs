@@ -0,0 +1,137 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    process_instruction,
+    states::{MultisigAction, MultisigProposalV1, MultisigV1},
+};
+
+#[tokio::test]
+async fn test_approve_multisig_proposal_v1() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let signer = Keypair::new();
+    let signer_pubkey = signer.pubkey();
+
+    let proposer = Pubkey::new_unique();
+
+    let project_pda = Pubkey::new_unique();
+
+    // PDAs
+
+    let (multisig_pda, _) =
+        Pubkey::find_program_address(&[MultisigV1::SEED, project_pda.as_ref()], &program_id);
+
+    let (multisig_proposal_pda, _) = Pubkey::find_program_address(
+        &[
+            MultisigProposalV1::SEED,
+            multisig_pda.as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        &program_id,
+    );
+
+    let mut signers = [Pubkey::default(); 10];
+    signers[0] = proposer;
+    signers[1] = signer_pubkey;
+
+    let multisig = MultisigV1 {
+        project: project_pda,
+        signers,
+        proposal_count: 1,
+        threshold: 2,
+        num_signers: 2,
+    };
+
+    let proposal = MultisigProposalV1 {
+        multisig: multisig_pda,
+        proposer,
+        payload_hash: MultisigProposalV1::hash_payload(&[]),
+        index: 0,
+        approvals: 1 << 0,
+        threshold: 2,
+        action: MultisigAction::ForceUnlockVesting,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        signer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        multisig_pda,
+        Account {
+            lamports,
+            data: multisig.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        multisig_proposal_pda,
+        Account {
+            lamports,
+            data: proposal.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(signer_pubkey, true),
+            AccountMeta::new_readonly(multisig_pda, false),
+            AccountMeta::new(multisig_proposal_pda, false),
+        ],
+        data: vec![19u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer_pubkey),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "ApproveMultisigProposalV1 failed: {:?}",
+        result.err()
+    );
+
+    let account = banks_client
+        .get_account(multisig_proposal_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal = MultisigProposalV1::load(&account.data).unwrap();
+
+    assert_eq!(proposal.approval_count(), 2);
+    assert!(proposal.is_approved());
+}
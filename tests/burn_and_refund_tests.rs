@@ -6,10 +6,14 @@ use solana_program_test::{processor, ProgramTest};
 use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
 use tweetonium::{
     process_instruction,
-    states::{NftAuthorityV1, ProjectV1, VaultV1, VestingMode},
+    states::{
+        MetadataPolicy, MintPhase, NftAuthorityV1, NftNaming, Pricing, ProjectV1, VaultV1,
+        VestingMode, VipGateMode, MAX_MINT_PHASES, PROJECT_V1_RESERVED_LEN,
+    },
     utils::{
         mock_base_asset, mock_mint, mock_mint_2022, mock_token_account, mock_token_account_2022,
-        noop_processor, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        noop_processor, ASSOCIATED_TOKEN_PROGRAM_ID, NATIVE_MINT, TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
     },
 };
 
@@ -95,6 +99,25 @@ async fn test_burn_and_refund_v1() {
         num_revenue_wallets: 0,
         revenue_wallets: [Pubkey::default(); 5],
         revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
     };
 
     let vault = VaultV1 {
@@ -102,6 +125,8 @@ async fn test_burn_and_refund_v1() {
         amount: 15_000_000,
         is_unlocked: 0,
         bump: [vault_bump],
+        price_paid: 15_000_000,
+        mint: token_mint,
     };
 
     let lamports = 1_000_000_000;
@@ -222,6 +247,256 @@ async fn test_burn_and_refund_v1() {
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(token_mint, false),
+        ],
+        data: vec![9u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer_pubkey), &[&payer], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "BurnAndRefundV1 failed: {:?}", result.err());
+
+    let vault_pda = banks_client.get_account(vault_pda).await.unwrap();
+    assert!(vault_pda.is_none(), "vault pda should be closed");
+    let vault_ata = banks_client.get_account(vault_ata).await.unwrap();
+    assert!(vault_ata.is_none(), "vault ata should be closed");
+}
+
+#[tokio::test]
+async fn test_burn_and_refund_v1_legacy_vault() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Pubkey::new_unique();
+
+    let token_mint = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    // Vault created before `price_paid` and `mint` existed: only the legacy prefix is stored.
+    let vault = VaultV1 {
+        nft: nft_asset,
+        amount: 15_000_000,
+        is_unlocked: 0,
+        bump: [vault_bump],
+        price_paid: 0,
+        mint: Pubkey::default(),
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_asset,
+        Account {
+            lamports,
+            data: mock_base_asset(payer_pubkey, "Test NFT", "https://example.com"),
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        vault_pda,
+        Account {
+            lamports,
+            data: vault.to_bytes()[..VaultV1::LEGACY_LEN].to_vec(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        vault_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &vault_pda, 15_000_000),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(token_mint, false),
         ],
         data: vec![9u8],
     };
@@ -239,6 +514,219 @@ async fn test_burn_and_refund_v1() {
     assert!(vault_ata.is_none(), "vault ata should be closed");
 }
 
+#[tokio::test]
+async fn test_burn_and_refund_v1_native_sol() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Pubkey::new_unique();
+
+    let token_mint = NATIVE_MINT;
+
+    // token accounts are unused for native SOL escrow
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let vault = VaultV1 {
+        nft: nft_asset,
+        amount: 15_000_000,
+        is_unlocked: 0,
+        bump: [vault_bump],
+        price_paid: 15_000_000,
+        mint: token_mint,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_asset,
+        Account {
+            lamports,
+            data: mock_base_asset(payer_pubkey, "Test NFT", "https://example.com"),
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        vault_pda,
+        Account {
+            lamports,
+            data: vault.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(token_mint, false),
+        ],
+        data: vec![9u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer_pubkey), &[&payer], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "BurnAndRefundV1 failed: {:?}", result.err());
+
+    let vault_pda = banks_client.get_account(vault_pda).await.unwrap();
+    assert!(vault_pda.is_none(), "vault pda should be closed");
+
+    // escrowed lamports and vault rent are returned to the payer
+    let payer_balance = banks_client.get_balance(payer_pubkey).await.unwrap();
+    assert!(payer_balance > lamports * 2 - 10_000);
+}
+
 #[tokio::test]
 async fn test_burn_and_refund_v1_token_2022() {
     let program_id = tweetonium::ID;
@@ -323,6 +811,25 @@ async fn test_burn_and_refund_v1_token_2022() {
         num_revenue_wallets: 0,
         revenue_wallets: [Pubkey::default(); 5],
         revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
     };
 
     let vault = VaultV1 {
@@ -330,6 +837,8 @@ async fn test_burn_and_refund_v1_token_2022() {
         amount: 15_000_000,
         is_unlocked: 0,
         bump: [vault_bump],
+        price_paid: 15_000_000,
+        mint: token_mint_pubkey,
     };
 
     let lamports = 2_000_000_000;
@@ -450,6 +959,7 @@ async fn test_burn_and_refund_v1_token_2022() {
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(token_mint_pubkey, false),
         ],
         data: vec![9u8],
     };
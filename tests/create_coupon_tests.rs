@@ -0,0 +1,366 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::CreateCouponV1InstructionData,
+    process_instruction,
+    states::{
+        CouponKind, CouponV1, MetadataPolicy, MintPhase, NftNaming, Pricing, ProjectV1,
+        VestingMode, VipGateMode, MAX_MINT_PHASES, PROJECT_V1_RESERVED_LEN,
+    },
+    utils::{mock_mint, TOKEN_PROGRAM_ID},
+};
+
+#[tokio::test]
+async fn test_create_coupon_v1() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Pubkey::new_unique();
+
+    // PDAs
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let code = [7u8; 32];
+
+    let (coupon_pda, _) =
+        Pubkey::find_program_address(&[CouponV1::SEED, project_pda.as_ref(), &code], &program_id);
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 15_000_000;
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 1,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = CreateCouponV1InstructionData {
+        code,
+        kind: CouponKind::Percent,
+        amount: 2_500,
+        max_redemptions: 100,
+        expires_at: 0,
+        wallet: Pubkey::default(),
+    };
+
+    let mut data = vec![36u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new_readonly(project_pda, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new(coupon_pda, false),
+            AccountMeta::new_readonly(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data,
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "CreateCouponV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(coupon_pda)
+        .await
+        .unwrap()
+        .expect("coupon account should exist");
+    let coupon = CouponV1::load(&account.data).unwrap();
+
+    assert_eq!(coupon.project, project_pda);
+    assert_eq!(coupon.code, code);
+    assert_eq!(coupon.kind, CouponKind::Percent);
+    assert_eq!(coupon.amount, 2_500);
+    assert_eq!(coupon.max_redemptions, 100);
+    assert_eq!(coupon.redeemed, 0);
+    assert_eq!(coupon.wallet, Pubkey::default());
+}
+
+#[tokio::test]
+async fn test_create_coupon_v1_rejects_invalid_percent() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Pubkey::new_unique();
+
+    // PDAs
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let code = [7u8; 32];
+
+    let (coupon_pda, _) =
+        Pubkey::find_program_address(&[CouponV1::SEED, project_pda.as_ref(), &code], &program_id);
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 15_000_000;
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 1,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = CreateCouponV1InstructionData {
+        code,
+        kind: CouponKind::Percent,
+        amount: 10_001,
+        max_redemptions: 100,
+        expires_at: 0,
+        wallet: Pubkey::default(),
+    };
+
+    let mut data = vec![36u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new_readonly(project_pda, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new(coupon_pda, false),
+            AccountMeta::new_readonly(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data,
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "CreateCouponV1 should reject a percent coupon above 10_000 bps"
+    );
+}
@@ -0,0 +1,140 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::CreateMultisigProposalV1InstructionData,
+    process_instruction,
+    states::{MultisigAction, MultisigProposalV1, MultisigV1},
+};
+
+#[tokio::test]
+async fn test_create_multisig_proposal_v1() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let proposer = Keypair::new();
+    let proposer_pubkey = proposer.pubkey();
+
+    let project_pda = Pubkey::new_unique();
+
+    // PDAs
+
+    let (multisig_pda, _) =
+        Pubkey::find_program_address(&[MultisigV1::SEED, project_pda.as_ref()], &program_id);
+
+    let (multisig_proposal_pda, _) = Pubkey::find_program_address(
+        &[
+            MultisigProposalV1::SEED,
+            multisig_pda.as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        &program_id,
+    );
+
+    let mut signers = [Pubkey::default(); 10];
+    signers[0] = Pubkey::new_unique();
+    signers[1] = proposer_pubkey;
+
+    let multisig = MultisigV1 {
+        project: project_pda,
+        signers,
+        proposal_count: 0,
+        threshold: 2,
+        num_signers: 2,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        proposer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        multisig_pda,
+        Account {
+            lamports,
+            data: multisig.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let payload_hash = MultisigProposalV1::hash_payload(&[]);
+
+    let args = CreateMultisigProposalV1InstructionData {
+        action: MultisigAction::ForceUnlockVesting,
+        payload_hash,
+    };
+
+    let mut data = vec![18u8];
+    data.extend_from_slice(&args.try_to_vec().unwrap());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(proposer_pubkey, true),
+            AccountMeta::new(multisig_pda, false),
+            AccountMeta::new(multisig_proposal_pda, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&proposer_pubkey),
+        &[&proposer],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "CreateMultisigProposalV1 failed: {:?}",
+        result.err()
+    );
+
+    let account = banks_client
+        .get_account(multisig_proposal_pda)
+        .await
+        .unwrap()
+        .expect("multisig proposal account should exist");
+    let proposal = MultisigProposalV1::load(&account.data).unwrap();
+
+    assert_eq!(proposal.multisig, multisig_pda);
+    assert_eq!(proposal.proposer, proposer_pubkey);
+    assert_eq!(proposal.payload_hash, payload_hash);
+    assert_eq!(proposal.action, MultisigAction::ForceUnlockVesting);
+    assert_eq!(proposal.approvals, 1 << 1);
+    assert!(!proposal.is_approved());
+
+    let account = banks_client
+        .get_account(multisig_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let multisig = MultisigV1::load(&account.data).unwrap();
+
+    assert_eq!(multisig.proposal_count, 1);
+}
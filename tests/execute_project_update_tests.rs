@@ -0,0 +1,404 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    process_instruction,
+    states::{
+        MetadataPolicy, MintPhase, NftNaming, Pricing, ProjectUpdateV1, ProjectV1,
+        UpdateProjectArgs, VestingMode, VipGateArgs, VipGateMode, MAX_MINT_PHASES,
+        PROJECT_V1_RESERVED_LEN,
+    },
+    utils::{mock_mint, TOKEN_PROGRAM_ID},
+};
+
+#[tokio::test]
+async fn test_execute_project_update_v1() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_update_pda, _) =
+        Pubkey::find_program_address(&[ProjectUpdateV1::SEED, project_pda.as_ref()], &program_id);
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::TimeStamp,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 3_600,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let project_update = ProjectUpdateV1 {
+        project: project_pda,
+        queued_by: admin_pubkey,
+        eta: 0,
+        args: UpdateProjectArgs {
+            max_supply: 10_000,
+            released: 8_000,
+            max_mint_per_user: 5,
+            max_mint_per_vip_user: 10,
+            vesting_mode: VestingMode::TimeStamp,
+            vesting_unlock_ts: 0,
+            mint_nft_fee_lamports: 0,
+            update_nft_fee_lamports: 0,
+            mint_price_total: 15_000_000,
+            escrow_amount: 15_000_000,
+            num_revenue_wallets: 0,
+            revenue_wallets: [Pubkey::default(); 5],
+            revenue_shares: [0; 5],
+            pricing: Pricing::default(),
+            vip_supply: 0,
+            vip_rollover_ts: 0,
+            vip_merkle_root: [9u8; 32],
+            vip_gate: VipGateArgs {
+                mode: VipGateMode::None,
+                single_use: false,
+                min_amount: 0,
+                gate: Pubkey::default(),
+            },
+        },
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_update_pda,
+        Account {
+            lamports,
+            data: project_update.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(project_update_pda, false),
+            AccountMeta::new(admin_pubkey, false),
+            AccountMeta::new_readonly(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+        ],
+        data: vec![22u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "ExecuteProjectUpdateV1 failed: {:?}",
+        result.err()
+    );
+
+    let account = banks_client
+        .get_account(project_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let project = ProjectV1::load(&account.data).unwrap();
+
+    assert_eq!(project.released, 8_000);
+    assert_eq!(project.vip_merkle_root, [9u8; 32]);
+
+    let account = banks_client.get_account(project_update_pda).await.unwrap();
+
+    assert!(account.is_none(), "executed update should be closed");
+}
+
+#[tokio::test]
+async fn test_execute_project_update_v1_before_eta() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_update_pda, _) =
+        Pubkey::find_program_address(&[ProjectUpdateV1::SEED, project_pda.as_ref()], &program_id);
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::TimeStamp,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 3_600,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let project_update = ProjectUpdateV1 {
+        project: project_pda,
+        queued_by: admin_pubkey,
+        eta: i64::MAX,
+        args: UpdateProjectArgs {
+            max_supply: 10_000,
+            released: 8_000,
+            max_mint_per_user: 5,
+            max_mint_per_vip_user: 10,
+            vesting_mode: VestingMode::TimeStamp,
+            vesting_unlock_ts: 0,
+            mint_nft_fee_lamports: 0,
+            update_nft_fee_lamports: 0,
+            mint_price_total: 15_000_000,
+            escrow_amount: 15_000_000,
+            num_revenue_wallets: 0,
+            revenue_wallets: [Pubkey::default(); 5],
+            revenue_shares: [0; 5],
+            pricing: Pricing::default(),
+            vip_supply: 0,
+            vip_rollover_ts: 0,
+            vip_merkle_root: [0u8; 32],
+            vip_gate: VipGateArgs {
+                mode: VipGateMode::None,
+                single_use: false,
+                min_amount: 0,
+                gate: Pubkey::default(),
+            },
+        },
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_update_pda,
+        Account {
+            lamports,
+            data: project_update.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(project_update_pda, false),
+            AccountMeta::new(admin_pubkey, false),
+            AccountMeta::new_readonly(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+        ],
+        data: vec![22u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "ExecuteProjectUpdateV1 should fail before eta"
+    );
+}
@@ -6,7 +6,11 @@ use solana_program_test::{processor, ProgramTest};
 use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
 use tweetonium::{
     process_instruction,
-    states::{ProjectV1, VestingMode},
+    states::{
+        MetadataPolicy, MintPhase, MultisigAction, MultisigProposalV1, MultisigV1, NftNaming,
+        Pricing, ProjectRoleV1, ProjectV1, VestingMode, VipGateMode, MAX_MINT_PHASES,
+        PROJECT_V1_RESERVED_LEN,
+    },
     utils::{mock_mint, TOKEN_PROGRAM_ID},
 };
 
@@ -41,6 +45,15 @@ async fn test_force_unlock_vesting_v1() {
         &program_id,
     );
 
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
     let cfg = ProjectV1 {
         admin: admin_pubkey,
         mint: token_mint,
@@ -60,6 +73,25 @@ async fn test_force_unlock_vesting_v1() {
         num_revenue_wallets: 0,
         revenue_wallets: [Pubkey::default(); 5],
         revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
     };
 
     let lamports = 1_000_000_000;
@@ -115,8 +147,12 @@ async fn test_force_unlock_vesting_v1() {
         accounts: vec![
             AccountMeta::new(admin_pubkey, true),
             AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
             AccountMeta::new_readonly(token_mint, false),
             AccountMeta::new(nft_collection, false),
+            // multisig_proposal_pda and proposer are unused without multisig
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
         ],
         data: vec![10u8],
     };
@@ -132,3 +168,225 @@ async fn test_force_unlock_vesting_v1() {
         result.err()
     );
 }
+
+#[tokio::test]
+async fn test_force_unlock_vesting_v1_multisig() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let executor = Keypair::new();
+    let executor_pubkey = executor.pubkey();
+
+    let admin = Pubkey::new_unique();
+
+    let proposer = Pubkey::new_unique();
+
+    let token_mint = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            executor_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (multisig_pda, _) =
+        Pubkey::find_program_address(&[MultisigV1::SEED, project_pda.as_ref()], &program_id);
+
+    let (multisig_proposal_pda, _) = Pubkey::find_program_address(
+        &[
+            MultisigProposalV1::SEED,
+            multisig_pda.as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        &program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::TimeStamp,
+        vesting_unlock_ts: i64::MAX,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: multisig_pda,
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let proposal = MultisigProposalV1 {
+        multisig: multisig_pda,
+        proposer,
+        payload_hash: MultisigProposalV1::hash_payload(&[]),
+        index: 0,
+        approvals: 0b11,
+        threshold: 2,
+        action: MultisigAction::ForceUnlockVesting,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        executor_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        proposer,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        multisig_proposal_pda,
+        Account {
+            lamports,
+            data: proposal.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(executor_pubkey, true),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(multisig_proposal_pda, false),
+            AccountMeta::new(proposer, false),
+        ],
+        data: vec![10u8],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&executor_pubkey),
+        &[&executor],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "ForceUnlockVestingV1 via multisig failed: {:?}",
+        result.err()
+    );
+
+    let account = banks_client
+        .get_account(project_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let project = ProjectV1::load(&account.data).unwrap();
+
+    assert_ne!(project.vesting_unlock_ts, i64::MAX);
+
+    let account = banks_client
+        .get_account(multisig_proposal_pda)
+        .await
+        .unwrap();
+
+    assert!(account.is_none(), "executed proposal should be closed");
+}
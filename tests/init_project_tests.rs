@@ -8,7 +8,9 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::InitProjectV1InstructionData,
     process_instruction,
-    states::{NftAuthorityV1, ProjectV1, VestingMode},
+    states::{
+        NftAuthorityV1, Pricing, ProjectV1, ProtocolConfigV1, VestingMode, VipGateArgs, VipGateMode,
+    },
     utils::{mock_mint_2022, noop_processor, TOKEN_2022_PROGRAM_ID},
 };
 
@@ -46,6 +48,18 @@ async fn test_init_project() {
         &program_id,
     );
 
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: Pubkey::new_unique(),
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
     let lamports = 2_000_000_000;
 
     program_test.add_account(
@@ -70,6 +84,17 @@ async fn test_init_project() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = InitProjectV1InstructionData {
@@ -103,6 +128,17 @@ async fn test_init_project() {
         royalty_shares_bps: [500, 0, 0, 0, 0],
         collection_name: "Test Collection".to_string(),
         collection_uri: "https://example.com/collection.json".to_string(),
+        vip_merkle_root: [0u8; 32],
+        vip_gate: VipGateArgs {
+            mode: VipGateMode::None,
+            single_use: false,
+            min_amount: 0,
+            gate: Pubkey::default(),
+        },
+        pricing: Pricing::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_rollover_ts: 0,
     };
 
     let mut data = vec![0u8];
@@ -116,6 +152,7 @@ async fn test_init_project() {
             AccountMeta::new_readonly(nft_authority, false),
             AccountMeta::new(nft_collection_pubkey, true),
             AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
         ],
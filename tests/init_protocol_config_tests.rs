@@ -0,0 +1,110 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    bpf_loader_upgradeable,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::InitProtocolConfigV1InstructionData, process_instruction,
+    states::ProtocolConfigV1, utils::mock_program_data,
+};
+
+#[tokio::test]
+async fn test_init_protocol_config() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let authority = Keypair::new();
+    let authority_pubkey = authority.pubkey();
+
+    let fee_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        authority_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        program_data,
+        Account {
+            lamports,
+            data: mock_program_data(&authority_pubkey),
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = InitProtocolConfigV1InstructionData {
+        default_mint_nft_fee_lamports: 500_000,
+        default_update_nft_fee_lamports: 100_000,
+        default_trait_mint_fee_lamports: 250_000,
+        protocol_fee_bps: 0,
+    };
+
+    let mut data = vec![26u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(protocol_config_pda, false),
+            AccountMeta::new_readonly(fee_wallet, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority_pubkey),
+        &[&authority],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "InitProtocolConfigV1 failed: {:?}",
+        result.err()
+    );
+
+    let account = banks_client
+        .get_account(protocol_config_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let config = ProtocolConfigV1::load(&account.data).unwrap();
+
+    assert_eq!(config.authority, authority_pubkey);
+    assert_eq!(config.fee_wallet, fee_wallet);
+    assert_eq!(config.default_mint_nft_fee_lamports, 500_000);
+}
@@ -8,7 +8,7 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::InitTraitV1InstructionData,
     process_instruction,
-    states::{TraitAuthorityV1, TraitItemV1},
+    states::{ProtocolConfigV1, TraitAuthorityV1, TraitItemV1},
     utils::noop_processor,
 };
 
@@ -39,6 +39,18 @@ async fn test_init_trait() {
         &program_id,
     );
 
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: Pubkey::new_unique(),
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
     let lamports = 2_000_000_000;
 
     program_test.add_account(
@@ -52,6 +64,17 @@ async fn test_init_trait() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = InitTraitV1InstructionData {
@@ -80,6 +103,7 @@ async fn test_init_trait() {
             AccountMeta::new(trait_pda, false),
             AccountMeta::new_readonly(trait_authority, false),
             AccountMeta::new(trait_collection_pubkey, true),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
         ],
@@ -0,0 +1,200 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{process_instruction, states::TraitItemV1};
+
+#[tokio::test]
+async fn test_migrate_trait_v1() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let authority_pubkey = Pubkey::new_unique();
+
+    let trait_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (trait_pda, _) =
+        Pubkey::find_program_address(&[TraitItemV1::SEED, trait_collection.as_ref()], &program_id);
+
+    let cfg = TraitItemV1 {
+        authority: authority_pubkey,
+        max_supply: 1_000,
+        user_minted: 42,
+        mint_fee_lamports: 500_000,
+        paused: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_pda,
+        Account {
+            lamports: Rent::default().minimum_balance(TraitItemV1::LEGACY_LEN),
+            data: cfg.to_bytes()[..TraitItemV1::LEGACY_LEN].to_vec(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(trait_pda, false),
+            AccountMeta::new_readonly(trait_collection, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data: vec![42u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer_pubkey), &[&payer], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MigrateTraitV1 failed: {:?}", result.err());
+
+    let account = banks_client.get_account(trait_pda).await.unwrap().unwrap();
+
+    assert_eq!(account.data.len(), TraitItemV1::LEN);
+    assert!(account.lamports >= Rent::default().minimum_balance(TraitItemV1::LEN));
+
+    let trait_item = TraitItemV1::load(&account.data).unwrap();
+
+    assert_eq!(trait_item.authority, authority_pubkey);
+    assert_eq!(trait_item.max_supply, 1_000);
+    assert_eq!(trait_item.user_minted, 42);
+    assert_eq!(trait_item.mint_fee_lamports, 500_000);
+    assert_eq!(trait_item.paused, 0);
+}
+
+#[tokio::test]
+async fn test_migrate_trait_v1_already_migrated() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let authority_pubkey = Pubkey::new_unique();
+
+    let trait_collection = Pubkey::new_unique();
+
+    // PDAs
+
+    let (trait_pda, _) =
+        Pubkey::find_program_address(&[TraitItemV1::SEED, trait_collection.as_ref()], &program_id);
+
+    let cfg = TraitItemV1 {
+        authority: authority_pubkey,
+        max_supply: 1_000,
+        user_minted: 42,
+        mint_fee_lamports: 500_000,
+        paused: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_pda,
+        Account {
+            lamports: Rent::default().minimum_balance(TraitItemV1::LEN),
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(trait_pda, false),
+            AccountMeta::new_readonly(trait_collection, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+        data: vec![42u8],
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer_pubkey), &[&payer], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "MigrateTraitV1 should reject a trait item already on the current layout"
+    );
+}
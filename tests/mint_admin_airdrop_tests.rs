@@ -0,0 +1,1011 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::{MintAdminAirdropItem, MintAdminAirdropV1InstructionData},
+    process_instruction,
+    states::{
+        MetadataPolicy, MintPhase, MintRecordV1, NftAuthorityV1, NftNaming, Pricing, ProjectRoleV1,
+        ProjectV1, ProtocolConfigV1, VaultV1, VestingMode, VipGateMode, MAX_MINT_PHASES,
+        PROJECT_V1_RESERVED_LEN,
+    },
+    utils::{
+        mock_mint, mock_token_account, noop_processor, ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+};
+
+#[tokio::test]
+async fn test_mint_admin_airdrop() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let recipient_0 = Pubkey::new_unique();
+    let recipient_1 = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Keypair::new();
+    let nft_asset_0_pubkey = nft_asset_0.pubkey();
+    let nft_asset_1 = Keypair::new();
+    let nft_asset_1_pubkey = nft_asset_1.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (admin_ata, _) = Pubkey::find_program_address(
+        &[
+            admin_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda_0, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_0_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_0_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_0, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_0.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vault_pda_1, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_1_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_1, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_1_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_1, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_1.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 2_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        admin_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &admin_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintAdminAirdropV1InstructionData {
+        nfts: vec![
+            MintAdminAirdropItem {
+                recipient: recipient_0,
+                nft_name: "Team NFT #0".to_string(),
+                nft_uri: "https://example.com/nft-0.json".to_string(),
+            },
+            MintAdminAirdropItem {
+                recipient: recipient_1,
+                nft_name: "Team NFT #1".to_string(),
+                nft_uri: "https://example.com/nft-1.json".to_string(),
+            },
+        ],
+    };
+
+    let mut data = vec![32u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new(admin_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(recipient_0, false),
+            AccountMeta::new(nft_asset_0_pubkey, true),
+            AccountMeta::new(vault_pda_0, false),
+            AccountMeta::new(vault_ata_0, false),
+            AccountMeta::new(mint_record_pda_0, false),
+            AccountMeta::new_readonly(recipient_1, false),
+            AccountMeta::new(nft_asset_1_pubkey, true),
+            AccountMeta::new(vault_pda_1, false),
+            AccountMeta::new(vault_ata_1, false),
+            AccountMeta::new(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin_pubkey),
+        &[&admin, &nft_asset_0, &nft_asset_1],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_ok(),
+        "MintAdminAirdropV1 failed: {:?}",
+        result.err()
+    );
+
+    for (vault_pda, nft_asset) in [
+        (vault_pda_0, nft_asset_0_pubkey),
+        (vault_pda_1, nft_asset_1_pubkey),
+    ] {
+        let account = banks_client
+            .get_account(vault_pda)
+            .await
+            .unwrap()
+            .expect("vault account should exist");
+        let vault = VaultV1::load(&account.data).unwrap();
+
+        assert_eq!(vault.nft, nft_asset);
+        assert_eq!(vault.amount, 15_000_000);
+        assert_eq!(vault.price_paid, 0);
+    }
+
+    let account = banks_client
+        .get_account(project_pda)
+        .await
+        .unwrap()
+        .expect("project account should exist");
+    let project = ProjectV1::load(&account.data).unwrap();
+    assert_eq!(project.admin_minted, 2);
+}
+
+#[tokio::test]
+async fn test_mint_admin_airdrop_exceeds_admin_supply() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let recipient_0 = Pubkey::new_unique();
+    let recipient_1 = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Keypair::new();
+    let nft_asset_0_pubkey = nft_asset_0.pubkey();
+    let nft_asset_1 = Keypair::new();
+    let nft_asset_1_pubkey = nft_asset_1.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (admin_ata, _) = Pubkey::find_program_address(
+        &[
+            admin_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda_0, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_0_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_0_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_0, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_0.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vault_pda_1, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_1_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_1, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_1_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_1, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_1.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 4_999,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 2_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        admin_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &admin_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintAdminAirdropV1InstructionData {
+        nfts: vec![
+            MintAdminAirdropItem {
+                recipient: recipient_0,
+                nft_name: "Team NFT #0".to_string(),
+                nft_uri: "https://example.com/nft-0.json".to_string(),
+            },
+            MintAdminAirdropItem {
+                recipient: recipient_1,
+                nft_name: "Team NFT #1".to_string(),
+                nft_uri: "https://example.com/nft-1.json".to_string(),
+            },
+        ],
+    };
+
+    let mut data = vec![32u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new(admin_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(recipient_0, false),
+            AccountMeta::new(nft_asset_0_pubkey, true),
+            AccountMeta::new(vault_pda_0, false),
+            AccountMeta::new(vault_ata_0, false),
+            AccountMeta::new(mint_record_pda_0, false),
+            AccountMeta::new_readonly(recipient_1, false),
+            AccountMeta::new(nft_asset_1_pubkey, true),
+            AccountMeta::new(vault_pda_1, false),
+            AccountMeta::new(vault_ata_1, false),
+            AccountMeta::new(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin_pubkey),
+        &[&admin, &nft_asset_0, &nft_asset_1],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "MintAdminAirdropV1 should fail when the airdrop exceeds the admin supply"
+    );
+}
+
+#[tokio::test]
+async fn test_mint_admin_airdrop_default_recipient() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let recipient_0 = Pubkey::default();
+    let recipient_1 = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Keypair::new();
+    let nft_asset_0_pubkey = nft_asset_0.pubkey();
+    let nft_asset_1 = Keypair::new();
+    let nft_asset_1_pubkey = nft_asset_1.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (admin_ata, _) = Pubkey::find_program_address(
+        &[
+            admin_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda_0, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_0_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_0_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_0, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_0.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vault_pda_1, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_1_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_1, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_1_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata_1, _) = Pubkey::find_program_address(
+        &[
+            vault_pda_1.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 15_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 2_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        admin_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &admin_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintAdminAirdropV1InstructionData {
+        nfts: vec![
+            MintAdminAirdropItem {
+                recipient: recipient_0,
+                nft_name: "Team NFT #0".to_string(),
+                nft_uri: "https://example.com/nft-0.json".to_string(),
+            },
+            MintAdminAirdropItem {
+                recipient: recipient_1,
+                nft_name: "Team NFT #1".to_string(),
+                nft_uri: "https://example.com/nft-1.json".to_string(),
+            },
+        ],
+    };
+
+    let mut data = vec![32u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new(admin_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(recipient_0, false),
+            AccountMeta::new(nft_asset_0_pubkey, true),
+            AccountMeta::new(vault_pda_0, false),
+            AccountMeta::new(vault_ata_0, false),
+            AccountMeta::new(mint_record_pda_0, false),
+            AccountMeta::new_readonly(recipient_1, false),
+            AccountMeta::new(nft_asset_1_pubkey, true),
+            AccountMeta::new(vault_pda_1, false),
+            AccountMeta::new(vault_ata_1, false),
+            AccountMeta::new(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin_pubkey),
+        &[&admin, &nft_asset_0, &nft_asset_1],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "MintAdminAirdropV1 should reject a default recipient"
+    );
+}
@@ -8,7 +8,11 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::MintAdminV1InstructionData,
     process_instruction,
-    states::{NftAuthorityV1, ProjectV1, VaultV1, VestingMode},
+    states::{
+        MetadataPolicy, MintPhase, MintRecordV1, MintTier, NftAuthorityV1, NftNaming, Pricing,
+        ProjectRoleV1, ProjectV1, ProtocolConfigV1, VaultV1, VestingMode, VipGateMode,
+        MAX_MINT_PHASES, PROJECT_V1_RESERVED_LEN,
+    },
     utils::{
         mock_mint, mock_mint_2022, mock_token_account, mock_token_account_2022, noop_processor,
         ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
@@ -68,6 +72,15 @@ async fn test_mint_admin() {
         &program_id,
     );
 
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
     let (vault_pda, _) = Pubkey::find_program_address(
         &[
             VaultV1::SEED,
@@ -78,6 +91,11 @@ async fn test_mint_admin() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -106,6 +124,37 @@ async fn test_mint_admin() {
         num_revenue_wallets: 0,
         revenue_wallets: [Pubkey::default(); 5],
         revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
     };
 
     let lamports = 2_000_000_000;
@@ -187,6 +236,17 @@ async fn test_mint_admin() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintAdminV1InstructionData {
@@ -203,6 +263,7 @@ async fn test_mint_admin() {
             AccountMeta::new(admin_pubkey, true),
             AccountMeta::new(admin_ata, false),
             AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(vault_ata, false),
             AccountMeta::new_readonly(nft_authority, false),
@@ -211,9 +272,11 @@ async fn test_mint_admin() {
             AccountMeta::new_readonly(token_mint, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(mint_record_pda, false),
         ],
         data,
     };
@@ -228,6 +291,18 @@ async fn test_mint_admin() {
     let result = banks_client.process_transaction(tx).await;
 
     assert!(result.is_ok(), "MintAdmintV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(mint_record_pda)
+        .await
+        .unwrap()
+        .expect("mint record account should exist");
+    let record = MintRecordV1::load(&account.data).unwrap();
+
+    assert_eq!(record.minter, admin_pubkey);
+    assert_eq!(record.tier, MintTier::Admin);
+    assert_eq!(record.price_paid, 0);
+    assert_eq!(record.escrow_amount, 15_000_000);
 }
 
 #[tokio::test]
@@ -283,6 +358,15 @@ async fn test_mint_admin_2022() {
         &program_id,
     );
 
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
     let (vault_pda, _) = Pubkey::find_program_address(
         &[
             VaultV1::SEED,
@@ -293,6 +377,11 @@ async fn test_mint_admin_2022() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -321,6 +410,37 @@ async fn test_mint_admin_2022() {
         num_revenue_wallets: 0,
         revenue_wallets: [Pubkey::default(); 5],
         revenue_shares: [0; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
     };
 
     let lamports = 2_000_000_000;
@@ -402,6 +522,17 @@ async fn test_mint_admin_2022() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintAdminV1InstructionData {
@@ -418,6 +549,7 @@ async fn test_mint_admin_2022() {
             AccountMeta::new(admin_pubkey, true),
             AccountMeta::new(admin_ata, false),
             AccountMeta::new(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(vault_ata, false),
             AccountMeta::new_readonly(nft_authority, false),
@@ -426,9 +558,11 @@ async fn test_mint_admin_2022() {
             AccountMeta::new_readonly(token_mint, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(mint_record_pda, false),
         ],
         data,
     };
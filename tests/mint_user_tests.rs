@@ -8,15 +8,4542 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::MintUserV1InstructionData,
     process_instruction,
-    states::{NftAuthorityV1, ProjectV1, UserMintedV1, VaultV1, VestingMode},
+    states::{
+        CouponKind, CouponV1, MetadataPolicy, MintPhase, MintPhaseKind, MintRecordV1, MintTier,
+        NftAuthorityV1, NftNaming, PaymentMintV1, Pricing, PricingMode, ProjectV1,
+        ProtocolConfigV1, ReferrerEarningsV1, ReferrerV1, UserMintedV1, VaultV1, VestingMode,
+        VipGateMode, MAX_MINT_PHASES, PROJECT_V1_RESERVED_LEN,
+    },
     utils::{
         mock_mint, mock_mint_2022, mock_token_account, mock_token_account_2022, noop_processor,
-        ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID, NATIVE_MINT, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
     },
 };
 
 #[tokio::test]
-async fn test_mint_user() {
+async fn test_mint_user() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(mint_record_pda)
+        .await
+        .unwrap()
+        .expect("mint record account should exist");
+    let record = MintRecordV1::load(&account.data).unwrap();
+
+    assert_eq!(record.project, project_pda);
+    assert_eq!(record.nft, nft_asset_pubkey);
+    assert_eq!(record.index, 1);
+    assert_eq!(record.minter, payer_pubkey);
+    assert_eq!(record.mint, token_mint);
+    assert_eq!(record.tier, MintTier::User);
+    assert_eq!(record.price_paid, 30_000_000);
+    assert_eq!(record.escrow_amount, 15_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_native_sol() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = NATIVE_MINT;
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // token accounts are unused for native SOL payments
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+    let revenue_wallet_0_ata = Pubkey::new_unique();
+    let revenue_wallet_1_ata = Pubkey::new_unique();
+    let protocol_wallet_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 9,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 2,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    assert_eq!(vault.mint, NATIVE_MINT);
+    assert_eq!(vault.amount, 15_000_000);
+    assert!(account.lamports > 15_000_000);
+
+    let revenue_wallet_0_balance = banks_client
+        .get_balance(revenue_wallet_0_pubkey)
+        .await
+        .unwrap();
+    let revenue_wallet_1_balance = banks_client
+        .get_balance(revenue_wallet_1_pubkey)
+        .await
+        .unwrap();
+
+    assert_eq!(revenue_wallet_0_balance, lamports + 5_000_000);
+    assert_eq!(revenue_wallet_1_balance, lamports + 10_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_with_referrer() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = NATIVE_MINT;
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    let referrer = Keypair::new();
+    let referrer_pubkey = referrer.pubkey();
+
+    // token accounts are unused for native SOL payments
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+    let revenue_wallet_0_ata = Pubkey::new_unique();
+    let revenue_wallet_1_ata = Pubkey::new_unique();
+    let protocol_wallet_ata = Pubkey::new_unique();
+    let referrer_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (referrer_pda, _) = Pubkey::find_program_address(
+        &[
+            ReferrerV1::SEED,
+            project_pda.as_ref(),
+            referrer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 9,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 2,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    // 10% of every revenue share goes to the referrer
+    let referrer_cfg = ReferrerV1 {
+        project: project_pda,
+        wallet: referrer_pubkey,
+        referral_count: 0,
+        total_earned: 0,
+        referral_bps: 1_000,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pda,
+        Account {
+            lamports,
+            data: referrer_cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new(referrer_pda, false),
+            AccountMeta::new(referrer_pubkey, false),
+            AccountMeta::new(referrer_ata, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    // the payer still pays the full price
+    assert_eq!(vault.amount, 15_000_000);
+    assert_eq!(vault.price_paid, 30_000_000);
+
+    let revenue_wallet_0_balance = banks_client
+        .get_balance(revenue_wallet_0_pubkey)
+        .await
+        .unwrap();
+    let revenue_wallet_1_balance = banks_client
+        .get_balance(revenue_wallet_1_pubkey)
+        .await
+        .unwrap();
+    let referrer_balance = banks_client.get_balance(referrer_pubkey).await.unwrap();
+
+    assert_eq!(revenue_wallet_0_balance, lamports + 4_500_000);
+    assert_eq!(revenue_wallet_1_balance, lamports + 9_000_000);
+    assert_eq!(referrer_balance, lamports + 1_500_000);
+
+    let account = banks_client
+        .get_account(referrer_pda)
+        .await
+        .unwrap()
+        .expect("referrer account should exist");
+    let referrer_state = ReferrerV1::load(&account.data).unwrap();
+
+    assert_eq!(referrer_state.referral_count, 1);
+    assert_eq!(referrer_state.total_earned, 1_500_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_with_referrer_payment_mint() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+    let payment_mint = NATIVE_MINT;
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    let referrer = Keypair::new();
+    let referrer_pubkey = referrer.pubkey();
+
+    // token accounts are unused for native SOL payments
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+    let revenue_wallet_0_ata = Pubkey::new_unique();
+    let revenue_wallet_1_ata = Pubkey::new_unique();
+    let protocol_wallet_ata = Pubkey::new_unique();
+    let referrer_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (referrer_pda, _) = Pubkey::find_program_address(
+        &[
+            ReferrerV1::SEED,
+            project_pda.as_ref(),
+            referrer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (payment_mint_pda, _) = Pubkey::find_program_address(
+        &[
+            PaymentMintV1::SEED,
+            project_pda.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (referrer_earnings_pda, _) = Pubkey::find_program_address(
+        &[
+            ReferrerEarningsV1::SEED,
+            referrer_pda.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 9,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 2,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let mut payment_revenue_shares = [0u64; 5];
+    payment_revenue_shares[0] = 20_000_000;
+    payment_revenue_shares[1] = 40_000_000;
+
+    let payment_mint_state = PaymentMintV1 {
+        project: project_pda,
+        mint: payment_mint,
+        mint_decimals: 9,
+        mint_price_total: 100_000_000,
+        escrow_amount: 40_000_000,
+        revenue_shares: payment_revenue_shares,
+        active_from_ts: 0,
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    // 10% of every revenue share goes to the referrer
+    let referrer_cfg = ReferrerV1 {
+        project: project_pda,
+        wallet: referrer_pubkey,
+        referral_count: 0,
+        total_earned: 0,
+        referral_bps: 1_000,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payment_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payment_mint_pda,
+        Account {
+            lamports,
+            data: payment_mint_state.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pda,
+        Account {
+            lamports,
+            data: referrer_cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(payment_mint, false),
+            AccountMeta::new_readonly(payment_mint_pda, false),
+            AccountMeta::new(referrer_pda, false),
+            AccountMeta::new(referrer_pubkey, false),
+            AccountMeta::new(referrer_ata, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new(referrer_earnings_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    assert_eq!(vault.mint, NATIVE_MINT);
+    assert_eq!(vault.amount, 40_000_000);
+    assert_eq!(vault.price_paid, 100_000_000);
+
+    let revenue_wallet_0_balance = banks_client
+        .get_balance(revenue_wallet_0_pubkey)
+        .await
+        .unwrap();
+    let revenue_wallet_1_balance = banks_client
+        .get_balance(revenue_wallet_1_pubkey)
+        .await
+        .unwrap();
+    let referrer_balance = banks_client.get_balance(referrer_pubkey).await.unwrap();
+
+    assert_eq!(revenue_wallet_0_balance, lamports + 18_000_000);
+    assert_eq!(revenue_wallet_1_balance, lamports + 36_000_000);
+    assert_eq!(referrer_balance, lamports + 6_000_000);
+
+    let account = banks_client
+        .get_account(referrer_pda)
+        .await
+        .unwrap()
+        .expect("referrer account should exist");
+    let referrer_state = ReferrerV1::load(&account.data).unwrap();
+
+    // the kickback was paid in SOL, not in the project mint
+    assert_eq!(referrer_state.referral_count, 1);
+    assert_eq!(referrer_state.total_earned, 0);
+
+    let account = banks_client
+        .get_account(referrer_earnings_pda)
+        .await
+        .unwrap()
+        .expect("referrer earnings account should exist");
+    let earnings = ReferrerEarningsV1::load(&account.data).unwrap();
+
+    assert_eq!(earnings.referrer, referrer_pda);
+    assert_eq!(earnings.mint, NATIVE_MINT);
+    assert_eq!(earnings.total_earned, 6_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_self_referral() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = NATIVE_MINT;
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // the payer refers itself
+    let referrer_pubkey = payer_pubkey;
+
+    // token accounts are unused for native SOL payments
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+    let revenue_wallet_0_ata = Pubkey::new_unique();
+    let revenue_wallet_1_ata = Pubkey::new_unique();
+    let protocol_wallet_ata = Pubkey::new_unique();
+    let referrer_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (referrer_pda, _) = Pubkey::find_program_address(
+        &[
+            ReferrerV1::SEED,
+            project_pda.as_ref(),
+            referrer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 9,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 2,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    // 10% of every revenue share goes to the referrer
+    let referrer_cfg = ReferrerV1 {
+        project: project_pda,
+        wallet: referrer_pubkey,
+        referral_count: 0,
+        total_earned: 0,
+        referral_bps: 1_000,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pda,
+        Account {
+            lamports,
+            data: referrer_cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new(referrer_pda, false),
+            AccountMeta::new(referrer_pubkey, false),
+            AccountMeta::new(referrer_ata, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_mint_user_with_coupon() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = NATIVE_MINT;
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // token accounts are unused for native SOL payments
+    let payer_ata = Pubkey::new_unique();
+    let vault_ata = Pubkey::new_unique();
+    let revenue_wallet_0_ata = Pubkey::new_unique();
+    let revenue_wallet_1_ata = Pubkey::new_unique();
+    let protocol_wallet_ata = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let code = [7u8; 32];
+
+    let (coupon_pda, _) =
+        Pubkey::find_program_address(&[CouponV1::SEED, project_pda.as_ref(), &code], &program_id);
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 9,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 2,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    // single-use flat discount bound to the payer
+    let coupon = CouponV1 {
+        project: project_pda,
+        wallet: payer_pubkey,
+        code,
+        amount: 3_000_000,
+        max_redemptions: 1,
+        redeemed: 0,
+        expires_at: 0,
+        kind: CouponKind::Fixed,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        coupon_pda,
+        Account {
+            lamports,
+            data: coupon.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: 27_000_000,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new(coupon_pda, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    // the discount only comes out of the revenue shares
+    assert_eq!(vault.amount, 15_000_000);
+    assert_eq!(vault.price_paid, 27_000_000);
+
+    let revenue_wallet_0_balance = banks_client
+        .get_balance(revenue_wallet_0_pubkey)
+        .await
+        .unwrap();
+    let revenue_wallet_1_balance = banks_client
+        .get_balance(revenue_wallet_1_pubkey)
+        .await
+        .unwrap();
+
+    assert_eq!(revenue_wallet_0_balance, lamports + 4_000_000);
+    assert_eq!(revenue_wallet_1_balance, lamports + 8_000_000);
+
+    let account = banks_client
+        .get_account(coupon_pda)
+        .await
+        .unwrap()
+        .expect("coupon account should exist");
+    let coupon_state = CouponV1::load(&account.data).unwrap();
+
+    assert_eq!(coupon_state.redeemed, 1);
+}
+
+#[tokio::test]
+async fn test_mint_user_payment_mint() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+    let payment_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (payment_mint_pda, _) = Pubkey::find_program_address(
+        &[
+            PaymentMintV1::SEED,
+            project_pda.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let payment_mint_state = PaymentMintV1 {
+        project: project_pda,
+        mint: payment_mint,
+        mint_decimals: 9,
+        mint_price_total: 2_000_000_000,
+        escrow_amount: 2_000_000_000,
+        revenue_shares: [0; 5],
+        active_from_ts: 0,
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&payment_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payment_mint,
+        Account {
+            lamports,
+            data: mock_mint(9, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payment_mint_pda,
+        Account {
+            lamports,
+            data: payment_mint_state.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(payment_mint, false),
+            AccountMeta::new_readonly(payment_mint_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    assert_eq!(vault.mint, payment_mint);
+    assert_eq!(vault.amount, 2_000_000_000);
+    assert_eq!(vault.price_paid, 2_000_000_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_gift() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let recipient = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            recipient.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 gift failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(user_minted_pda)
+        .await
+        .unwrap()
+        .expect("user minted account should exist");
+    let user_minted = UserMintedV1::load(&account.data).unwrap();
+
+    assert_eq!(user_minted.owner, recipient);
+    assert_eq!(user_minted.minted_count, 1);
+}
+
+#[tokio::test]
+async fn test_mint_user_2022() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_2022_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account_2022(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint_2022(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_mint_user_with_protocol_fee() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 12_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 1_000,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 1_000,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &protocol_wallet, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_mint_user_outside_mint_phase() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    // only a VIP phase is running, the public phase starts later
+    let mut mint_phases = [MintPhase::default(); MAX_MINT_PHASES];
+    mint_phases[0] = MintPhase {
+        kind: MintPhaseKind::Vip,
+        start_ts: 0,
+        end_ts: 4_000_000_000,
+        max_mints: 0,
+        minted: 0,
+    };
+    mint_phases[1] = MintPhase {
+        kind: MintPhaseKind::Public,
+        start_ts: 4_000_000_000,
+        end_ts: 4_100_000_000,
+        max_mints: 0,
+        minted: 0,
+    };
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 2,
+        mint_phases,
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "MintUserV1 should fail outside a public mint phase"
+    );
+}
+
+#[tokio::test]
+async fn test_mint_user_dutch_auction() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        // auction already decayed to its floor: half the start price
+        pricing: Pricing {
+            mode: PricingMode::DutchAuction,
+            auction_start_ts: 0,
+            auction_duration_secs: 60,
+            auction_floor_price: 15_000_000,
+            curve_step: 0,
+            curve_growth_bps: 0,
+        },
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintUserV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![3u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    assert_eq!(vault.price_paid, 15_000_000);
+    assert_eq!(vault.amount, 7_500_000);
+}
+
+#[tokio::test]
+async fn test_mint_user_bonding_curve() {
     let program_id = tweetonium::ID;
     let token_program_id = TOKEN_PROGRAM_ID;
     let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
@@ -84,6 +4611,11 @@ async fn test_mint_user() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -121,6 +4653,15 @@ async fn test_mint_user() {
         &associated_token_program_id,
     );
 
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
     let mut revenue_wallets = [Pubkey::default(); 5];
     revenue_wallets[0] = revenue_wallet_0_pubkey;
     revenue_wallets[1] = revenue_wallet_1_pubkey;
@@ -138,7 +4679,7 @@ async fn test_mint_user() {
         max_mint_per_user: 5,
         max_mint_per_vip_user: 10,
         admin_minted: 0,
-        user_minted: 0,
+        user_minted: 10,
         vesting_mode: VestingMode::None,
         vesting_unlock_ts: 0,
         mint_nft_fee_lamports: 0,
@@ -148,6 +4689,45 @@ async fn test_mint_user() {
         num_revenue_wallets: 0,
         revenue_wallets,
         revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        // 10 sold at +3M each: the next mint costs twice the start price
+        pricing: Pricing {
+            mode: PricingMode::LinearCurve,
+            auction_start_ts: 0,
+            auction_duration_secs: 0,
+            auction_floor_price: 0,
+            curve_step: 3_000_000,
+            curve_growth_bps: 0,
+        },
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
     };
 
     let lamports = 1_000_000_000;
@@ -251,11 +4831,23 @@ async fn test_mint_user() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintUserV1InstructionData {
         nft_name: "Test NFT".to_string(),
         nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: 60_000_000,
     };
 
     let mut data = vec![3u8];
@@ -284,11 +4876,22 @@ async fn test_mint_user() {
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
         ],
         data,
     };
@@ -303,12 +4906,22 @@ async fn test_mint_user() {
     let result = banks_client.process_transaction(tx).await;
 
     assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .expect("vault account should exist");
+    let vault = VaultV1::load(&account.data).unwrap();
+
+    assert_eq!(vault.price_paid, 60_000_000);
+    assert_eq!(vault.amount, 30_000_000);
 }
 
 #[tokio::test]
-async fn test_mint_user_2022() {
+async fn test_mint_user_bonding_curve_slippage() {
     let program_id = tweetonium::ID;
-    let token_program_id = TOKEN_2022_PROGRAM_ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
     let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
     let system_program_id = solana_program::system_program::id();
     let mpl_core_id = mpl_core::ID;
@@ -374,6 +4987,11 @@ async fn test_mint_user_2022() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -411,6 +5029,15 @@ async fn test_mint_user_2022() {
         &associated_token_program_id,
     );
 
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
     let mut revenue_wallets = [Pubkey::default(); 5];
     revenue_wallets[0] = revenue_wallet_0_pubkey;
     revenue_wallets[1] = revenue_wallet_1_pubkey;
@@ -428,7 +5055,7 @@ async fn test_mint_user_2022() {
         max_mint_per_user: 5,
         max_mint_per_vip_user: 10,
         admin_minted: 0,
-        user_minted: 0,
+        user_minted: 10,
         vesting_mode: VestingMode::None,
         vesting_unlock_ts: 0,
         mint_nft_fee_lamports: 0,
@@ -438,6 +5065,45 @@ async fn test_mint_user_2022() {
         num_revenue_wallets: 0,
         revenue_wallets,
         revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        // 10 sold at +3M each: the next mint costs twice the start price
+        pricing: Pricing {
+            mode: PricingMode::LinearCurve,
+            auction_start_ts: 0,
+            auction_duration_secs: 0,
+            auction_floor_price: 0,
+            curve_step: 3_000_000,
+            curve_growth_bps: 0,
+        },
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
     };
 
     let lamports = 1_000_000_000;
@@ -457,7 +5123,7 @@ async fn test_mint_user_2022() {
         payer_ata,
         Account {
             lamports,
-            data: mock_token_account_2022(&token_mint, &payer_pubkey, 0),
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
             owner: token_program_id,
             executable: false,
             rent_epoch: 0,
@@ -501,7 +5167,7 @@ async fn test_mint_user_2022() {
         token_mint,
         Account {
             lamports,
-            data: mock_mint_2022(6, payer_pubkey),
+            data: mock_mint(6, payer_pubkey),
             owner: token_program_id,
             executable: false,
             rent_epoch: 0,
@@ -541,11 +5207,23 @@ async fn test_mint_user_2022() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintUserV1InstructionData {
         nft_name: "Test NFT".to_string(),
         nft_uri: "https://example.com/nft.json".to_string(),
+        max_price: 59_999_999,
     };
 
     let mut data = vec![3u8];
@@ -574,11 +5252,22 @@ async fn test_mint_user_2022() {
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(payer_pubkey, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
         ],
         data,
     };
@@ -592,5 +5281,8 @@ async fn test_mint_user_2022() {
 
     let result = banks_client.process_transaction(tx).await;
 
-    assert!(result.is_ok(), "MintUserV1 failed: {:?}", result.err());
+    assert!(
+        result.is_err(),
+        "MintUserV1 should fail when the price exceeds max_price"
+    );
 }
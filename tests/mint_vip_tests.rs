@@ -8,10 +8,15 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::MintVipV1InstructionData,
     process_instruction,
-    states::{NftAuthorityV1, ProjectV1, UserMintedV1, VaultV1, VestingMode},
+    states::{
+        MetadataPolicy, MintPhase, MintRecordV1, MintTier, NftAuthorityV1, NftNaming, Pricing,
+        ProjectV1, ProtocolConfigV1, ReferrerV1, UserMintedV1, VaultV1, VestingMode, VipGateMode,
+        VipGateRecordV1, VipPassV1, MAX_MINT_PHASES, PROJECT_V1_RESERVED_LEN,
+    },
     utils::{
-        mock_mint, mock_mint_2022, mock_token_account, mock_token_account_2022, noop_processor,
-        ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        mock_collection_asset, mock_mint, mock_mint_2022, mock_token_account,
+        mock_token_account_2022, noop_processor, MerkleProof, ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
     },
 };
 
@@ -39,6 +44,13 @@ async fn test_mint_vip() {
     let payer = Keypair::new();
     let payer_pubkey = payer.pubkey();
 
+    // VIP allowlist of two wallets
+    let other_vip = Pubkey::new_unique();
+    let vip_merkle_root = MerkleProof::node(
+        &MerkleProof::leaf(&payer_pubkey),
+        &MerkleProof::leaf(&other_vip),
+    );
+
     let nft_collection = Pubkey::new_unique();
     let nft_asset = Keypair::new();
     let nft_asset_pubkey = nft_asset.pubkey();
@@ -84,6 +96,11 @@ async fn test_mint_vip() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -121,6 +138,15 @@ async fn test_mint_vip() {
         &associated_token_program_id,
     );
 
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
     let mut revenue_wallets = [Pubkey::default(); 5];
     revenue_wallets[0] = revenue_wallet_0_pubkey;
     revenue_wallets[1] = revenue_wallet_1_pubkey;
@@ -148,6 +174,37 @@ async fn test_mint_vip() {
         num_revenue_wallets: 0,
         revenue_wallets,
         revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root,
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
     };
 
     let lamports = 1_000_000_000;
@@ -251,11 +308,24 @@ async fn test_mint_vip() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintVipV1InstructionData {
         nft_name: "Test NFT".to_string(),
         nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![MerkleProof::leaf(&other_vip)],
+        max_price: u64::MAX,
     };
 
     let mut data = vec![4u8];
@@ -284,11 +354,21 @@ async fn test_mint_vip() {
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
         ],
         data,
     };
@@ -303,12 +383,33 @@ async fn test_mint_vip() {
     let result = banks_client.process_transaction(tx).await;
 
     assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(mint_record_pda)
+        .await
+        .unwrap()
+        .expect("mint record account should exist");
+    let record = MintRecordV1::load(&account.data).unwrap();
+
+    assert_eq!(record.minter, payer_pubkey);
+    assert_eq!(record.tier, MintTier::Vip);
+    assert_eq!(record.escrow_amount, 15_000_000);
+
+    let account = banks_client
+        .get_account(project_pda)
+        .await
+        .unwrap()
+        .expect("project account should exist");
+    let project = ProjectV1::load(&account.data).unwrap();
+
+    assert_eq!(project.vip_minted, 1);
+    assert_eq!(project.user_minted, 0);
 }
 
 #[tokio::test]
-async fn test_mint_vip_2022() {
+async fn test_mint_vip_self_referral() {
     let program_id = tweetonium::ID;
-    let token_program_id = TOKEN_2022_PROGRAM_ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
     let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
     let system_program_id = solana_program::system_program::id();
     let mpl_core_id = mpl_core::ID;
@@ -329,6 +430,13 @@ async fn test_mint_vip_2022() {
     let payer = Keypair::new();
     let payer_pubkey = payer.pubkey();
 
+    // VIP allowlist of two wallets
+    let other_vip = Pubkey::new_unique();
+    let vip_merkle_root = MerkleProof::node(
+        &MerkleProof::leaf(&payer_pubkey),
+        &MerkleProof::leaf(&other_vip),
+    );
+
     let nft_collection = Pubkey::new_unique();
     let nft_asset = Keypair::new();
     let nft_asset_pubkey = nft_asset.pubkey();
@@ -374,6 +482,11 @@ async fn test_mint_vip_2022() {
         &program_id,
     );
 
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
     let (vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -411,6 +524,25 @@ async fn test_mint_vip_2022() {
         &associated_token_program_id,
     );
 
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    // the payer refers itself
+    let (referrer_pda, _) = Pubkey::find_program_address(
+        &[
+            ReferrerV1::SEED,
+            project_pda.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
     let mut revenue_wallets = [Pubkey::default(); 5];
     revenue_wallets[0] = revenue_wallet_0_pubkey;
     revenue_wallets[1] = revenue_wallet_1_pubkey;
@@ -438,6 +570,45 @@ async fn test_mint_vip_2022() {
         num_revenue_wallets: 0,
         revenue_wallets,
         revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root,
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let referrer_cfg = ReferrerV1 {
+        project: project_pda,
+        wallet: payer_pubkey,
+        referral_count: 0,
+        total_earned: 0,
+        referral_bps: 1_000,
     };
 
     let lamports = 1_000_000_000;
@@ -457,7 +628,7 @@ async fn test_mint_vip_2022() {
         payer_ata,
         Account {
             lamports,
-            data: mock_token_account_2022(&token_mint, &payer_pubkey, 0),
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
             owner: token_program_id,
             executable: false,
             rent_epoch: 0,
@@ -501,7 +672,7 @@ async fn test_mint_vip_2022() {
         token_mint,
         Account {
             lamports,
-            data: mock_mint_2022(6, payer_pubkey),
+            data: mock_mint(6, payer_pubkey),
             owner: token_program_id,
             executable: false,
             rent_epoch: 0,
@@ -541,11 +712,35 @@ async fn test_mint_vip_2022() {
         },
     );
 
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        referrer_pda,
+        Account {
+            lamports,
+            data: referrer_cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
 
     let ix_data = MintVipV1InstructionData {
         nft_name: "Test NFT".to_string(),
         nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![MerkleProof::leaf(&other_vip)],
+        max_price: u64::MAX,
     };
 
     let mut data = vec![4u8];
@@ -574,11 +769,21 @@ async fn test_mint_vip_2022() {
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
             AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
             AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
             AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(referrer_pda, false),
+            AccountMeta::new(payer_pubkey, false),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
         ],
         data,
     };
@@ -592,5 +797,2664 @@ async fn test_mint_vip_2022() {
 
     let result = banks_client.process_transaction(tx).await;
 
-    assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_mint_vip_sold_out() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // VIP allowlist of two wallets
+    let other_vip = Pubkey::new_unique();
+    let vip_merkle_root = MerkleProof::node(
+        &MerkleProof::leaf(&payer_pubkey),
+        &MerkleProof::leaf(&other_vip),
+    );
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root,
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 1_000,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![MerkleProof::leaf(&other_vip)],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "Mint vip user should fail once the VIP supply is exhausted"
+    );
+}
+
+#[tokio::test]
+async fn test_mint_vip_2022() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_2022_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // VIP allowlist of two wallets
+    let other_vip = Pubkey::new_unique();
+    let vip_merkle_root = MerkleProof::node(
+        &MerkleProof::leaf(&payer_pubkey),
+        &MerkleProof::leaf(&other_vip),
+    );
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root,
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account_2022(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint_2022(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![MerkleProof::leaf(&other_vip)],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_mint_vip_not_allowlisted() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // VIP allowlist of two wallets
+    let other_vip = Pubkey::new_unique();
+    let vip_merkle_root = MerkleProof::node(
+        &MerkleProof::leaf(&Pubkey::new_unique()),
+        &MerkleProof::leaf(&other_vip),
+    );
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root,
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![MerkleProof::leaf(&other_vip)],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "Mint vip user should reject a payer outside the VIP allowlist"
+    );
+}
+
+#[tokio::test]
+async fn test_mint_vip_with_pass() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vip_pass_pda, _) = Pubkey::find_program_address(
+        &[VipPassV1::SEED, project_pda.as_ref(), payer_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let vip_pass = VipPassV1 {
+        project: project_pda,
+        wallet: payer_pubkey,
+        max_mint: 1,
+        minted: 0,
+        expires_at: 0,
+    };
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        vip_pass_pda,
+        Account {
+            lamports,
+            data: vip_pass.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(vip_pass_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vip_pass_pda)
+        .await
+        .unwrap()
+        .expect("vip pass account should exist");
+    let vip_pass = VipPassV1::load(&account.data).unwrap();
+
+    assert_eq!(vip_pass.minted, 1);
+}
+
+#[tokio::test]
+async fn test_mint_vip_collection_gated() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // partner collection asset held by the payer
+    let gate_collection = Pubkey::new_unique();
+    let gate_asset = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vip_gate_record_pda, _) = Pubkey::find_program_address(
+        &[
+            VipGateRecordV1::SEED,
+            project_pda.as_ref(),
+            gate_asset.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::Collection,
+        vip_gate_single_use: true,
+        vip_gate_min_amount: 0,
+        vip_gate: gate_collection,
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        gate_asset,
+        Account {
+            lamports,
+            data: mock_collection_asset(payer_pubkey, gate_collection),
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(gate_asset, false),
+            AccountMeta::new(vip_gate_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+
+    let account = banks_client
+        .get_account(vip_gate_record_pda)
+        .await
+        .unwrap()
+        .expect("vip gate record account should exist");
+    let record = VipGateRecordV1::load(&account.data).unwrap();
+
+    assert_eq!(record.asset, gate_asset);
+    assert_eq!(record.wallet, payer_pubkey);
+}
+
+#[tokio::test]
+async fn test_mint_vip_token_2022_gate() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // Token-2022 gate mint held by the payer, while the project is paid in SPL Token
+    let gate_mint = Pubkey::new_unique();
+    let gate_account = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::Token,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 1,
+        vip_gate: gate_mint,
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        gate_account,
+        Account {
+            lamports,
+            data: mock_token_account_2022(&gate_mint, &payer_pubkey, 5),
+            owner: TOKEN_2022_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(gate_account, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "Mint vip user failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_mint_vip_collection_gate_already_used() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let associated_token_program_id = ASSOCIATED_TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("token", token_program_id, processor!(noop_processor));
+    program_test.add_program(
+        "associated_token",
+        associated_token_program_id,
+        processor!(noop_processor),
+    );
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let payer = Keypair::new();
+    let payer_pubkey = payer.pubkey();
+
+    // partner collection asset held by the payer
+    let gate_collection = Pubkey::new_unique();
+    let gate_asset = Pubkey::new_unique();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset = Keypair::new();
+    let nft_asset_pubkey = nft_asset.pubkey();
+
+    let token_mint = Pubkey::new_unique();
+
+    let revenue_wallet_0 = Keypair::new();
+    let revenue_wallet_0_pubkey = revenue_wallet_0.pubkey();
+
+    let revenue_wallet_1 = Keypair::new();
+    let revenue_wallet_1_pubkey = revenue_wallet_1.pubkey();
+
+    let protocol_wallet = Pubkey::new_unique();
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (payer_ata, _) = Pubkey::find_program_address(
+        &[
+            payer_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            VaultV1::SEED,
+            nft_asset_pubkey.as_ref(),
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda, _) = Pubkey::find_program_address(
+        &[MintRecordV1::SEED, nft_asset_pubkey.as_ref()],
+        &program_id,
+    );
+
+    let (vault_ata, _) = Pubkey::find_program_address(
+        &[
+            vault_pda.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (user_minted_pda, _) = Pubkey::find_program_address(
+        &[
+            UserMintedV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+            payer_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (revenue_wallet_0_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_0_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (revenue_wallet_1_ata, _) = Pubkey::find_program_address(
+        &[
+            revenue_wallet_1_pubkey.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (protocol_wallet_ata, _) = Pubkey::find_program_address(
+        &[
+            protocol_wallet.as_ref(),
+            token_program_id.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &associated_token_program_id,
+    );
+
+    let (vip_gate_record_pda, _) = Pubkey::find_program_address(
+        &[
+            VipGateRecordV1::SEED,
+            project_pda.as_ref(),
+            gate_asset.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let mut revenue_wallets = [Pubkey::default(); 5];
+    revenue_wallets[0] = revenue_wallet_0_pubkey;
+    revenue_wallets[1] = revenue_wallet_1_pubkey;
+
+    let mut revenue_shares = [0u64; 5];
+    revenue_shares[0] = 5_000_000;
+    revenue_shares[1] = 10_000_000;
+
+    let cfg = ProjectV1 {
+        admin: payer_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 0,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets,
+        revenue_shares,
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::Collection,
+        vip_gate_single_use: true,
+        vip_gate_min_amount: 0,
+        vip_gate: gate_collection,
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root: [0u8; 32],
+        vip_supply: 1_000,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let (protocol_config_pda, _) =
+        Pubkey::find_program_address(&[ProtocolConfigV1::SEED], &program_id);
+
+    let protocol_config = ProtocolConfigV1 {
+        authority: Pubkey::new_unique(),
+        fee_wallet: protocol_wallet,
+        default_mint_nft_fee_lamports: 0,
+        default_update_nft_fee_lamports: 0,
+        default_trait_mint_fee_lamports: 0,
+        protocol_fee_bps: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        payer_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        payer_ata,
+        Account {
+            lamports,
+            data: mock_token_account(&token_mint, &payer_pubkey, 0),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, payer_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_0_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        revenue_wallet_1_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_wallet,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        protocol_config_pda,
+        Account {
+            lamports,
+            data: protocol_config.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        gate_asset,
+        Account {
+            lamports,
+            data: mock_collection_asset(payer_pubkey, gate_collection),
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let record = VipGateRecordV1 {
+        project: project_pda,
+        asset: gate_asset,
+        wallet: Pubkey::new_unique(),
+    };
+
+    program_test.add_account(
+        vip_gate_record_pda,
+        Account {
+            lamports,
+            data: record.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = MintVipV1InstructionData {
+        nft_name: "Test NFT".to_string(),
+        nft_uri: "https://example.com/nft.json".to_string(),
+        vip_proof: vec![],
+        max_price: u64::MAX,
+    };
+
+    let mut data = vec![4u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new(payer_ata, false),
+            AccountMeta::new(project_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(user_minted_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new(nft_asset_pubkey, true),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(revenue_wallet_0_pubkey, false),
+            AccountMeta::new(revenue_wallet_0_ata, false),
+            AccountMeta::new(revenue_wallet_1_pubkey, false),
+            AccountMeta::new(revenue_wallet_1_ata, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new_readonly(protocol_config_pda, false),
+            AccountMeta::new(protocol_wallet, false),
+            AccountMeta::new(protocol_wallet_ata, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_program_id, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(gate_asset, false),
+            AccountMeta::new(vip_gate_record_pda, false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(Pubkey::default(), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(mint_record_pda, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer_pubkey),
+        &[&payer, &nft_asset],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(
+        result.is_err(),
+        "Mint vip should fail when the gate asset was already used"
+    );
 }
@@ -0,0 +1,814 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::{RevealItem, RevealV1InstructionData},
+    process_instruction,
+    states::{
+        MetadataPolicy, MintPhase, MintRecordV1, MintTier, NftAuthorityV1, NftNaming, Pricing,
+        ProjectRoleV1, ProjectV1, VestingMode, VipGateMode, MAX_MINT_PHASES,
+        PROJECT_V1_RESERVED_LEN,
+    },
+    utils::{mock_base_asset, mock_mint, noop_processor, MerkleProof, TOKEN_PROGRAM_ID},
+};
+
+#[tokio::test]
+async fn test_reveal() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Pubkey::new_unique();
+    let nft_asset_1 = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    // manifest committed at init: NFT #1 and NFT #2
+    let uri_0 = "https://arweave.net/manifest/1.json";
+    let uri_1 = "https://arweave.net/manifest/2.json";
+    let leaf_0 = MerkleProof::manifest_leaf(1, uri_0);
+    let leaf_1 = MerkleProof::manifest_leaf(2, uri_1);
+    let reveal_root = MerkleProof::node(&leaf_0, &leaf_1);
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_0.as_ref()], &program_id);
+
+    let (mint_record_pda_1, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_1.as_ref()], &program_id);
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 5_000,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0u64; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root,
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let record_0 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_0,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 1,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let record_1 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_1,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 2,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    for nft_asset in [nft_asset_0, nft_asset_1] {
+        program_test.add_account(
+            nft_asset,
+            Account {
+                lamports,
+                data: mock_base_asset(
+                    Pubkey::new_unique(),
+                    "Hidden NFT",
+                    "https://arweave.net/placeholder.json",
+                ),
+                owner: mpl_core_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    program_test.add_account(
+        mint_record_pda_0,
+        Account {
+            lamports,
+            data: record_0.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        mint_record_pda_1,
+        Account {
+            lamports,
+            data: record_1.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = RevealV1InstructionData {
+        nfts: vec![
+            RevealItem {
+                uri: uri_0.to_string(),
+                proof: vec![leaf_1],
+            },
+            RevealItem {
+                uri: uri_1.to_string(),
+                proof: vec![leaf_0],
+            },
+        ],
+    };
+
+    let mut data = vec![40u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new_readonly(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(nft_asset_0, false),
+            AccountMeta::new_readonly(mint_record_pda_0, false),
+            AccountMeta::new(nft_asset_1, false),
+            AccountMeta::new_readonly(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "RevealV1 failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_reveal_before_sellout() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Pubkey::new_unique();
+    let nft_asset_1 = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    // manifest committed at init: NFT #1 and NFT #2
+    let uri_0 = "https://arweave.net/manifest/1.json";
+    let uri_1 = "https://arweave.net/manifest/2.json";
+    let leaf_0 = MerkleProof::manifest_leaf(1, uri_0);
+    let leaf_1 = MerkleProof::manifest_leaf(2, uri_1);
+    let reveal_root = MerkleProof::node(&leaf_0, &leaf_1);
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_0.as_ref()], &program_id);
+
+    let (mint_record_pda_1, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_1.as_ref()], &program_id);
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 4_999,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0u64; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root,
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let record_0 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_0,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 1,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let record_1 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_1,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 2,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    for nft_asset in [nft_asset_0, nft_asset_1] {
+        program_test.add_account(
+            nft_asset,
+            Account {
+                lamports,
+                data: mock_base_asset(
+                    Pubkey::new_unique(),
+                    "Hidden NFT",
+                    "https://arweave.net/placeholder.json",
+                ),
+                owner: mpl_core_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    program_test.add_account(
+        mint_record_pda_0,
+        Account {
+            lamports,
+            data: record_0.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        mint_record_pda_1,
+        Account {
+            lamports,
+            data: record_1.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = RevealV1InstructionData {
+        nfts: vec![
+            RevealItem {
+                uri: uri_0.to_string(),
+                proof: vec![leaf_1],
+            },
+            RevealItem {
+                uri: uri_1.to_string(),
+                proof: vec![leaf_0],
+            },
+        ],
+    };
+
+    let mut data = vec![40u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new_readonly(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(nft_asset_0, false),
+            AccountMeta::new_readonly(mint_record_pda_0, false),
+            AccountMeta::new(nft_asset_1, false),
+            AccountMeta::new_readonly(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    // 4_999 of 5_000 public NFTs minted
+    assert!(
+        result.is_err(),
+        "RevealV1 should fail before the public mint sells out"
+    );
+}
+
+#[tokio::test]
+async fn test_reveal_wrong_uri() {
+    let program_id = tweetonium::ID;
+    let token_program_id = TOKEN_PROGRAM_ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+    program_test.add_program("mpl_core", mpl_core_id, processor!(noop_processor));
+
+    // --- signers / keys ---
+    let admin = Keypair::new();
+    let admin_pubkey = admin.pubkey();
+
+    let nft_collection = Pubkey::new_unique();
+    let nft_asset_0 = Pubkey::new_unique();
+    let nft_asset_1 = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    // manifest committed at init: NFT #1 and NFT #2
+    let uri_0 = "https://arweave.net/manifest/1.json";
+    let uri_1 = "https://arweave.net/manifest/2.json";
+    let leaf_0 = MerkleProof::manifest_leaf(1, uri_0);
+    let leaf_1 = MerkleProof::manifest_leaf(2, uri_1);
+    let reveal_root = MerkleProof::node(&leaf_0, &leaf_1);
+
+    // PDAs
+    let (nft_authority, _) = Pubkey::find_program_address(&[NftAuthorityV1::SEED], &program_id);
+
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (project_role_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectRoleV1::SEED,
+            project_pda.as_ref(),
+            admin_pubkey.as_ref(),
+        ],
+        &program_id,
+    );
+
+    let (mint_record_pda_0, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_0.as_ref()], &program_id);
+
+    let (mint_record_pda_1, _) =
+        Pubkey::find_program_address(&[MintRecordV1::SEED, nft_asset_1.as_ref()], &program_id);
+
+    let cfg = ProjectV1 {
+        admin: admin_pubkey,
+        mint: token_mint,
+        mint_decimals: 6,
+        max_supply: 10_000,
+        released: 5_000,
+        max_mint_per_user: 5,
+        max_mint_per_vip_user: 10,
+        admin_minted: 0,
+        user_minted: 5_000,
+        vesting_mode: VestingMode::None,
+        vesting_unlock_ts: 0,
+        mint_nft_fee_lamports: 0,
+        update_nft_fee_lamports: 0,
+        mint_price_total: 30_000_000,
+        escrow_amount: 15_000_000,
+        num_revenue_wallets: 0,
+        revenue_wallets: [Pubkey::default(); 5],
+        revenue_shares: [0u64; 5],
+        multisig: Pubkey::default(),
+        update_delay_secs: 0,
+        paused: 0,
+        protocol_fee_bps: 0,
+        vip_merkle_root: [0u8; 32],
+        vip_gate_mode: VipGateMode::None,
+        vip_gate_single_use: false,
+        vip_gate_min_amount: 0,
+        vip_gate: Pubkey::default(),
+        num_mint_phases: 0,
+        mint_phases: [MintPhase::default(); MAX_MINT_PHASES],
+        pricing: Pricing::default(),
+        naming: NftNaming::default(),
+        metadata_policy: MetadataPolicy::default(),
+        reveal_root,
+        vip_supply: 0,
+        vip_minted: 0,
+        vip_rollover_ts: 0,
+        reserved: [0u8; PROJECT_V1_RESERVED_LEN],
+    };
+
+    let record_0 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_0,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 1,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let record_1 = MintRecordV1 {
+        project: project_pda,
+        nft: nft_asset_1,
+        minter: Pubkey::new_unique(),
+        mint: token_mint,
+        index: 2,
+        price_paid: 30_000_000,
+        escrow_amount: 15_000_000,
+        slot: 0,
+        timestamp: 0,
+        tier: MintTier::User,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        admin_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: cfg.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        token_mint,
+        Account {
+            lamports,
+            data: mock_mint(6, admin_pubkey),
+            owner: token_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_authority,
+        Account {
+            lamports,
+            data: vec![],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        nft_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    for nft_asset in [nft_asset_0, nft_asset_1] {
+        program_test.add_account(
+            nft_asset,
+            Account {
+                lamports,
+                data: mock_base_asset(
+                    Pubkey::new_unique(),
+                    "Hidden NFT",
+                    "https://arweave.net/placeholder.json",
+                ),
+                owner: mpl_core_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    program_test.add_account(
+        mint_record_pda_0,
+        Account {
+            lamports,
+            data: record_0.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        mint_record_pda_1,
+        Account {
+            lamports,
+            data: record_1.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = RevealV1InstructionData {
+        nfts: vec![
+            RevealItem {
+                uri: uri_0.to_string(),
+                proof: vec![leaf_1],
+            },
+            RevealItem {
+                uri: "https://arweave.net/manifest/rare.json".to_string(),
+                proof: vec![leaf_0],
+            },
+        ],
+    };
+
+    let mut data = vec![40u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pubkey, true),
+            AccountMeta::new_readonly(project_pda, false),
+            AccountMeta::new_readonly(project_role_pda, false),
+            AccountMeta::new_readonly(nft_authority, false),
+            AccountMeta::new(nft_collection, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(mpl_core_id, false),
+            AccountMeta::new(nft_asset_0, false),
+            AccountMeta::new_readonly(mint_record_pda_0, false),
+            AccountMeta::new(nft_asset_1, false),
+            AccountMeta::new_readonly(mint_record_pda_1, false),
+        ],
+        data,
+    };
+
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&admin_pubkey), &[&admin], recent_blockhash);
+
+    let result = banks_client.process_transaction(tx).await;
+
+    // NFT #2 swapped for a URI that was never committed
+    assert!(
+        result.is_err(),
+        "RevealV1 should fail on a URI outside the committed manifest"
+    );
+}
@@ -0,0 +1,112 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use tweetonium::{
+    instructions::SetTraitPauseV1InstructionData,
+    process_instruction,
+    states::{PauseFlag, TraitItemV1},
+};
+
+#[tokio::test]
+async fn test_set_trait_pause_v1() {
+    let program_id = tweetonium::ID;
+    let system_program_id = solana_program::system_program::id();
+    let mpl_core_id = mpl_core::ID;
+
+    let mut program_test = ProgramTest::default();
+
+    // add the tested program and CPI programs
+    program_test.add_program("tweetonium", program_id, processor!(process_instruction));
+
+    // --- signers / keys ---
+    let authority = Keypair::new();
+    let authority_pubkey = authority.pubkey();
+
+    let trait_collection = Pubkey::new_unique();
+
+    // PDAs
+    let (trait_pda, _) =
+        Pubkey::find_program_address(&[TraitItemV1::SEED, trait_collection.as_ref()], &program_id);
+
+    let trait_item = TraitItemV1 {
+        authority: authority_pubkey,
+        max_supply: 1000,
+        user_minted: 0,
+        mint_fee_lamports: 1_000_000,
+        paused: 0,
+    };
+
+    let lamports = 1_000_000_000;
+
+    program_test.add_account(
+        authority_pubkey,
+        Account {
+            lamports,
+            data: vec![],
+            owner: system_program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_pda,
+        Account {
+            lamports,
+            data: trait_item.to_bytes(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test.add_account(
+        trait_collection,
+        Account {
+            lamports,
+            data: vec![],
+            owner: mpl_core_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, _bank_payer, recent_blockhash) = program_test.start().await;
+
+    let ix_data = SetTraitPauseV1InstructionData {
+        paused: PauseFlag::MintUser.mask(),
+    };
+
+    let mut data = vec![25u8];
+    data.extend(ix_data.try_to_vec().expect("Failed to serialize ix data"));
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(trait_pda, false),
+            AccountMeta::new_readonly(trait_collection, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority_pubkey),
+        &[&authority],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(tx).await;
+
+    assert!(result.is_ok(), "SetTraitPauseV1 failed: {:?}", result.err());
+
+    let account = banks_client.get_account(trait_pda).await.unwrap().unwrap();
+    let trait_item = TraitItemV1::load(&account.data).unwrap();
+
+    assert_eq!(trait_item.paused, PauseFlag::MintUser.mask());
+}
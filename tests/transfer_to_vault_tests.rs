@@ -8,7 +8,7 @@ use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transacti
 use tweetonium::{
     instructions::TransferToVaultV1InstructionData,
     process_instruction,
-    states::VaultV1,
+    states::{ProjectV1, VaultV1},
     utils::{
         mock_base_asset, mock_mint, mock_mint_2022, mock_token_account, mock_token_account_2022,
         noop_processor, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
@@ -64,6 +64,15 @@ async fn test_transfer_to_vault() {
         &program_id,
     );
 
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            project_token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
     let (new_vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -78,6 +87,8 @@ async fn test_transfer_to_vault() {
         amount: 1_000_000,
         is_unlocked: 0,
         bump: [vault_bump],
+        price_paid: 0,
+        mint: project_token_mint,
     };
 
     let lamports = 1_000_000_000;
@@ -115,6 +126,17 @@ async fn test_transfer_to_vault() {
         },
     );
 
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: vec![0u8; ProjectV1::LEN],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     program_test.add_account(
         nft_collection,
         Account {
@@ -180,6 +202,7 @@ async fn test_transfer_to_vault() {
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(project_pda, false),
         ],
         data,
     };
@@ -245,6 +268,15 @@ async fn test_transfer_to_vault_2022() {
         &program_id,
     );
 
+    let (project_pda, _) = Pubkey::find_program_address(
+        &[
+            ProjectV1::SEED,
+            nft_collection.as_ref(),
+            project_token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+
     let (new_vault_ata, _) = Pubkey::find_program_address(
         &[
             vault_pda.as_ref(),
@@ -259,6 +291,8 @@ async fn test_transfer_to_vault_2022() {
         amount: 1_000_000,
         is_unlocked: 0,
         bump: [vault_bump],
+        price_paid: 0,
+        mint: project_token_mint,
     };
 
     let lamports = 1_000_000_000;
@@ -296,6 +330,17 @@ async fn test_transfer_to_vault_2022() {
         },
     );
 
+    program_test.add_account(
+        project_pda,
+        Account {
+            lamports,
+            data: vec![0u8; ProjectV1::LEN],
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     program_test.add_account(
         nft_collection,
         Account {
@@ -361,6 +406,7 @@ async fn test_transfer_to_vault_2022() {
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_program_id, false),
             AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(project_pda, false),
         ],
         data,
     };
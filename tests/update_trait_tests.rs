@@ -41,6 +41,7 @@ async fn test_update_trait() {
         max_supply: 10_000,
         user_minted: 0,
         mint_fee_lamports: 10_000,
+        paused: 0,
     };
 
     let lamports = 1_000_000_000;